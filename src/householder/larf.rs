@@ -0,0 +1,156 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct LARF_Driver<'v, 'c, F>
+where
+    F: LapackFloat,
+{
+    side: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    v: ArrayView1<'v, F>,
+    incv: lapack_int,
+    tau: F,
+    c: ArrayOut2<'c, F>,
+    ldc: lapack_int,
+    work: Array1<F>,
+}
+
+pub trait LARFNum: LapackFloat {
+    unsafe fn run_larf(driver: &mut LARF_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl LARFNum for $type {
+            unsafe fn run_larf(driver: &mut LARF_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.side,
+                    &driver.m,
+                    &driver.n,
+                    driver.v.as_ptr(),
+                    &driver.incv,
+                    &driver.tau,
+                    driver.c.get_data_mut_ptr(),
+                    &driver.ldc,
+                    driver.work.as_mut_ptr(),
+                );
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, slarf_);
+impl_func!(f64, dlarf_);
+impl_func!(c32, clarf_);
+impl_func!(c64, zlarf_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Apply elementary reflector `H = I - τ v vᴴ` to general matrix `C`: `H C` (`side = 'L'`) or `C H`
+/// (`side = 'R'`).
+///
+/// Vector `v` should have length `m` for `side = 'L'`, or `n` for `side = 'R'`; its first element is referenced
+/// (pass `1` there for reflectors from `LARFG`). To apply `Hᴴ`, pass conjugate of `τ`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct LARF_<'v, 'c, F>
+where
+    F: LARFNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub v: ArrayView1<'v, F>,
+    pub tau: F,
+
+    // input/output
+    #[builder(setter(into))]
+    pub c: ArrayViewOrMut2<'c, F>,
+
+    // option
+    #[builder(default = "'L'")]
+    pub side: char,
+}
+
+impl<'v, 'c, F> LARF_<'v, 'c, F>
+where
+    F: LARFNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let Self { v, tau, c, side } = self;
+
+        // 1. dim assign, flag check
+        let v = v.to_seq_layout()?;
+        let c = c.into_col_array_out();
+        let (m, n) = c.view().dim();
+        let side = side.to_ascii_uppercase();
+        lapack_check_flag!(side, ['L', 'R'])?;
+
+        // 2. dim check & buffer alloc
+        let (nq, nw) = if side == 'L' { (m, n) } else { (n, m) };
+        lapack_assert_eq!(v.len(), nq, InvalidDim)?;
+        let ldc = c.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let work = Array1::zeros(nw.max(1));
+
+        // 3. struct build
+        let mut driver = LARF_Driver {
+            side: side as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            v: v.view(),
+            incv: 1,
+            tau,
+            c,
+            ldc: ldc.try_into()?,
+            work,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_larf(&mut driver)?;
+        }
+
+        // 5. finalize
+        let LARF_Driver { c, .. } = driver;
+        return Ok(c.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type LARF<'v, 'c, F> = LARF_Builder<'v, 'c, F>;
+pub type SLARF<'v, 'c> = LARF<'v, 'c, f32>;
+pub type DLARF<'v, 'c> = LARF<'v, 'c, f64>;
+pub type CLARF<'v, 'c> = LARF<'v, 'c, c32>;
+pub type ZLARF<'v, 'c> = LARF<'v, 'c, c64>;
+
+impl<'v, 'c, F> LARF_Builder<'v, 'c, F>
+where
+    F: LARFNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.c.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let c = obj.driver()?;
+            let c = match c {
+                ArrayOut::Owned(c) => ArrayOut::Owned(c.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(c) => ArrayOut::ViewMut(c),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(c);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,201 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct LARFB_Driver<'v, 't, 'c, F>
+where
+    F: LapackFloat,
+{
+    side: c_char,
+    trans: c_char,
+    direct: c_char,
+    storev: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    k: lapack_int,
+    v: ArrayView2<'v, F>,
+    ldv: lapack_int,
+    t: ArrayView2<'t, F>,
+    ldt: lapack_int,
+    c: ArrayOut2<'c, F>,
+    ldc: lapack_int,
+    work: Array2<F>,
+    ldwork: lapack_int,
+}
+
+pub trait LARFBNum: LapackFloat {
+    unsafe fn run_larfb(driver: &mut LARFB_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl LARFBNum for $type {
+            unsafe fn run_larfb(driver: &mut LARFB_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.side,
+                    &driver.trans,
+                    &driver.direct,
+                    &driver.storev,
+                    &driver.m,
+                    &driver.n,
+                    &driver.k,
+                    driver.v.as_ptr(),
+                    &driver.ldv,
+                    driver.t.as_ptr(),
+                    &driver.ldt,
+                    driver.c.get_data_mut_ptr(),
+                    &driver.ldc,
+                    driver.work.as_mut_ptr(),
+                    &driver.ldwork,
+                );
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, slarfb_);
+impl_func!(f64, dlarfb_);
+impl_func!(c32, clarfb_);
+impl_func!(c64, zlarfb_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Apply block reflector `H = I - V T Vᴴ` from `LARFT` to general matrix `C`: `op(H) C` (`side = 'L'`) or
+/// `C op(H)` (`side = 'R'`).
+///
+/// With `t` of shape `(k, k)` and `C` of shape `(m, n)`, the order of `H` is `nq = m` for `side = 'L'` or `nq = n`
+/// for `side = 'R'`; `v` should be `(nq, k)` for `storev = 'C'`, or `(k, nq)` for `storev = 'R'`. Options `direct`
+/// and `storev` should be the same as those passed to `LARFT`. For real matrices, `trans = 'C'` is regarded as
+/// `'T'`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct LARFB_<'v, 't, 'c, F>
+where
+    F: LARFBNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub v: ArrayView2<'v, F>,
+    #[builder(setter(into))]
+    pub t: ArrayView2<'t, F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub c: ArrayViewOrMut2<'c, F>,
+
+    // option
+    #[builder(default = "'L'")]
+    pub side: char,
+    #[builder(default = "'N'")]
+    pub trans: char,
+    #[builder(default = "'F'")]
+    pub direct: char,
+    #[builder(default = "'C'")]
+    pub storev: char,
+}
+
+impl<'v, 't, 'c, F> LARFB_<'v, 't, 'c, F>
+where
+    F: LARFBNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let Self { v, t, c, side, trans, direct, storev } = self;
+
+        // 1. dim assign, flag check
+        let v = v.to_col_layout()?;
+        let t = t.to_col_layout()?;
+        let c = c.into_col_array_out();
+        let (m, n) = c.view().dim();
+        let k = t.nrows();
+        let side = side.to_ascii_uppercase();
+        let trans = trans.to_ascii_uppercase();
+        let trans = if F::is_complex() || trans != 'C' { trans } else { 'T' };
+        let direct = direct.to_ascii_uppercase();
+        let storev = storev.to_ascii_uppercase();
+        lapack_check_flag!(side, ['L', 'R'])?;
+        match F::is_complex() {
+            true => lapack_check_flag!(trans, ['N', 'C'])?,
+            false => lapack_check_flag!(trans, ['N', 'T'])?,
+        };
+        lapack_check_flag!(direct, ['F', 'B'])?;
+        lapack_check_flag!(storev, ['C', 'R'])?;
+
+        // 2. dim check & buffer alloc
+        let (nq, nw) = if side == 'L' { (m, n) } else { (n, m) };
+        let v_dim = if storev == 'C' { (nq, k) } else { (k, nq) };
+        lapack_assert_eq!(t.dim(), (k, k), InvalidDim)?;
+        lapack_assert_eq!(v.dim(), v_dim, InvalidDim)?;
+        lapack_assert_le!(k, nq, InvalidDim)?;
+        let ldv = v.stride_of(Axis(1)).max(v_dim.0.max(1) as isize);
+        let ldt = t.stride_of(Axis(1)).max(k.max(1) as isize);
+        let ldc = c.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let ldwork = nw.max(1);
+        let work = Array2::zeros((ldwork, k.max(1)).f());
+
+        // 3. struct build
+        let mut driver = LARFB_Driver {
+            side: side as c_char,
+            trans: trans as c_char,
+            direct: direct as c_char,
+            storev: storev as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            v: v.view(),
+            ldv: ldv.try_into()?,
+            t: t.view(),
+            ldt: ldt.try_into()?,
+            c,
+            ldc: ldc.try_into()?,
+            work,
+            ldwork: ldwork.try_into()?,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_larfb(&mut driver)?;
+        }
+
+        // 5. finalize
+        let LARFB_Driver { c, .. } = driver;
+        return Ok(c.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type LARFB<'v, 't, 'c, F> = LARFB_Builder<'v, 't, 'c, F>;
+pub type SLARFB<'v, 't, 'c> = LARFB<'v, 't, 'c, f32>;
+pub type DLARFB<'v, 't, 'c> = LARFB<'v, 't, 'c, f64>;
+pub type CLARFB<'v, 't, 'c> = LARFB<'v, 't, 'c, c32>;
+pub type ZLARFB<'v, 't, 'c> = LARFB<'v, 't, 'c, c64>;
+
+impl<'v, 't, 'c, F> LARFB_Builder<'v, 't, 'c, F>
+where
+    F: LARFBNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.c.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let c = obj.driver()?;
+            let c = match c {
+                ArrayOut::Owned(c) => ArrayOut::Owned(c.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(c) => ArrayOut::ViewMut(c),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(c);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,109 @@
+use crate::ffi::{self, lapack_int};
+use crate::util::*;
+use derive_builder::Builder;
+
+/* #region Lapack driver */
+
+pub struct LARFG_Driver<'x, F>
+where
+    F: LapackFloat,
+{
+    n: lapack_int,
+    alpha: F,
+    x: ArrayOut1<'x, F>,
+    incx: lapack_int,
+    tau: F,
+}
+
+pub trait LARFGNum: LapackFloat {
+    unsafe fn run_larfg(driver: &mut LARFG_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl LARFGNum for $type {
+            unsafe fn run_larfg(driver: &mut LARFG_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.n,
+                    &mut driver.alpha,
+                    driver.x.get_data_mut_ptr(),
+                    &driver.incx,
+                    &mut driver.tau,
+                );
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, slarfg_);
+impl_func!(f64, dlarfg_);
+impl_func!(c32, clarfg_);
+impl_func!(c64, zlarfg_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Generate elementary reflector `H = I - τ v vᴴ` such that `Hᴴ [α; x] = [β; 0]`, with `v = [1; v₂]` and `β` real.
+///
+/// Output is `(β, v₂, τ)`; vector `x` of length `n - 1` is overwritten by `v₂` if given as mutable view.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct LARFG_<'x, F>
+where
+    F: LARFGNum,
+{
+    // input
+    pub alpha: F,
+
+    // input/output
+    #[builder(setter(into))]
+    pub x: ArrayViewOrMut1<'x, F>,
+}
+
+impl<'x, F> LARFG_<'x, F>
+where
+    F: LARFGNum,
+{
+    pub fn driver(self) -> Result<(F, ArrayOut1<'x, F>, F), LapackError> {
+        let Self { alpha, x } = self;
+
+        // 1. dim assign
+        let x = x.into_seq_array_out();
+        let n = x.view().len() + 1;
+
+        // 2. struct build
+        let mut driver = LARFG_Driver { n: n.try_into()?, alpha, x, incx: 1, tau: F::zero() };
+
+        // 3. perform comput
+        unsafe {
+            F::run_larfg(&mut driver)?;
+        }
+
+        // 4. finalize
+        let LARFG_Driver { alpha, x, tau, .. } = driver;
+        return Ok((alpha, x.clone_to_view_mut(), tau));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type LARFG<'x, F> = LARFG_Builder<'x, F>;
+pub type SLARFG<'x> = LARFG<'x, f32>;
+pub type DLARFG<'x> = LARFG<'x, f64>;
+pub type CLARFG<'x> = LARFG<'x, c32>;
+pub type ZLARFG<'x> = LARFG<'x, c64>;
+
+impl<'x, F> LARFG_Builder<'x, F>
+where
+    F: LARFGNum,
+{
+    pub fn run(self) -> Result<(F, ArrayOut1<'x, F>, F), LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,149 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct LARFT_Driver<'v, 'tau, F>
+where
+    F: LapackFloat,
+{
+    direct: c_char,
+    storev: c_char,
+    n: lapack_int,
+    k: lapack_int,
+    v: ArrayView2<'v, F>,
+    ldv: lapack_int,
+    tau: ArrayView1<'tau, F>,
+    t: Array2<F>,
+    ldt: lapack_int,
+}
+
+pub trait LARFTNum: LapackFloat {
+    unsafe fn run_larft(driver: &mut LARFT_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl LARFTNum for $type {
+            unsafe fn run_larft(driver: &mut LARFT_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.direct,
+                    &driver.storev,
+                    &driver.n,
+                    &driver.k,
+                    driver.v.as_ptr(),
+                    &driver.ldv,
+                    driver.tau.as_ptr(),
+                    driver.t.as_mut_ptr(),
+                    &driver.ldt,
+                );
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, slarft_);
+impl_func!(f64, dlarft_);
+impl_func!(c32, clarft_);
+impl_func!(c64, zlarft_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Triangular factor `T` of block reflector `H = I - V T Vᴴ` of order `n`, from `k` elementary reflectors.
+///
+/// With `k` as length of `tau`, `v` should be `(n, k)` for `storev = 'C'` (reflectors stored column-wise), or
+/// `(k, n)` for `storev = 'R'` (row-wise). `H = H₁ H₂ … Hₖ` and `T` is upper triangular for `direct = 'F'`;
+/// `H = Hₖ … H₂ H₁` and `T` is lower triangular for `direct = 'B'`. Output `T` is of shape `(k, k)`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct LARFT_<'v, 'tau, F>
+where
+    F: LARFTNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub v: ArrayView2<'v, F>,
+    #[builder(setter(into))]
+    pub tau: ArrayView1<'tau, F>,
+
+    // option
+    #[builder(default = "'F'")]
+    pub direct: char,
+    #[builder(default = "'C'")]
+    pub storev: char,
+}
+
+impl<'v, 'tau, F> LARFT_<'v, 'tau, F>
+where
+    F: LARFTNum,
+{
+    pub fn driver(self) -> Result<Array2<F>, LapackError> {
+        let Self { v, tau, direct, storev } = self;
+
+        // 1. dim assign, flag check
+        let v = v.to_col_layout()?;
+        let tau = tau.to_seq_layout()?;
+        let k = tau.len();
+        let direct = direct.to_ascii_uppercase();
+        let storev = storev.to_ascii_uppercase();
+        lapack_check_flag!(direct, ['F', 'B'])?;
+        lapack_check_flag!(storev, ['C', 'R'])?;
+
+        // 2. dim check & alloc
+        let n = if storev == 'C' { v.nrows() } else { v.ncols() };
+        let v_dim = if storev == 'C' { (n, k) } else { (k, n) };
+        lapack_assert_eq!(v.dim(), v_dim, InvalidDim)?;
+        lapack_assert_le!(1, k, InvalidDim)?;
+        lapack_assert_le!(k, n, InvalidDim)?;
+        let ldv = v.stride_of(Axis(1)).max(v_dim.0.max(1) as isize);
+        let t = Array2::zeros((k, k).f());
+
+        // 3. struct build
+        let mut driver = LARFT_Driver {
+            direct: direct as c_char,
+            storev: storev as c_char,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            v: v.view(),
+            ldv: ldv.try_into()?,
+            tau: tau.view(),
+            t,
+            ldt: k.try_into()?,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_larft(&mut driver)?;
+        }
+
+        // 5. finalize
+        let LARFT_Driver { t, .. } = driver;
+        return Ok(t);
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type LARFT<'v, 'tau, F> = LARFT_Builder<'v, 'tau, F>;
+pub type SLARFT<'v, 'tau> = LARFT<'v, 'tau, f32>;
+pub type DLARFT<'v, 'tau> = LARFT<'v, 'tau, f64>;
+pub type CLARFT<'v, 'tau> = LARFT<'v, 'tau, c32>;
+pub type ZLARFT<'v, 'tau> = LARFT<'v, 'tau, c64>;
+
+impl<'v, 'tau, F> LARFT_Builder<'v, 'tau, F>
+where
+    F: LARFTNum,
+{
+    pub fn run(self) -> Result<Array2<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,153 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct LARFX_Driver<'v, 'c, F>
+where
+    F: LapackFloat,
+{
+    side: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    v: ArrayView1<'v, F>,
+    tau: F,
+    c: ArrayOut2<'c, F>,
+    ldc: lapack_int,
+    work: Array1<F>,
+}
+
+pub trait LARFXNum: LapackFloat {
+    unsafe fn run_larfx(driver: &mut LARFX_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl LARFXNum for $type {
+            unsafe fn run_larfx(driver: &mut LARFX_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.side,
+                    &driver.m,
+                    &driver.n,
+                    driver.v.as_ptr(),
+                    &driver.tau,
+                    driver.c.get_data_mut_ptr(),
+                    &driver.ldc,
+                    driver.work.as_mut_ptr(),
+                );
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, slarfx_);
+impl_func!(f64, dlarfx_);
+impl_func!(c32, clarfx_);
+impl_func!(c64, zlarfx_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Apply elementary reflector `H = I - τ v vᴴ` to general matrix `C`: `H C` (`side = 'L'`) or `C H`
+/// (`side = 'R'`), with loops unrolled when order of `H` is at most 10.
+///
+/// Vector `v` should have length `m` for `side = 'L'`, or `n` for `side = 'R'`; its first element is referenced
+/// (pass `1` there for reflectors from `LARFG`). To apply `Hᴴ`, pass conjugate of `τ`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct LARFX_<'v, 'c, F>
+where
+    F: LARFXNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub v: ArrayView1<'v, F>,
+    pub tau: F,
+
+    // input/output
+    #[builder(setter(into))]
+    pub c: ArrayViewOrMut2<'c, F>,
+
+    // option
+    #[builder(default = "'L'")]
+    pub side: char,
+}
+
+impl<'v, 'c, F> LARFX_<'v, 'c, F>
+where
+    F: LARFXNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let Self { v, tau, c, side } = self;
+
+        // 1. dim assign, flag check
+        let v = v.to_seq_layout()?;
+        let c = c.into_col_array_out();
+        let (m, n) = c.view().dim();
+        let side = side.to_ascii_uppercase();
+        lapack_check_flag!(side, ['L', 'R'])?;
+
+        // 2. dim check & buffer alloc
+        let (nq, nw) = if side == 'L' { (m, n) } else { (n, m) };
+        lapack_assert_eq!(v.len(), nq, InvalidDim)?;
+        let ldc = c.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let work = Array1::zeros(nw.max(1));
+
+        // 3. struct build
+        let mut driver = LARFX_Driver {
+            side: side as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            v: v.view(),
+            tau,
+            c,
+            ldc: ldc.try_into()?,
+            work,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_larfx(&mut driver)?;
+        }
+
+        // 5. finalize
+        let LARFX_Driver { c, .. } = driver;
+        return Ok(c.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type LARFX<'v, 'c, F> = LARFX_Builder<'v, 'c, F>;
+pub type SLARFX<'v, 'c> = LARFX<'v, 'c, f32>;
+pub type DLARFX<'v, 'c> = LARFX<'v, 'c, f64>;
+pub type CLARFX<'v, 'c> = LARFX<'v, 'c, c32>;
+pub type ZLARFX<'v, 'c> = LARFX<'v, 'c, c64>;
+
+impl<'v, 'c, F> LARFX_Builder<'v, 'c, F>
+where
+    F: LARFXNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.c.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let c = obj.driver()?;
+            let c = match c {
+                ArrayOut::Owned(c) => ArrayOut::Owned(c.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(c) => ArrayOut::ViewMut(c),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(c);
+        }
+    }
+}
+
+/* #endregion */
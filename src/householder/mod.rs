@@ -0,0 +1,11 @@
+pub mod larf;
+pub mod larfb;
+pub mod larfg;
+pub mod larft;
+pub mod larfx;
+
+pub use larf::*;
+pub use larfb::*;
+pub use larfg::*;
+pub use larft::*;
+pub use larfx::*;
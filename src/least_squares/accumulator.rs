@@ -0,0 +1,207 @@
+use crate::linear_solve::{TRTRSNum, TRTRS};
+use crate::orthogonal_factor::{TPLQTNum, TPMLQTNum, TPMQRTNum, TPQRTNum, TPLQT, TPMLQT, TPMQRT, TPQRT};
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::{Float, Zero};
+
+/// Block size for `TPQRT`/`TPLQT` updates of the accumulators.
+const ACCUMULATOR_BLOCK_SIZE: usize = 32;
+
+/// Sum of squares of each column.
+fn column_sum_squares<F>(b: ArrayView2<F>) -> Array1<F::RealFloat>
+where
+    F: LapackFloat,
+{
+    b.axis_iter(Axis(1))
+        .map(|col| col.fold(F::RealFloat::zero(), |acc, &x| acc + F::abs(x) * F::abs(x)))
+        .collect()
+}
+
+/* #region QR accumulator */
+
+/// Streaming least squares `min |A X - B|`, absorbing rows of `A` and `B` block by block.
+///
+/// Only the `(n, n)` upper triangular factor `R`, the projected right-hand side `Qᴴ B` of shape `(n, nrhs)` and
+/// the residual sum of squares are kept. Each block of rows is merged into `R` by `TPQRT`, and `Qᴴ` is applied to
+/// the right-hand side by `TPMQRT`.
+#[derive(Debug, Clone)]
+pub struct QrAccumulator<F>
+where
+    F: LapackFloat,
+{
+    r: Array2<F>,
+    qhb: Array2<F>,
+    rss: Array1<F::RealFloat>,
+    rows_seen: usize,
+}
+
+impl<F> QrAccumulator<F>
+where
+    F: LapackFloat,
+{
+    /// Empty accumulator for `n` unknowns and `nrhs` right-hand sides.
+    pub fn new(n: usize, nrhs: usize) -> Self {
+        Self {
+            r: Array2::zeros((n, n).f()),
+            qhb: Array2::zeros((n, nrhs).f()),
+            rss: Array1::zeros(nrhs),
+            rows_seen: 0,
+        }
+    }
+
+    /// Upper triangular factor `R` of shape `(n, n)` of all rows seen so far.
+    pub fn r(&self) -> ArrayView2<'_, F> {
+        self.r.view()
+    }
+
+    /// Projected right-hand side `Qᴴ B` of shape `(n, nrhs)`.
+    pub fn qhb(&self) -> ArrayView2<'_, F> {
+        self.qhb.view()
+    }
+
+    /// Number of rows absorbed so far.
+    pub fn rows_seen(&self) -> usize {
+        self.rows_seen
+    }
+
+    /// Residual norm `|A X - B|` of each right-hand side, for the least squares solution `X` of rows seen so far.
+    pub fn residual_norm(&self) -> Array1<F::RealFloat> {
+        self.rss.mapv(|x| x.sqrt())
+    }
+}
+
+impl<F> QrAccumulator<F>
+where
+    F: TPQRTNum + TPMQRTNum + TRTRSNum,
+{
+    /// Absorb a block of rows: `a` of shape `(rows, n)` and `b` of shape `(rows, nrhs)`.
+    pub fn absorb(&mut self, a: ArrayView2<F>, b: ArrayView2<F>) -> Result<(), LapackError> {
+        let (n, nrhs) = self.qhb.dim();
+        let rows = a.nrows();
+        lapack_assert_eq!(a.ncols(), n, InvalidDim)?;
+        lapack_assert_eq!(b.dim(), (rows, nrhs), InvalidDim)?;
+        if rows == 0 {
+            return Ok(());
+        }
+
+        let nb = n.clamp(1, ACCUMULATOR_BLOCK_SIZE);
+        let mut v = Array2::zeros((rows, n).f());
+        v.assign(&a);
+        let mut e = Array2::zeros((rows, nrhs).f());
+        e.assign(&b);
+
+        let (_, _, t) = TPQRT::default().a(self.r.view_mut()).b(v.view_mut()).nb(nb).run()?;
+        TPMQRT::default().v(v.view()).t(t.view()).a(self.qhb.view_mut()).b(e.view_mut()).trans('C').run()?;
+
+        self.rss += &column_sum_squares(e.view());
+        self.rows_seen += rows;
+        return Ok(());
+    }
+
+    /// Least squares solution `X` of shape `(n, nrhs)`, by solving `R X = Qᴴ B`.
+    ///
+    /// Fails if `R` is singular, e.g. fewer than `n` rows have been absorbed.
+    pub fn solve(&self) -> Result<Array2<F>, LapackError> {
+        let mut x = self.qhb.clone();
+        TRTRS::default().a(self.r.view()).b(x.view_mut()).uplo('U').run()?;
+        return Ok(x);
+    }
+}
+
+/* #endregion */
+
+/* #region LQ accumulator */
+
+/// Streaming least squares `min |Aᴴ X - B|`, absorbing columns of `A` (and the matching rows of `B`) block by
+/// block.
+///
+/// This is the column-wise analogue of [`QrAccumulator`]: for `A` of shape `(m, cols)`, only the `(m, m)` lower
+/// triangular factor `L`, the projected right-hand side `Q B` of shape `(m, nrhs)` and the residual sum of
+/// squares are kept. Each block of columns is merged into `L` by `TPLQT`, and `Q` is applied to the right-hand
+/// side by `TPMLQT`.
+#[derive(Debug, Clone)]
+pub struct LqAccumulator<F>
+where
+    F: LapackFloat,
+{
+    l: Array2<F>,
+    qb: Array2<F>,
+    rss: Array1<F::RealFloat>,
+    cols_seen: usize,
+}
+
+impl<F> LqAccumulator<F>
+where
+    F: LapackFloat,
+{
+    /// Empty accumulator for `m` unknowns and `nrhs` right-hand sides.
+    pub fn new(m: usize, nrhs: usize) -> Self {
+        Self {
+            l: Array2::zeros((m, m).f()),
+            qb: Array2::zeros((m, nrhs).f()),
+            rss: Array1::zeros(nrhs),
+            cols_seen: 0,
+        }
+    }
+
+    /// Lower triangular factor `L` of shape `(m, m)` of all columns seen so far.
+    pub fn l(&self) -> ArrayView2<'_, F> {
+        self.l.view()
+    }
+
+    /// Projected right-hand side `Q B` of shape `(m, nrhs)`.
+    pub fn qb(&self) -> ArrayView2<'_, F> {
+        self.qb.view()
+    }
+
+    /// Number of columns absorbed so far.
+    pub fn cols_seen(&self) -> usize {
+        self.cols_seen
+    }
+
+    /// Residual norm `|Aᴴ X - B|` of each right-hand side, for the least squares solution `X` of columns seen so
+    /// far.
+    pub fn residual_norm(&self) -> Array1<F::RealFloat> {
+        self.rss.mapv(|x| x.sqrt())
+    }
+}
+
+impl<F> LqAccumulator<F>
+where
+    F: TPLQTNum + TPMLQTNum + TRTRSNum,
+{
+    /// Absorb a block of columns: `a` of shape `(m, cols)` and `b` of shape `(cols, nrhs)`.
+    pub fn absorb(&mut self, a: ArrayView2<F>, b: ArrayView2<F>) -> Result<(), LapackError> {
+        let (m, nrhs) = self.qb.dim();
+        let cols = a.ncols();
+        lapack_assert_eq!(a.nrows(), m, InvalidDim)?;
+        lapack_assert_eq!(b.dim(), (cols, nrhs), InvalidDim)?;
+        if cols == 0 {
+            return Ok(());
+        }
+
+        let mb = m.clamp(1, ACCUMULATOR_BLOCK_SIZE);
+        let mut v = Array2::zeros((m, cols).f());
+        v.assign(&a);
+        let mut e = Array2::zeros((cols, nrhs).f());
+        e.assign(&b);
+
+        let (_, _, t) = TPLQT::default().a(self.l.view_mut()).b(v.view_mut()).mb(mb).run()?;
+        TPMLQT::default().v(v.view()).t(t.view()).a(self.qb.view_mut()).b(e.view_mut()).trans('N').run()?;
+
+        self.rss += &column_sum_squares(e.view());
+        self.cols_seen += cols;
+        return Ok(());
+    }
+
+    /// Least squares solution `X` of shape `(m, nrhs)`, by solving `Lᴴ X = Q B`.
+    ///
+    /// Fails if `L` is singular, e.g. fewer than `m` columns have been absorbed.
+    pub fn solve(&self) -> Result<Array2<F>, LapackError> {
+        let mut x = self.qb.clone();
+        TRTRS::default().a(self.l.view()).b(x.view_mut()).uplo('L').trans('C').run()?;
+        return Ok(x);
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,249 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/* #region Lapack driver */
+
+pub struct GELS_Driver<'a, 'b, 'work, F>
+where
+    F: LapackFloat,
+{
+    trans: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    nrhs: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GELSNum: LapackFloat {
+    unsafe fn run_gels(driver: &mut GELS_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GELSNum for $type {
+            unsafe fn run_gels(driver: &mut GELS_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.trans,
+                    &driver.m,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgels_);
+impl_func!(f64, dgels_);
+impl_func!(c32, cgels_);
+impl_func!(c64, zgels_);
+
+/* #endregion */
+
+/* #region least squares utilities */
+
+/// Prepare right-hand side of least squares problem for LAPACK.
+///
+/// LAPACK requires `b` to have `max(m, n)` rows, since the same array holds both the right-hand side (`rows_in`
+/// rows) and the solution (`rows_out` rows). If `b` only has `rows_in` rows and `rows_in < max(m, n)`, an owned
+/// column-major array with `max(m, n)` rows is allocated, and input `b` is left unchanged.
+pub(crate) fn lstsq_prepare_b<'b, F>(
+    b: ArrayViewOrMut2<'b, F>,
+    rows_in: usize,
+    rows_max: usize,
+) -> Result<ArrayOut2<'b, F>, LapackError>
+where
+    F: LapackFloat,
+{
+    let nrows = b.view().nrows();
+    if nrows == rows_max {
+        return Ok(b.into_col_array_out());
+    }
+    lapack_assert_eq!(nrows, rows_in, InvalidDim)?;
+    let nrhs = b.view().ncols();
+    let mut b_ext = Array2::zeros((rows_max, nrhs).f());
+    b_ext.slice_mut(s![..rows_in, ..]).assign(&b.view());
+    return Ok(ArrayOut::Owned(b_ext));
+}
+
+/// Split LAPACK output `b` into the solution (first `rows_out` rows) and residual sum of squares per column
+/// (from rows `rows_out..rows_in`, only available if `rows_in > rows_out`).
+pub(crate) fn lstsq_finalize_b<F>(
+    b: ArrayOut2<'_, F>,
+    rows_in: usize,
+    rows_out: usize,
+) -> Result<GELS_Output<'_, F>, LapackError>
+where
+    F: LapackFloat,
+{
+    let residual = match rows_in > rows_out {
+        true => Some(
+            b.view()
+                .slice(s![rows_out..rows_in, ..])
+                .columns()
+                .into_iter()
+                .map(|col| col.iter().fold(F::RealFloat::zero(), |acc, &x| acc + F::abs(x) * F::abs(x)))
+                .collect(),
+        ),
+        false => None,
+    };
+    let x = match b.clone_to_view_mut() {
+        ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b.slice_move(s![..rows_out, ..])),
+        ArrayOut::Owned(b) => ArrayOut::Owned(b.slice_move(s![..rows_out, ..])),
+        _ => lapack_raise!(RuntimeError)?,
+    };
+    return Ok((x, residual));
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`GELS`]: solution `x` and, for overdetermined systems, residual sum of squares of each column.
+pub type GELS_Output<'b, F> = (ArrayOut2<'b, F>, Option<Array1<<F as LapackFloat>::RealFloat>>);
+
+/// Least squares or minimum norm solution of full-rank `op(A) X = B` by QR or LQ factorization.
+///
+/// With `op(A)` of shape `(m', n')` (`(m, n)` for `trans = 'N'`, `(n, m)` otherwise), `b` could have either
+/// `m'` rows, or `max(m, n)` rows, with the right-hand side in its first `m'` rows. In the latter case `b` is
+/// overwritten in place; in the former case with `m' < max(m, n)`, an owned array is allocated.
+///
+/// Output is the solution with `n'` rows, and, for overdetermined systems (`m' > n'`), the residual sum of
+/// squares of each column. Matrix `a` is overwritten by its QR or LQ factors.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GELS_<'a, 'b, 'work, F>
+where
+    F: GELSNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    #[builder(default = "'N'")]
+    pub trans: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'b, 'work, F> GELS_<'a, 'b, 'work, F>
+where
+    F: GELSNum,
+{
+    pub fn driver(self) -> Result<GELS_Output<'b, F>, LapackError> {
+        let Self { a, b, trans, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let trans = trans.to_ascii_uppercase();
+        let trans = if F::is_complex() || trans != 'C' { trans } else { 'T' };
+        match F::is_complex() {
+            true => lapack_check_flag!(trans, ['N', 'C'])?,
+            false => lapack_check_flag!(trans, ['N', 'T'])?,
+        };
+        let (rows_in, rows_out) = if trans == 'N' { (m, n) } else { (n, m) };
+        let rows_max = m.max(n);
+
+        // 2. dim check
+        let b = lstsq_prepare_b(b, rows_in, rows_max)?;
+        let nrhs = b.view().ncols();
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(rows_max.max(1) as isize);
+
+        // 3. buffer check & alloc
+        let mn = m.min(n);
+        let (work, query_work) = ArrayOut1::optional_buffer(work, (mn + mn.max(nrhs)).max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GELS_Driver {
+            trans: trans as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_gels(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_gels(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GELS_Driver { a, b, .. } = driver;
+        a.clone_to_view_mut(); // write back factors if `a` was cloned for layout
+        return lstsq_finalize_b(b, rows_in, rows_out);
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GELS<'a, 'b, 'work, F> = GELS_Builder<'a, 'b, 'work, F>;
+pub type SGELS<'a, 'b, 'work> = GELS<'a, 'b, 'work, f32>;
+pub type DGELS<'a, 'b, 'work> = GELS<'a, 'b, 'work, f64>;
+pub type CGELS<'a, 'b, 'work> = GELS<'a, 'b, 'work, c32>;
+pub type ZGELS<'a, 'b, 'work> = GELS<'a, 'b, 'work, c64>;
+
+impl<'a, 'b, 'work, F> GELS_Builder<'a, 'b, 'work, F>
+where
+    F: GELSNum,
+{
+    pub fn run(self) -> Result<GELS_Output<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (x, residual) = obj.driver()?;
+            let x = match x {
+                ArrayOut::Owned(x) => ArrayOut::Owned(x.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(x) => ArrayOut::ViewMut(x),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((x, residual));
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,187 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::least_squares::gels::{lstsq_finalize_b, lstsq_prepare_b, GELS_Output};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GETSLS_Driver<'a, 'b, 'work, F>
+where
+    F: LapackFloat,
+{
+    trans: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    nrhs: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GETSLSNum: LapackFloat {
+    unsafe fn run_getsls(driver: &mut GETSLS_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GETSLSNum for $type {
+            unsafe fn run_getsls(driver: &mut GETSLS_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.trans,
+                    &driver.m,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgetsls_);
+impl_func!(f64, dgetsls_);
+impl_func!(c32, cgetsls_);
+impl_func!(c64, zgetsls_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Least squares or minimum norm solution of full-rank `op(A) X = B` by tall-skinny QR or short-wide LQ
+/// factorization.
+///
+/// Same interface as [`GELS`](crate::least_squares::GELS), but more efficient for very tall or very wide `a`.
+///
+/// Workspace `work` is queried if not given; a buffer given by user (larger than one element) is used as-is, so
+/// it could be allocated once and reused among calls.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GETSLS_<'a, 'b, 'work, F>
+where
+    F: GETSLSNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    #[builder(default = "'N'")]
+    pub trans: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'b, 'work, F> GETSLS_<'a, 'b, 'work, F>
+where
+    F: GETSLSNum,
+{
+    pub fn driver(self) -> Result<GELS_Output<'b, F>, LapackError> {
+        let Self { a, b, trans, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let trans = trans.to_ascii_uppercase();
+        let trans = if F::is_complex() || trans != 'C' { trans } else { 'T' };
+        match F::is_complex() {
+            true => lapack_check_flag!(trans, ['N', 'C'])?,
+            false => lapack_check_flag!(trans, ['N', 'T'])?,
+        };
+        let (rows_in, rows_out) = if trans == 'N' { (m, n) } else { (n, m) };
+        let rows_max = m.max(n);
+
+        // 2. dim check
+        let b = lstsq_prepare_b(b, rows_in, rows_max)?;
+        let nrhs = b.view().ncols();
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(rows_max.max(1) as isize);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, 1, true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GETSLS_Driver {
+            trans: trans as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_getsls(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_getsls(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GETSLS_Driver { a, b, .. } = driver;
+        a.clone_to_view_mut(); // write back factors if `a` was cloned for layout
+        return lstsq_finalize_b(b, rows_in, rows_out);
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GETSLS<'a, 'b, 'work, F> = GETSLS_Builder<'a, 'b, 'work, F>;
+pub type SGETSLS<'a, 'b, 'work> = GETSLS<'a, 'b, 'work, f32>;
+pub type DGETSLS<'a, 'b, 'work> = GETSLS<'a, 'b, 'work, f64>;
+pub type CGETSLS<'a, 'b, 'work> = GETSLS<'a, 'b, 'work, c32>;
+pub type ZGETSLS<'a, 'b, 'work> = GETSLS<'a, 'b, 'work, c64>;
+
+impl<'a, 'b, 'work, F> GETSLS_Builder<'a, 'b, 'work, F>
+where
+    F: GETSLSNum,
+{
+    pub fn run(self) -> Result<GELS_Output<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (x, residual) = obj.driver()?;
+            let x = match x {
+                ArrayOut::Owned(x) => ArrayOut::Owned(x.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(x) => ArrayOut::ViewMut(x),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((x, residual));
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,179 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GGGLM_Driver<'a, 'b, 'd, 'work, F>
+where
+    F: LapackFloat,
+{
+    n: lapack_int,
+    m: lapack_int,
+    p: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    d: ArrayOut1<'d, F>,
+    x: Array1<F>,
+    y: Array1<F>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GGGLMNum: LapackFloat {
+    unsafe fn run_ggglm(driver: &mut GGGLM_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GGGLMNum for $type {
+            unsafe fn run_ggglm(driver: &mut GGGLM_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.n,
+                    &driver.m,
+                    &driver.p,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    driver.d.get_data_mut_ptr(),
+                    driver.x.as_mut_ptr(),
+                    driver.y.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sggglm_);
+impl_func!(f64, dggglm_);
+impl_func!(c32, cggglm_);
+impl_func!(c64, zggglm_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// General Gauss-Markov linear model problem `min |y|` subject to `d = A x + B y`.
+///
+/// Matrix `a` is `(n, m)` and `b` is `(n, p)`, with `m <= n <= m + p`. All of `a`, `b` and `d` are destroyed on
+/// exit.
+///
+/// Output is the solution `(x, y)`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GGGLM_<'a, 'b, 'd, 'work, F>
+where
+    F: GGGLMNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+    #[builder(setter(into))]
+    pub d: ArrayViewOrMut1<'d, F>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'b, 'd, 'work, F> GGGLM_<'a, 'b, 'd, 'work, F>
+where
+    F: GGGLMNum,
+{
+    pub fn driver(self) -> Result<(Array1<F>, Array1<F>), LapackError> {
+        let Self { a, b, d, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let b = b.into_col_array_out();
+        let d = d.into_seq_array_out();
+        let (n, m) = a.view().dim();
+        let p = b.view().ncols();
+
+        // 2. dim check
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        lapack_assert_eq!(d.view().len(), n, InvalidDim)?;
+        lapack_assert_le!(m, n, InvalidDim)?;
+        lapack_assert_le!(n, m + p, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(n.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+        let x = Array1::zeros(m);
+        let y = Array1::zeros(p);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, (n + m + p).max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GGGLM_Driver {
+            n: n.try_into()?,
+            m: m.try_into()?,
+            p: p.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            d,
+            x,
+            y,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_ggglm(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_ggglm(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GGGLM_Driver { a, b, d, x, y, .. } = driver;
+        a.clone_to_view_mut();
+        b.clone_to_view_mut();
+        d.clone_to_view_mut();
+        return Ok((x, y));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GGGLM<'a, 'b, 'd, 'work, F> = GGGLM_Builder<'a, 'b, 'd, 'work, F>;
+pub type SGGGLM<'a, 'b, 'd, 'work> = GGGLM<'a, 'b, 'd, 'work, f32>;
+pub type DGGGLM<'a, 'b, 'd, 'work> = GGGLM<'a, 'b, 'd, 'work, f64>;
+pub type CGGGLM<'a, 'b, 'd, 'work> = GGGLM<'a, 'b, 'd, 'work, c32>;
+pub type ZGGGLM<'a, 'b, 'd, 'work> = GGGLM<'a, 'b, 'd, 'work, c64>;
+
+impl<'a, 'b, 'd, 'work, F> GGGLM_Builder<'a, 'b, 'd, 'work, F>
+where
+    F: GGGLMNum,
+{
+    pub fn run(self) -> Result<(Array1<F>, Array1<F>), LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
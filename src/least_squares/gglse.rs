@@ -0,0 +1,187 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/* #region Lapack driver */
+
+pub struct GGLSE_Driver<'a, 'b, 'c, 'd, 'work, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    p: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    c: ArrayOut1<'c, F>,
+    d: ArrayOut1<'d, F>,
+    x: Array1<F>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GGLSENum: LapackFloat {
+    unsafe fn run_gglse(driver: &mut GGLSE_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GGLSENum for $type {
+            unsafe fn run_gglse(driver: &mut GGLSE_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    &driver.p,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    driver.c.get_data_mut_ptr(),
+                    driver.d.get_data_mut_ptr(),
+                    driver.x.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgglse_);
+impl_func!(f64, dgglse_);
+impl_func!(c32, cgglse_);
+impl_func!(c64, zgglse_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Linear equality-constrained least squares problem `min |c - A x|` subject to `B x = d`.
+///
+/// Matrix `a` is `(m, n)` and `b` is `(p, n)`, with `p <= n <= m + p`. All of `a`, `b`, `c` and `d` are destroyed
+/// on exit.
+///
+/// Output is the solution `x` and the residual sum of squares `|c - A x|^2`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GGLSE_<'a, 'b, 'c, 'd, 'work, F>
+where
+    F: GGLSENum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+    #[builder(setter(into))]
+    pub c: ArrayViewOrMut1<'c, F>,
+    #[builder(setter(into))]
+    pub d: ArrayViewOrMut1<'d, F>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'b, 'c, 'd, 'work, F> GGLSE_<'a, 'b, 'c, 'd, 'work, F>
+where
+    F: GGLSENum,
+{
+    pub fn driver(self) -> Result<(Array1<F>, F::RealFloat), LapackError> {
+        let Self { a, b, c, d, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let b = b.into_col_array_out();
+        let c = c.into_seq_array_out();
+        let d = d.into_seq_array_out();
+        let (m, n) = a.view().dim();
+        let p = b.view().nrows();
+
+        // 2. dim check
+        lapack_assert_eq!(b.view().ncols(), n, InvalidDim)?;
+        lapack_assert_eq!(c.view().len(), m, InvalidDim)?;
+        lapack_assert_eq!(d.view().len(), p, InvalidDim)?;
+        lapack_assert_le!(p, n, InvalidDim)?;
+        lapack_assert_le!(n, m + p, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(p.max(1) as isize);
+        let x = Array1::zeros(n);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, (m + n + p).max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GGLSE_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            p: p.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            c,
+            d,
+            x,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_gglse(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_gglse(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GGLSE_Driver { a, b, c, d, x, .. } = driver;
+        // residual sum of squares in elements n-p..m of c
+        let rss =
+            c.view().slice(s![(n - p)..]).fold(F::RealFloat::zero(), |acc, &v| acc + F::abs(v) * F::abs(v));
+        a.clone_to_view_mut();
+        b.clone_to_view_mut();
+        c.clone_to_view_mut();
+        d.clone_to_view_mut();
+        return Ok((x, rss));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GGLSE<'a, 'b, 'c, 'd, 'work, F> = GGLSE_Builder<'a, 'b, 'c, 'd, 'work, F>;
+pub type SGGLSE<'a, 'b, 'c, 'd, 'work> = GGLSE<'a, 'b, 'c, 'd, 'work, f32>;
+pub type DGGLSE<'a, 'b, 'c, 'd, 'work> = GGLSE<'a, 'b, 'c, 'd, 'work, f64>;
+pub type CGGLSE<'a, 'b, 'c, 'd, 'work> = GGLSE<'a, 'b, 'c, 'd, 'work, c32>;
+pub type ZGGLSE<'a, 'b, 'c, 'd, 'work> = GGLSE<'a, 'b, 'c, 'd, 'work, c64>;
+
+impl<'a, 'b, 'c, 'd, 'work, F> GGLSE_Builder<'a, 'b, 'c, 'd, 'work, F>
+where
+    F: GGLSENum,
+{
+    pub fn run(self) -> Result<(Array1<F>, F::RealFloat), LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,358 @@
+use crate::ffi::{self, lapack_int};
+use crate::least_squares::gels::{lstsq_finalize_b, lstsq_prepare_b};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::{NumCast, One, Zero};
+
+/* #region Lapack driver */
+
+pub struct LSTSQ_Driver<'a, 'b, 'work, F>
+where
+    F: LapackFloat,
+{
+    method: char,
+    m: lapack_int,
+    n: lapack_int,
+    nrhs: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    s: Array1<F::RealFloat>,
+    jpvt: Array1<lapack_int>,
+    rcond: F::RealFloat,
+    rank: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    rwork: Array1<F::RealFloat>,
+    iwork: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait LSTSQNum: LapackFloat {
+    unsafe fn run_lstsq(driver: &mut LSTSQ_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func_d:ident, $func_s:ident, $func_y:ident) => {
+        impl LSTSQNum for $type {
+            unsafe fn run_lstsq(driver: &mut LSTSQ_Driver<Self>) -> Result<(), LapackError> {
+                match driver.method {
+                    'D' => ffi::$func_d(
+                        &driver.m,
+                        &driver.n,
+                        &driver.nrhs,
+                        driver.a.get_data_mut_ptr(),
+                        &driver.lda,
+                        driver.b.get_data_mut_ptr(),
+                        &driver.ldb,
+                        driver.s.as_mut_ptr(),
+                        &driver.rcond,
+                        &mut driver.rank,
+                        driver.work.get_data_mut_ptr(),
+                        &driver.lwork,
+                        driver.iwork.as_mut_ptr(),
+                        &mut driver.info,
+                    ),
+                    'S' => ffi::$func_s(
+                        &driver.m,
+                        &driver.n,
+                        &driver.nrhs,
+                        driver.a.get_data_mut_ptr(),
+                        &driver.lda,
+                        driver.b.get_data_mut_ptr(),
+                        &driver.ldb,
+                        driver.s.as_mut_ptr(),
+                        &driver.rcond,
+                        &mut driver.rank,
+                        driver.work.get_data_mut_ptr(),
+                        &driver.lwork,
+                        &mut driver.info,
+                    ),
+                    'Y' => ffi::$func_y(
+                        &driver.m,
+                        &driver.n,
+                        &driver.nrhs,
+                        driver.a.get_data_mut_ptr(),
+                        &driver.lda,
+                        driver.b.get_data_mut_ptr(),
+                        &driver.ldb,
+                        driver.jpvt.as_mut_ptr(),
+                        &driver.rcond,
+                        &mut driver.rank,
+                        driver.work.get_data_mut_ptr(),
+                        &driver.lwork,
+                        &mut driver.info,
+                    ),
+                    _ => lapack_invalid!(driver.method)?,
+                };
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func_d:ident, $func_s:ident, $func_y:ident) => {
+        impl LSTSQNum for $type {
+            unsafe fn run_lstsq(driver: &mut LSTSQ_Driver<Self>) -> Result<(), LapackError> {
+                match driver.method {
+                    'D' => ffi::$func_d(
+                        &driver.m,
+                        &driver.n,
+                        &driver.nrhs,
+                        driver.a.get_data_mut_ptr(),
+                        &driver.lda,
+                        driver.b.get_data_mut_ptr(),
+                        &driver.ldb,
+                        driver.s.as_mut_ptr(),
+                        &driver.rcond,
+                        &mut driver.rank,
+                        driver.work.get_data_mut_ptr(),
+                        &driver.lwork,
+                        driver.rwork.as_mut_ptr(),
+                        driver.iwork.as_mut_ptr(),
+                        &mut driver.info,
+                    ),
+                    'S' => ffi::$func_s(
+                        &driver.m,
+                        &driver.n,
+                        &driver.nrhs,
+                        driver.a.get_data_mut_ptr(),
+                        &driver.lda,
+                        driver.b.get_data_mut_ptr(),
+                        &driver.ldb,
+                        driver.s.as_mut_ptr(),
+                        &driver.rcond,
+                        &mut driver.rank,
+                        driver.work.get_data_mut_ptr(),
+                        &driver.lwork,
+                        driver.rwork.as_mut_ptr(),
+                        &mut driver.info,
+                    ),
+                    'Y' => ffi::$func_y(
+                        &driver.m,
+                        &driver.n,
+                        &driver.nrhs,
+                        driver.a.get_data_mut_ptr(),
+                        &driver.lda,
+                        driver.b.get_data_mut_ptr(),
+                        &driver.ldb,
+                        driver.jpvt.as_mut_ptr(),
+                        &driver.rcond,
+                        &mut driver.rank,
+                        driver.work.get_data_mut_ptr(),
+                        &driver.lwork,
+                        driver.rwork.as_mut_ptr(),
+                        &mut driver.info,
+                    ),
+                    _ => lapack_invalid!(driver.method)?,
+                };
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sgelsd_, sgelss_, sgelsy_);
+impl_func_real!(f64, dgelsd_, dgelss_, dgelsy_);
+impl_func_comp!(c32, cgelsd_, cgelss_, cgelsy_);
+impl_func_comp!(c64, zgelsd_, zgelss_, zgelsy_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`LSTSQ`].
+#[derive(Debug)]
+pub struct LSTSQ_Output<'b, F>
+where
+    F: LapackFloat,
+{
+    /// Minimum norm least squares solution, with `n` rows.
+    pub x: ArrayOut2<'b, F>,
+    /// Effective rank of `a`.
+    pub rank: usize,
+    /// Singular values of `a` in decreasing order (method `'D'` and `'S'`).
+    pub s: Option<Array1<F::RealFloat>>,
+    /// Column permutation of complete orthogonal factorization (method `'Y'`), 0-based: column `k` of `A P` is
+    /// column `jpvt[k]` of `A`. Columns `jpvt[rank..]` are the ones regarded as linearly dependent.
+    pub jpvt: Option<Array1<usize>>,
+}
+
+/// Minimum norm solution of possibly rank-deficient least squares problem `min |A X - B|`.
+///
+/// Method (routine) could be
+/// - `'D'`: `GELSD`, SVD with divide-and-conquer (default);
+/// - `'S'`: `GELSS`, SVD;
+/// - `'Y'`: `GELSY`, complete orthogonal factorization with column pivoting.
+///
+/// Rank is determined by `rcond`:
+/// - `'D'`, `'S'`: singular values `s[i] <= rcond * s[0]` are treated as zero; negative `rcond` (default) means
+///   machine precision `ε`;
+/// - `'Y'`: leading triangular factor is truncated where its estimated condition number exceeds `1 / rcond`;
+///   GELSY itself has no fallback for negative `rcond`, so negative `rcond` (default) is replaced by
+///   `max(m, n) · ε` here.
+///
+/// Argument `b` could have either `m` or `max(m, n)` rows, see [`GELS`](crate::least_squares::GELS). Matrix
+/// `a` is destroyed (or overwritten by intermediate factors) on exit.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct LSTSQ_<'a, 'b, 'work, F>
+where
+    F: LSTSQNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    #[builder(default = "F::RealFloat::zero() - F::RealFloat::one()")]
+    pub rcond: F::RealFloat,
+    #[builder(default = "'D'")]
+    pub method: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'b, 'work, F> LSTSQ_<'a, 'b, 'work, F>
+where
+    F: LSTSQNum,
+{
+    pub fn driver(self) -> Result<LSTSQ_Output<'b, F>, LapackError> {
+        let Self { a, b, rcond, method, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let method = method.to_ascii_uppercase();
+        lapack_check_flag!(method, ['D', 'S', 'Y'])?;
+        let mn = m.min(n);
+        let rows_max = m.max(n);
+        let rcond = match method == 'Y' && rcond < F::RealFloat::zero() {
+            true => <F::RealFloat as NumCast>::from(rows_max).unwrap() * F::EPSILON,
+            false => rcond,
+        };
+
+        // 2. dim check & alloc
+        let b = lstsq_prepare_b(b, m, rows_max)?;
+        let nrhs = b.view().ncols();
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(rows_max.max(1) as isize);
+        let s = Array1::zeros(if method == 'Y' { 0 } else { mn });
+        let jpvt = Array1::zeros(if method == 'Y' { n } else { 0 });
+
+        // 3. buffer check & alloc
+        // rwork and iwork of GELSD are obtained from workspace query
+        let (work, query_work) = ArrayOut1::optional_buffer(work, 1, true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+        let rwork_size = match (F::is_complex(), method) {
+            (false, _) => 0,
+            (true, 'S') => 5 * mn,
+            (true, 'Y') => 2 * n,
+            _ => 0,
+        };
+        let rwork = Array1::zeros(rwork_size.max(1));
+        let iwork = Array1::zeros(1);
+
+        // 4. struct build
+        let mut driver = LSTSQ_Driver {
+            method,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            s,
+            jpvt,
+            rcond,
+            rank: 0,
+            work,
+            lwork: lwork.try_into()?,
+            rwork,
+            iwork,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work || method == 'D' {
+            let lwork_user = driver.lwork;
+            let work_user = core::mem::replace(&mut driver.work, ArrayOut1::Owned(Array1::zeros(1)));
+            driver.lwork = -1;
+            unsafe {
+                F::run_lstsq(&mut driver)?;
+            }
+            if method == 'D' {
+                let lrwork = F::ftoi(F::from_real(driver.rwork[0])).max(0) as usize;
+                let liwork = driver.iwork[0].max(0) as usize;
+                driver.rwork = Array1::zeros(lrwork.max(rwork_size).max(1));
+                driver.iwork = Array1::zeros(liwork.max(1));
+            }
+            if query_work {
+                let lwork = F::ftoi(driver.work.view()[0]).max(1);
+                driver.lwork = lwork.try_into()?;
+                driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+            } else {
+                driver.lwork = lwork_user;
+                driver.work = work_user;
+            }
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_lstsq(&mut driver)?;
+        }
+
+        // 7. finalize
+        let LSTSQ_Driver { a, b, s, jpvt, rank, .. } = driver;
+        a.clone_to_view_mut(); // write back if `a` was cloned for layout
+        let (x, _) = lstsq_finalize_b(b, m, n)?;
+        let rank = rank as usize;
+        let (s, jpvt) = match method {
+            'Y' => (None, Some(jpvt.mapv(|p| (p - 1) as usize))),
+            _ => (Some(s), None),
+        };
+        return Ok(LSTSQ_Output { x, rank, s, jpvt });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type LSTSQ<'a, 'b, 'work, F> = LSTSQ_Builder<'a, 'b, 'work, F>;
+pub type SLSTSQ<'a, 'b, 'work> = LSTSQ<'a, 'b, 'work, f32>;
+pub type DLSTSQ<'a, 'b, 'work> = LSTSQ<'a, 'b, 'work, f64>;
+pub type CLSTSQ<'a, 'b, 'work> = LSTSQ<'a, 'b, 'work, c32>;
+pub type ZLSTSQ<'a, 'b, 'work> = LSTSQ<'a, 'b, 'work, c64>;
+
+impl<'a, 'b, 'work, F> LSTSQ_Builder<'a, 'b, 'work, F>
+where
+    F: LSTSQNum,
+{
+    pub fn run(self) -> Result<LSTSQ_Output<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let LSTSQ_Output { x, rank, s, jpvt } = obj.driver()?;
+            let x = match x {
+                ArrayOut::Owned(x) => ArrayOut::Owned(x.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(x) => ArrayOut::ViewMut(x),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(LSTSQ_Output { x, rank, s, jpvt });
+        }
+    }
+}
+
+/* #endregion */
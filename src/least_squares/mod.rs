@@ -0,0 +1,13 @@
+pub mod accumulator;
+pub mod gels;
+pub mod getsls;
+pub mod ggglm;
+pub mod gglse;
+pub mod lstsq;
+
+pub use accumulator::*;
+pub use gels::*;
+pub use getsls::*;
+pub use ggglm::*;
+pub use gglse::*;
+pub use lstsq::*;
@@ -5,6 +5,11 @@
 #![cfg_attr(not(test), no_std)]
 
 pub mod ffi;
+pub mod householder;
+pub mod least_squares;
+pub mod linear_solve;
+pub mod orthogonal_factor;
 pub mod prelude;
+pub mod singular_value;
 pub mod symmetric_eigenvalues;
 pub mod util;
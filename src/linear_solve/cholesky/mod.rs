@@ -0,0 +1,5 @@
+pub mod potrf;
+pub mod potri;
+
+pub use potrf::*;
+pub use potri::*;
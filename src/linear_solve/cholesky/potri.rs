@@ -0,0 +1,130 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct POTRI_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    info: lapack_int,
+}
+
+pub trait POTRINum: LapackFloat {
+    unsafe fn run_potri(driver: &mut POTRI_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl POTRINum for $type {
+            unsafe fn run_potri(driver: &mut POTRI_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, spotri_);
+impl_func!(f64, dpotri_);
+impl_func!(c32, cpotri_);
+impl_func!(c64, zpotri_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Inverse of Hermitian (symmetric) positive definite matrix from its Cholesky factor by `POTRF`.
+///
+/// Only triangle `uplo` of `a` is referenced and overwritten by the same triangle of the inverse.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct POTRI_<'a, F>
+where
+    F: POTRINum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+}
+
+impl<'a, F> POTRI_<'a, F>
+where
+    F: POTRINum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let Self { a, uplo } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let n = a.view().nrows();
+        let uplo = uplo.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+
+        // 2. dim check
+        lapack_assert_eq!(a.view().dim(), (n, n), InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver =
+            POTRI_Driver { uplo: uplo as c_char, n: n.try_into()?, a, lda: lda.try_into()?, info: 0 };
+
+        // 4. perform comput
+        unsafe {
+            F::run_potri(&mut driver)?;
+        }
+
+        // 5. finalize
+        let POTRI_Driver { a, .. } = driver;
+        return Ok(a.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type POTRI<'a, F> = POTRI_Builder<'a, F>;
+pub type SPOTRI<'a> = POTRI<'a, f32>;
+pub type DPOTRI<'a> = POTRI<'a, f64>;
+pub type CPOTRI<'a> = POTRI<'a, c32>;
+pub type ZPOTRI<'a> = POTRI<'a, c64>;
+
+impl<'a, F> POTRI_Builder<'a, F>
+where
+    F: POTRINum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let a = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(a);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,237 @@
+//! Determinant of square matrix, by LU, Cholesky or Bunch-Kaufman factorization.
+//!
+//! Functions `slogdet*` return `(sign, logabs)`, where `sign` is `±1` for real matrices (or unit phase for
+//! complex), and `logabs` is natural logarithm of absolute value of determinant. Determinant equals to
+//! `sign * exp(logabs)`; using `slogdet*` avoids overflow or underflow for large matrices.
+//!
+//! Input matrix is not overwritten.
+
+use crate::ffi::lapack_int;
+use crate::linear_solve::{GETRFNum, POTRFNum, SYTRFNum, GETRF, HETRF, POTRF, SYTRF};
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::{Float, Zero};
+
+/* #region accumulate */
+
+fn slogdet_accumulate<F>(sign: &mut F, logabs: &mut F::RealFloat, d: F)
+where
+    F: LapackFloat,
+{
+    let d_abs = F::abs(d);
+    if d_abs == F::RealFloat::zero() {
+        *sign = F::zero();
+        *logabs = F::RealFloat::neg_infinity();
+    } else {
+        *sign = *sign * d * F::from_real(d_abs.recip());
+        *logabs += d_abs.ln();
+    }
+}
+
+fn slogdet_zero<F>() -> (F, F::RealFloat)
+where
+    F: LapackFloat,
+{
+    (F::zero(), F::RealFloat::neg_infinity())
+}
+
+fn slogdet_to_det<F>(sign: F, logabs: F::RealFloat) -> F
+where
+    F: LapackFloat,
+{
+    sign * F::from_real(logabs.exp())
+}
+
+/* #endregion */
+
+/* #region general matrix (LU) */
+
+/// Sign (or phase) and log-abs determinant of general square matrix, by LU factorization (getrf).
+pub fn slogdet<F>(a: ArrayView2<F>) -> Result<(F, F::RealFloat), LapackError>
+where
+    F: GETRFNum,
+{
+    let n = a.nrows();
+    lapack_assert_eq!(a.dim(), (n, n), InvalidDim)?;
+    let (lu, ipiv) = match GETRF::<F>::default().a(a).run() {
+        Ok(res) => res,
+        Err(LapackError::Info(info)) if info > 0 => return Ok(slogdet_zero()),
+        Err(err) => return Err(err),
+    };
+    let lu = lu.view();
+    let mut sign = F::one();
+    let mut logabs = F::RealFloat::zero();
+    for i in 0..n {
+        slogdet_accumulate(&mut sign, &mut logabs, lu[[i, i]]);
+        if ipiv[i] != (i + 1) as lapack_int {
+            sign = F::zero() - sign;
+        }
+    }
+    return Ok((sign, logabs));
+}
+
+/// Determinant of general square matrix, by LU factorization (getrf).
+pub fn det<F>(a: ArrayView2<F>) -> Result<F, LapackError>
+where
+    F: GETRFNum,
+{
+    let (sign, logabs) = slogdet(a)?;
+    return Ok(slogdet_to_det(sign, logabs));
+}
+
+/// Log-abs determinant of general square matrix, by LU factorization (getrf).
+pub fn logdet<F>(a: ArrayView2<F>) -> Result<F::RealFloat, LapackError>
+where
+    F: GETRFNum,
+{
+    let (_, logabs) = slogdet(a)?;
+    return Ok(logabs);
+}
+
+/* #endregion */
+
+/* #region Hermitian positive definite (Cholesky) */
+
+/// Sign and log determinant of Hermitian (symmetric) positive definite matrix, by Cholesky factorization
+/// (potrf).
+///
+/// Sign is always one; only triangle `uplo` of `a` is referenced. Matrix that is not positive definite gives
+/// `LapackError::Info`.
+pub fn slogdet_po<F>(a: ArrayView2<F>, uplo: char) -> Result<(F, F::RealFloat), LapackError>
+where
+    F: POTRFNum,
+{
+    let n = a.nrows();
+    lapack_assert_eq!(a.dim(), (n, n), InvalidDim)?;
+    let c = POTRF::<F>::default().a(a).uplo(uplo).run()?;
+    let c = c.view();
+    let mut logabs = F::RealFloat::zero();
+    for i in 0..n {
+        logabs += F::abs(c[[i, i]]).ln();
+    }
+    return Ok((F::one(), logabs + logabs));
+}
+
+/// Determinant of Hermitian (symmetric) positive definite matrix, by Cholesky factorization (potrf).
+pub fn det_po<F>(a: ArrayView2<F>, uplo: char) -> Result<F, LapackError>
+where
+    F: POTRFNum,
+{
+    let (sign, logabs) = slogdet_po(a, uplo)?;
+    return Ok(slogdet_to_det(sign, logabs));
+}
+
+/// Log determinant of Hermitian (symmetric) positive definite matrix, by Cholesky factorization (potrf).
+pub fn logdet_po<F>(a: ArrayView2<F>, uplo: char) -> Result<F::RealFloat, LapackError>
+where
+    F: POTRFNum,
+{
+    let (_, logabs) = slogdet_po(a, uplo)?;
+    return Ok(logabs);
+}
+
+/* #endregion */
+
+/* #region symmetric/Hermitian indefinite (Bunch-Kaufman) */
+
+fn slogdet_ldl<F>(a: ArrayView2<F>, uplo: char, hermi: bool) -> Result<(F, F::RealFloat), LapackError>
+where
+    F: SYTRFNum,
+{
+    let n = a.nrows();
+    let uplo = uplo.to_ascii_uppercase();
+    lapack_assert_eq!(a.dim(), (n, n), InvalidDim)?;
+    let result = match hermi {
+        true => HETRF::<F>::default().a(a).uplo(uplo).run(),
+        false => SYTRF::<F>::default().a(a).uplo(uplo).run(),
+    };
+    let (ldl, ipiv) = match result {
+        Ok(res) => res,
+        Err(LapackError::Info(info)) if info > 0 => return Ok(slogdet_zero()),
+        Err(err) => return Err(err),
+    };
+    let ldl = ldl.view();
+
+    // det(A) = det(D), where D is block diagonal with 1x1 and 2x2 blocks;
+    // 2x2 block occupies rows k, k+1 with ipiv[k] = ipiv[k+1] < 0.
+    let mut sign = F::one();
+    let mut logabs = F::RealFloat::zero();
+    let mut k = 0;
+    while k < n {
+        if ipiv[k] > 0 || k + 1 == n {
+            slogdet_accumulate(&mut sign, &mut logabs, ldl[[k, k]]);
+            k += 1;
+        } else {
+            let d11 = ldl[[k, k]];
+            let d22 = ldl[[k + 1, k + 1]];
+            let d21 = match uplo {
+                'U' => ldl[[k, k + 1]],
+                _ => ldl[[k + 1, k]],
+            };
+            let d21_t = if hermi { F::conj(d21) } else { d21 };
+            slogdet_accumulate(&mut sign, &mut logabs, d11 * d22 - d21 * d21_t);
+            k += 2;
+        }
+    }
+    return Ok((sign, logabs));
+}
+
+/// Sign (or phase) and log-abs determinant of symmetric indefinite matrix, by Bunch-Kaufman factorization
+/// (sytrf).
+///
+/// For complex matrices, this is complex symmetric (not Hermitian); see also [`slogdet_he`].
+/// Only triangle `uplo` of `a` is referenced.
+pub fn slogdet_sy<F>(a: ArrayView2<F>, uplo: char) -> Result<(F, F::RealFloat), LapackError>
+where
+    F: SYTRFNum,
+{
+    slogdet_ldl(a, uplo, false)
+}
+
+/// Determinant of symmetric indefinite matrix, by Bunch-Kaufman factorization (sytrf).
+pub fn det_sy<F>(a: ArrayView2<F>, uplo: char) -> Result<F, LapackError>
+where
+    F: SYTRFNum,
+{
+    let (sign, logabs) = slogdet_sy(a, uplo)?;
+    return Ok(slogdet_to_det(sign, logabs));
+}
+
+/// Log-abs determinant of symmetric indefinite matrix, by Bunch-Kaufman factorization (sytrf).
+pub fn logdet_sy<F>(a: ArrayView2<F>, uplo: char) -> Result<F::RealFloat, LapackError>
+where
+    F: SYTRFNum,
+{
+    let (_, logabs) = slogdet_sy(a, uplo)?;
+    return Ok(logabs);
+}
+
+/// Sign and log-abs determinant of Hermitian indefinite matrix, by Bunch-Kaufman factorization (hetrf).
+///
+/// Determinant of Hermitian matrix is real, so sign is `±1`. Only triangle `uplo` of `a` is referenced.
+pub fn slogdet_he<F>(a: ArrayView2<F>, uplo: char) -> Result<(F, F::RealFloat), LapackError>
+where
+    F: SYTRFNum,
+{
+    slogdet_ldl(a, uplo, true)
+}
+
+/// Determinant of Hermitian indefinite matrix, by Bunch-Kaufman factorization (hetrf).
+pub fn det_he<F>(a: ArrayView2<F>, uplo: char) -> Result<F, LapackError>
+where
+    F: SYTRFNum,
+{
+    let (sign, logabs) = slogdet_he(a, uplo)?;
+    return Ok(slogdet_to_det(sign, logabs));
+}
+
+/// Log-abs determinant of Hermitian indefinite matrix, by Bunch-Kaufman factorization (hetrf).
+pub fn logdet_he<F>(a: ArrayView2<F>, uplo: char) -> Result<F::RealFloat, LapackError>
+where
+    F: SYTRFNum,
+{
+    let (_, logabs) = slogdet_he(a, uplo)?;
+    return Ok(logabs);
+}
+
+/* #endregion */
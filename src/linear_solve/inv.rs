@@ -0,0 +1,221 @@
+//! Matrix inverse, with routine chosen by structure of matrix.
+//!
+//! | structure                   | factorization      | inversion          |
+//! |-----------------------------|--------------------|--------------------|
+//! | `General`                   | getrf              | getri              |
+//! | `HermitianPositiveDefinite` | potrf              | potri              |
+//! | `SymmetricIndefinite`       | sytrf              | sytri              |
+//! | `HermitianIndefinite`       | hetrf              | hetri              |
+//! | `Triangular`                | -                  | trtri              |
+//!
+//! For Hermitian/symmetric matrices, only triangle `uplo` of input is referenced, but both triangles of output
+//! are filled. For triangular matrices, only triangle `uplo` of input is referenced, and the other triangle of
+//! output is set to zero.
+
+extern crate alloc;
+
+use crate::linear_solve::*;
+use crate::util::*;
+use alloc::string::String;
+use core::fmt::Write;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region structure */
+
+/// Structure hint for [`INV`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatrixStructure {
+    /// General square matrix (LU, getri).
+    #[default]
+    General,
+    /// Hermitian (symmetric for real) positive definite matrix (Cholesky, potri).
+    HermitianPositiveDefinite,
+    /// Symmetric indefinite matrix (Bunch-Kaufman, sytri); complex symmetric for complex matrices.
+    SymmetricIndefinite,
+    /// Hermitian indefinite matrix (Bunch-Kaufman, hetri); same as `SymmetricIndefinite` for real matrices.
+    HermitianIndefinite,
+    /// Triangular matrix (trtri).
+    Triangular,
+}
+
+pub trait INVNum: GETRFNum + GETRINum + POTRFNum + POTRINum + SYTRFNum + SYTRINum + TRTRINum {}
+
+impl<F> INVNum for F where F: GETRFNum + GETRINum + POTRFNum + POTRINum + SYTRFNum + SYTRINum + TRTRINum {}
+
+/* #endregion */
+
+/* #region error */
+
+/// Translate positive `info` of factorization/inversion into readable error.
+fn inv_check_info<T>(result: Result<T, LapackError>, structure: MatrixStructure) -> Result<T, LapackError> {
+    match result {
+        Err(LapackError::Info(info)) if info > 0 => {
+            let mut s = String::from(concat!(file!(), ":", line!(), ": ", "LapackError::FailedCheck", " : "));
+            match structure {
+                MatrixStructure::HermitianPositiveDefinite => write!(
+                    s,
+                    "matrix is not positive definite: leading minor of order {info} is not positive, inverse \
+                     cannot be computed"
+                ),
+                MatrixStructure::Triangular => write!(
+                    s,
+                    "matrix is singular: diagonal element A({i}, {i}) is exactly zero, inverse cannot be computed",
+                    i = info - 1
+                ),
+                MatrixStructure::General => write!(
+                    s,
+                    "matrix is singular: diagonal element U({i}, {i}) of LU factorization is exactly zero, inverse \
+                     cannot be computed",
+                    i = info - 1
+                ),
+                MatrixStructure::SymmetricIndefinite | MatrixStructure::HermitianIndefinite => write!(
+                    s,
+                    "matrix is singular: diagonal element D({i}, {i}) of factorization is exactly zero, inverse \
+                     cannot be computed",
+                    i = info - 1
+                ),
+            }
+            .unwrap();
+            Err(LapackError::FailedCheck(s))
+        },
+        _ => result,
+    }
+}
+
+/* #endregion */
+
+/* #region builder */
+
+/// Inverse of square matrix, by routine chosen from `structure`.
+///
+/// Indices in error message of singular matrix are 0-based.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct INV_<'a, F>
+where
+    F: INVNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    #[builder(default)]
+    pub structure: MatrixStructure,
+    /// Referenced triangle for Hermitian/symmetric or triangular matrix.
+    #[builder(default = "'U'")]
+    pub uplo: char,
+    /// Unit diagonal (`'U'`) or not (`'N'`) for triangular matrix.
+    #[builder(default = "'N'")]
+    pub diag: char,
+}
+
+impl<'a, F> INV_<'a, F>
+where
+    F: INVNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let Self { a, structure, uplo, diag } = self;
+
+        // 1. dim assign, flag check
+        let mut a = a.into_col_array_out();
+        let n = a.view().nrows();
+        let uplo = uplo.to_ascii_uppercase();
+        let diag = diag.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        lapack_check_flag!(diag, ['N', 'U'])?;
+
+        // 2. dim check
+        lapack_assert_eq!(a.view().dim(), (n, n), InvalidDim)?;
+
+        // 3. perform comput
+        match structure {
+            MatrixStructure::General => {
+                let (_, ipiv) = inv_check_info(GETRF::default().a(a.view_mut()).run(), structure)?;
+                inv_check_info(GETRI::default().a(a.view_mut()).ipiv(ipiv.view()).run(), structure)?;
+            },
+            MatrixStructure::HermitianPositiveDefinite => {
+                inv_check_info(POTRF::default().a(a.view_mut()).uplo(uplo).run(), structure)?;
+                inv_check_info(POTRI::default().a(a.view_mut()).uplo(uplo).run(), structure)?;
+            },
+            MatrixStructure::SymmetricIndefinite => {
+                let (_, ipiv) = inv_check_info(SYTRF::default().a(a.view_mut()).uplo(uplo).run(), structure)?;
+                inv_check_info(
+                    SYTRI::default().a(a.view_mut()).ipiv(ipiv.view()).uplo(uplo).run(),
+                    structure,
+                )?;
+            },
+            MatrixStructure::HermitianIndefinite => {
+                let (_, ipiv) = inv_check_info(HETRF::default().a(a.view_mut()).uplo(uplo).run(), structure)?;
+                inv_check_info(
+                    HETRI::default().a(a.view_mut()).ipiv(ipiv.view()).uplo(uplo).run(),
+                    structure,
+                )?;
+            },
+            MatrixStructure::Triangular => {
+                inv_check_info(TRTRI::default().a(a.view_mut()).uplo(uplo).diag(diag).run(), structure)?;
+            },
+        }
+
+        // 4. finalize
+        let hermi = matches!(
+            structure,
+            MatrixStructure::HermitianPositiveDefinite | MatrixStructure::HermitianIndefinite
+        );
+        let mut a_view = a.view_mut();
+        for j in 0..n {
+            for i in (j + 1)..n {
+                // (i, j) in lower triangle, (j, i) in upper triangle
+                let (src, dst) = if uplo == 'U' { ((j, i), (i, j)) } else { ((i, j), (j, i)) };
+                a_view[dst] = match structure {
+                    MatrixStructure::General => continue,
+                    MatrixStructure::Triangular => F::zero(),
+                    _ if hermi => F::conj(a_view[src]),
+                    _ => a_view[src],
+                };
+            }
+        }
+        return Ok(a.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region wrapper */
+
+pub type INV<'a, F> = INV_Builder<'a, F>;
+pub type SINV<'a> = INV<'a, f32>;
+pub type DINV<'a> = INV<'a, f64>;
+pub type CINV<'a> = INV<'a, c32>;
+pub type ZINV<'a> = INV<'a, c64>;
+
+impl<'a, F> INV_Builder<'a, F>
+where
+    F: INVNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let a = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(a);
+        }
+    }
+}
+
+/// Inverse of general square matrix (owned output); see [`INV`] for other structures.
+pub fn inv<F>(a: ArrayView2<F>) -> Result<Array2<F>, LapackError>
+where
+    F: INVNum,
+{
+    Ok(INV::default().a(a).run()?.into_owned())
+}
+
+/* #endregion */
@@ -0,0 +1,5 @@
+pub mod sytrf;
+pub mod sytri;
+
+pub use sytrf::*;
+pub use sytri::*;
@@ -0,0 +1,246 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct SYTRF_Driver<'a, 'work, F>
+where
+    F: LapackFloat,
+{
+    hermi: bool,
+    uplo: c_char,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    ipiv: Array1<lapack_int>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait SYTRFNum: LapackFloat {
+    unsafe fn run_sytrf(driver: &mut SYTRF_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl SYTRFNum for $type {
+            unsafe fn run_sytrf(driver: &mut SYTRF_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.ipiv.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func_sy:ident, $func_he:ident) => {
+        impl SYTRFNum for $type {
+            unsafe fn run_sytrf(driver: &mut SYTRF_Driver<Self>) -> Result<(), LapackError> {
+                let func = if driver.hermi { ffi::$func_he } else { ffi::$func_sy };
+                func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.ipiv.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, ssytrf_);
+impl_func_real!(f64, dsytrf_);
+impl_func_comp!(c32, csytrf_, chetrf_);
+impl_func_comp!(c64, zsytrf_, zhetrf_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Bunch-Kaufman factorization `A = U D U^T` or `A = L D L^T` of symmetric indefinite matrix.
+///
+/// Only triangle `uplo` of `a` is referenced and overwritten by the factor.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct SYTRF_<'a, 'work, F>
+where
+    F: SYTRFNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+/// Bunch-Kaufman factorization `A = U D U^H` or `A = L D L^H` of Hermitian indefinite matrix.
+///
+/// Only triangle `uplo` of `a` is referenced and overwritten by the factor.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct HETRF_<'a, 'work, F>
+where
+    F: SYTRFNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'work, F> SYTRF_<'a, 'work, F>
+where
+    F: SYTRFNum,
+{
+    pub fn driver(self) -> Result<(ArrayOut2<'a, F>, Array1<lapack_int>), LapackError> {
+        self.driver_hermi(false)
+    }
+
+    fn driver_hermi(self, hermi: bool) -> Result<(ArrayOut2<'a, F>, Array1<lapack_int>), LapackError> {
+        let Self { a, uplo, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let n = a.view().nrows();
+        let uplo = uplo.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+
+        // 2. dim check & alloc
+        lapack_assert_eq!(a.view().dim(), (n, n), InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(n.max(1) as isize);
+        let ipiv = Array1::zeros(n);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, n.max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = SYTRF_Driver {
+            hermi,
+            uplo: uplo as c_char,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            ipiv,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_sytrf(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_sytrf(&mut driver)?;
+        }
+
+        // 7. finalize
+        let SYTRF_Driver { a, ipiv, .. } = driver;
+        return Ok((a.clone_to_view_mut(), ipiv));
+    }
+}
+
+impl<'a, 'work, F> HETRF_<'a, 'work, F>
+where
+    F: SYTRFNum,
+{
+    pub fn driver(self) -> Result<(ArrayOut2<'a, F>, Array1<lapack_int>), LapackError> {
+        let Self { a, uplo, work } = self;
+        SYTRF_ { a, uplo, work }.driver_hermi(true)
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type SYTRF<'a, 'work, F> = SYTRF_Builder<'a, 'work, F>;
+pub type HETRF<'a, 'work, F> = HETRF_Builder<'a, 'work, F>;
+pub type SSYTRF<'a, 'work> = SYTRF<'a, 'work, f32>;
+pub type DSYTRF<'a, 'work> = SYTRF<'a, 'work, f64>;
+pub type CSYTRF<'a, 'work> = SYTRF<'a, 'work, c32>;
+pub type ZSYTRF<'a, 'work> = SYTRF<'a, 'work, c64>;
+pub type CHETRF<'a, 'work> = HETRF<'a, 'work, c32>;
+pub type ZHETRF<'a, 'work> = HETRF<'a, 'work, c64>;
+
+impl<'a, 'work, F> SYTRF_Builder<'a, 'work, F>
+where
+    F: SYTRFNum,
+{
+    pub fn run(self) -> Result<(ArrayOut2<'a, F>, Array1<lapack_int>), LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, ipiv) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, ipiv));
+        }
+    }
+}
+
+impl<'a, 'work, F> HETRF_Builder<'a, 'work, F>
+where
+    F: SYTRFNum,
+{
+    pub fn run(self) -> Result<(ArrayOut2<'a, F>, Array1<lapack_int>), LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, ipiv) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, ipiv));
+        }
+    }
+}
+
+/* #endregion */
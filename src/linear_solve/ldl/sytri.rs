@@ -0,0 +1,230 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct SYTRI_Driver<'a, 'p, F>
+where
+    F: LapackFloat,
+{
+    hermi: bool,
+    uplo: c_char,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    ipiv: ArrayView1<'p, lapack_int>,
+    work: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait SYTRINum: LapackFloat {
+    unsafe fn run_sytri(driver: &mut SYTRI_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl SYTRINum for $type {
+            unsafe fn run_sytri(driver: &mut SYTRI_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.ipiv.as_ptr(),
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func_sy:ident, $func_he:ident) => {
+        impl SYTRINum for $type {
+            unsafe fn run_sytri(driver: &mut SYTRI_Driver<Self>) -> Result<(), LapackError> {
+                let func = if driver.hermi { ffi::$func_he } else { ffi::$func_sy };
+                func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.ipiv.as_ptr(),
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, ssytri_);
+impl_func_real!(f64, dsytri_);
+impl_func_comp!(c32, csytri_, chetri_);
+impl_func_comp!(c64, zsytri_, zhetri_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Inverse of symmetric indefinite matrix from its Bunch-Kaufman factorization by `SYTRF`.
+///
+/// Only triangle `uplo` of `a` is referenced and overwritten by the same triangle of the inverse.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct SYTRI_<'a, 'p, F>
+where
+    F: SYTRINum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // input
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+}
+
+/// Inverse of Hermitian indefinite matrix from its Bunch-Kaufman factorization by `HETRF`.
+///
+/// Only triangle `uplo` of `a` is referenced and overwritten by the same triangle of the inverse.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct HETRI_<'a, 'p, F>
+where
+    F: SYTRINum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // input
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+}
+
+impl<'a, 'p, F> SYTRI_<'a, 'p, F>
+where
+    F: SYTRINum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        self.driver_hermi(false)
+    }
+
+    fn driver_hermi(self, hermi: bool) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let Self { a, ipiv, uplo } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let n = a.view().nrows();
+        let ipiv = ipiv.to_seq_layout()?;
+        let uplo = uplo.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+
+        // 2. dim check & buffer alloc
+        lapack_assert_eq!(a.view().dim(), (n, n), InvalidDim)?;
+        lapack_assert_eq!(ipiv.len(), n, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(n.max(1) as isize);
+        let work = Array1::zeros(2 * n.max(1));
+
+        // 3. struct build
+        let mut driver = SYTRI_Driver {
+            hermi,
+            uplo: uplo as c_char,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            ipiv: ipiv.view(),
+            work,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_sytri(&mut driver)?;
+        }
+
+        // 5. finalize
+        let SYTRI_Driver { a, .. } = driver;
+        return Ok(a.clone_to_view_mut());
+    }
+}
+
+impl<'a, 'p, F> HETRI_<'a, 'p, F>
+where
+    F: SYTRINum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let Self { a, ipiv, uplo } = self;
+        SYTRI_ { a, ipiv, uplo }.driver_hermi(true)
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type SYTRI<'a, 'p, F> = SYTRI_Builder<'a, 'p, F>;
+pub type HETRI<'a, 'p, F> = HETRI_Builder<'a, 'p, F>;
+pub type SSYTRI<'a, 'p> = SYTRI<'a, 'p, f32>;
+pub type DSYTRI<'a, 'p> = SYTRI<'a, 'p, f64>;
+pub type CSYTRI<'a, 'p> = SYTRI<'a, 'p, c32>;
+pub type ZSYTRI<'a, 'p> = SYTRI<'a, 'p, c64>;
+pub type CHETRI<'a, 'p> = HETRI<'a, 'p, c32>;
+pub type ZHETRI<'a, 'p> = HETRI<'a, 'p, c64>;
+
+impl<'a, 'p, F> SYTRI_Builder<'a, 'p, F>
+where
+    F: SYTRINum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let a = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(a);
+        }
+    }
+}
+
+impl<'a, 'p, F> HETRI_Builder<'a, 'p, F>
+where
+    F: SYTRINum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let a = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(a);
+        }
+    }
+}
+
+/* #endregion */
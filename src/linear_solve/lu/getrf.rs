@@ -0,0 +1,126 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GETRF_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    ipiv: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait GETRFNum: LapackFloat {
+    unsafe fn run_getrf(driver: &mut GETRF_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GETRFNum for $type {
+            unsafe fn run_getrf(driver: &mut GETRF_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.ipiv.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgetrf_);
+impl_func!(f64, dgetrf_);
+impl_func!(c32, cgetrf_);
+impl_func!(c64, zgetrf_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// LU factorization with partial pivoting `A = P L U`.
+///
+/// Pivot indices `ipiv` are 1-based, as returned by LAPACK.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GETRF_<'a, F>
+where
+    F: GETRFNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+}
+
+impl<'a, F> GETRF_<'a, F>
+where
+    F: GETRFNum,
+{
+    pub fn driver(self) -> Result<(ArrayOut2<'a, F>, Array1<lapack_int>), LapackError> {
+        let Self { a } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 2. alloc
+        let ipiv = Array1::zeros(m.min(n));
+
+        // 3. struct build
+        let mut driver =
+            GETRF_Driver { m: m.try_into()?, n: n.try_into()?, a, lda: lda.try_into()?, ipiv, info: 0 };
+
+        // 4. perform comput
+        unsafe {
+            F::run_getrf(&mut driver)?;
+        }
+
+        // 5. finalize
+        let GETRF_Driver { a, ipiv, .. } = driver;
+        return Ok((a.clone_to_view_mut(), ipiv));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GETRF<'a, F> = GETRF_Builder<'a, F>;
+pub type SGETRF<'a> = GETRF<'a, f32>;
+pub type DGETRF<'a> = GETRF<'a, f64>;
+pub type CGETRF<'a> = GETRF<'a, c32>;
+pub type ZGETRF<'a> = GETRF<'a, c64>;
+
+impl<'a, F> GETRF_Builder<'a, F>
+where
+    F: GETRFNum,
+{
+    pub fn run(self) -> Result<(ArrayOut2<'a, F>, Array1<lapack_int>), LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, ipiv) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, ipiv));
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,158 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GETRI_Driver<'a, 'p, 'work, F>
+where
+    F: LapackFloat,
+{
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    ipiv: ArrayView1<'p, lapack_int>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GETRINum: LapackFloat {
+    unsafe fn run_getri(driver: &mut GETRI_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GETRINum for $type {
+            unsafe fn run_getri(driver: &mut GETRI_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.ipiv.as_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgetri_);
+impl_func!(f64, dgetri_);
+impl_func!(c32, cgetri_);
+impl_func!(c64, zgetri_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Inverse of general matrix from its LU factorization by `GETRF`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GETRI_<'a, 'p, 'work, F>
+where
+    F: GETRINum,
+{
+    // input/output
+    /// LU factor from `GETRF`, overwritten by the inverse on exit.
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // input
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'p, 'work, F> GETRI_<'a, 'p, 'work, F>
+where
+    F: GETRINum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let Self { a, ipiv, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let n = a.view().nrows();
+        let ipiv = ipiv.to_seq_layout()?;
+
+        // 2. dim check
+        lapack_assert_eq!(a.view().dim(), (n, n), InvalidDim)?;
+        lapack_assert_eq!(ipiv.len(), n, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, n.max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GETRI_Driver {
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            ipiv: ipiv.view(),
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_getri(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_getri(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GETRI_Driver { a, .. } = driver;
+        return Ok(a.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GETRI<'a, 'p, 'work, F> = GETRI_Builder<'a, 'p, 'work, F>;
+pub type SGETRI<'a, 'p, 'work> = GETRI<'a, 'p, 'work, f32>;
+pub type DGETRI<'a, 'p, 'work> = GETRI<'a, 'p, 'work, f64>;
+pub type CGETRI<'a, 'p, 'work> = GETRI<'a, 'p, 'work, c32>;
+pub type ZGETRI<'a, 'p, 'work> = GETRI<'a, 'p, 'work, c64>;
+
+impl<'a, 'p, 'work, F> GETRI_Builder<'a, 'p, 'work, F>
+where
+    F: GETRINum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let a = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(a);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,5 @@
+pub mod getrf;
+pub mod getri;
+
+pub use getrf::*;
+pub use getri::*;
@@ -0,0 +1,17 @@
+pub mod cholesky;
+pub mod det;
+pub mod inv;
+pub mod ldl;
+pub mod lu;
+pub mod packed;
+pub mod rfp;
+pub mod triangular;
+
+pub use cholesky::*;
+pub use det::*;
+pub use inv::*;
+pub use ldl::*;
+pub use lu::*;
+pub use packed::*;
+pub use rfp::*;
+pub use triangular::*;
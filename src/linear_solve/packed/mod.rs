@@ -0,0 +1,23 @@
+pub mod packed_matrix;
+pub mod ppcon;
+pub mod ppsv;
+pub mod pptrf;
+pub mod pptri;
+pub mod pptrs;
+pub mod spcon;
+pub mod spsv;
+pub mod sptrf;
+pub mod sptri;
+pub mod sptrs;
+
+pub use packed_matrix::*;
+pub use ppcon::*;
+pub use ppsv::*;
+pub use pptrf::*;
+pub use pptri::*;
+pub use pptrs::*;
+pub use spcon::*;
+pub use spsv::*;
+pub use sptrf::*;
+pub use sptri::*;
+pub use sptrs::*;
@@ -0,0 +1,168 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use core::cmp::max;
+use ndarray::prelude::*;
+
+/* #region PackedMatrix */
+
+/// Triangle of a square matrix stored in LAPACK packed format (column-major).
+///
+/// Only one triangle (given by `uplo`) of the `n x n` matrix is kept, in an array of length `n * (n + 1) / 2`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedMatrix<F> {
+    pub ap: Array1<F>,
+    pub n: usize,
+    pub uplo: char,
+}
+
+pub trait PackedMatrixNum: LapackFloat {
+    unsafe fn run_trttp(
+        uplo: c_char,
+        n: lapack_int,
+        a: *const Self,
+        lda: lapack_int,
+        ap: *mut Self,
+    ) -> Result<(), LapackError>;
+    unsafe fn run_tpttr(
+        uplo: c_char,
+        n: lapack_int,
+        ap: *const Self,
+        a: *mut Self,
+        lda: lapack_int,
+    ) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $trttp:ident, $tpttr:ident) => {
+        impl PackedMatrixNum for $type {
+            unsafe fn run_trttp(
+                uplo: c_char,
+                n: lapack_int,
+                a: *const Self,
+                lda: lapack_int,
+                ap: *mut Self,
+            ) -> Result<(), LapackError> {
+                let mut info = 0;
+                ffi::$trttp(&uplo, &n, a, &lda, ap, &mut info);
+                lapack_info!(info)?;
+                return Ok(());
+            }
+
+            unsafe fn run_tpttr(
+                uplo: c_char,
+                n: lapack_int,
+                ap: *const Self,
+                a: *mut Self,
+                lda: lapack_int,
+            ) -> Result<(), LapackError> {
+                let mut info = 0;
+                ffi::$tpttr(&uplo, &n, ap, a, &lda, &mut info);
+                lapack_info!(info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, strttp_, stpttr_);
+impl_func!(f64, dtrttp_, dtpttr_);
+impl_func!(c32, ctrttp_, ctpttr_);
+impl_func!(c64, ztrttp_, ztpttr_);
+
+/// Dimension `n` of packed matrix with `len = n * (n + 1) / 2`, or `None` if `len` is not triangular number.
+pub fn packed_dim(len: usize) -> Option<usize> {
+    let mut n = 0;
+    while n * (n + 1) / 2 < len {
+        n += 1;
+    }
+    if n * (n + 1) / 2 == len {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+impl<F> PackedMatrix<F>
+where
+    F: LapackFloat,
+{
+    /// Wrap packed array `ap`; dimension `n` is deduced from its length.
+    pub fn new(ap: Array1<F>, uplo: char) -> Result<Self, LapackError> {
+        let uplo = uplo.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        let n = match packed_dim(ap.len()) {
+            Some(n) => n,
+            None => lapack_raise!(InvalidDim, "length of packed array is not n * (n + 1) / 2")?,
+        };
+        let ap = match ap.is_standard_layout() {
+            true => ap,
+            false => ap.as_standard_layout().into_owned(),
+        };
+        return Ok(Self { ap, n, uplo });
+    }
+
+    /// Check consistency of `uplo`, dimension and memory layout, before passing to LAPACK.
+    pub fn check(&self) -> Result<(), LapackError> {
+        lapack_check_flag!(self.uplo, ['U', 'L'])?;
+        lapack_assert_eq!(self.ap.len(), self.n * (self.n + 1) / 2, InvalidDim)?;
+        lapack_assert!(self.ap.is_standard_layout(), InvalidDim, "packed array should be contiguous")?;
+        return Ok(());
+    }
+
+    pub fn view(&self) -> ArrayView1<'_, F> {
+        self.ap.view()
+    }
+
+    pub fn view_mut(&mut self) -> ArrayViewMut1<'_, F> {
+        self.ap.view_mut()
+    }
+
+    pub fn into_raw(self) -> (Array1<F>, usize, char) {
+        let Self { ap, n, uplo } = self;
+        return (ap, n, uplo);
+    }
+}
+
+impl<F> PackedMatrix<F>
+where
+    F: PackedMatrixNum,
+{
+    /// Pack triangle `uplo` of dense square matrix `a` (trttp).
+    ///
+    /// Elements in the other triangle of `a` are not referenced.
+    pub fn from_dense(a: &ArrayView2<F>, uplo: char) -> Result<Self, LapackError> {
+        let uplo = uplo.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        let n = a.nrows();
+        lapack_assert_eq!(a.dim(), (n, n), InvalidDim)?;
+        let a = a.to_col_layout()?;
+        let lda = max(a.stride_of(Axis(1)), max(n, 1) as isize);
+        let mut ap = Array1::<F>::zeros(n * (n + 1) / 2);
+        unsafe {
+            F::run_trttp(uplo as c_char, n.try_into()?, a.as_ptr(), lda.try_into()?, ap.as_mut_ptr())?;
+        }
+        return Ok(Self { ap, n, uplo });
+    }
+
+    /// Unpack to dense column-major matrix (tpttr).
+    ///
+    /// Only triangle `uplo` is filled; the other triangle is zero.
+    pub fn to_dense(&self) -> Result<Array2<F>, LapackError> {
+        self.check()?;
+        let n = self.n;
+        let mut a = Array2::<F>::zeros((n, n).f());
+        let lda = max(n, 1);
+        unsafe {
+            F::run_tpttr(
+                self.uplo as c_char,
+                n.try_into()?,
+                self.ap.as_ptr(),
+                a.as_mut_ptr(),
+                lda.try_into()?,
+            )?;
+        }
+        return Ok(a);
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,155 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/* #region Lapack driver */
+
+pub struct PPCON_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    n: lapack_int,
+    ap: ArrayView1<'a, F>,
+    anorm: F::RealFloat,
+    rcond: F::RealFloat,
+    work: Array1<F>,
+    rwork: Array1<F::RealFloat>,
+    iwork: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait PPCONNum: LapackFloat {
+    unsafe fn run_ppcon(driver: &mut PPCON_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl PPCONNum for $type {
+            unsafe fn run_ppcon(driver: &mut PPCON_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.ap.as_ptr(),
+                    &driver.anorm,
+                    &mut driver.rcond,
+                    driver.work.as_mut_ptr(),
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl PPCONNum for $type {
+            unsafe fn run_ppcon(driver: &mut PPCON_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.ap.as_ptr(),
+                    &driver.anorm,
+                    &mut driver.rcond,
+                    driver.work.as_mut_ptr(),
+                    driver.rwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sppcon_);
+impl_func_real!(f64, dppcon_);
+impl_func_comp!(c32, cppcon_);
+impl_func_comp!(c64, zppcon_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct PPCON_<'a, F>
+where
+    F: PPCONNum,
+{
+    // input
+    /// Cholesky factor from `PPTRF`.
+    pub ap: &'a PackedMatrix<F>,
+    /// 1-norm (or infinity-norm) of the original matrix.
+    pub anorm: F::RealFloat,
+}
+
+impl<'a, F> PPCON_<'a, F>
+where
+    F: PPCONNum,
+{
+    /// Reciprocal of the condition number (in the 1-norm).
+    pub fn driver(self) -> Result<F::RealFloat, LapackError> {
+        let Self { ap, anorm } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let n = ap.n;
+
+        // 2. buffer alloc
+        let work = match F::is_complex() {
+            true => Array1::zeros(2 * n),
+            false => Array1::zeros(3 * n),
+        };
+        let rwork = Array1::zeros(n);
+        let iwork = Array1::zeros(n);
+
+        // 3. struct build
+        let mut driver = PPCON_Driver {
+            uplo: ap.uplo as c_char,
+            n: n.try_into()?,
+            ap: ap.view(),
+            anorm,
+            rcond: F::RealFloat::zero(),
+            work,
+            rwork,
+            iwork,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_ppcon(&mut driver)?;
+        }
+
+        // 5. finalize
+        return Ok(driver.rcond);
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type PPCON<'a, F> = PPCON_Builder<'a, F>;
+pub type SPPCON<'a> = PPCON<'a, f32>;
+pub type DPPCON<'a> = PPCON<'a, f64>;
+pub type CPPCON<'a> = PPCON<'a, c32>;
+pub type ZPPCON<'a> = PPCON<'a, c64>;
+
+impl<'a, F> PPCON_Builder<'a, F>
+where
+    F: PPCONNum,
+{
+    pub fn run(self) -> Result<F::RealFloat, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
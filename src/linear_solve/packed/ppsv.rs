@@ -0,0 +1,137 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct PPSV_Driver<'b, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    n: lapack_int,
+    nrhs: lapack_int,
+    ap: Array1<F>,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    info: lapack_int,
+}
+
+pub trait PPSVNum: LapackFloat {
+    unsafe fn run_ppsv(driver: &mut PPSV_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl PPSVNum for $type {
+            unsafe fn run_ppsv(driver: &mut PPSV_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.ap.as_mut_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sppsv_);
+impl_func!(f64, dppsv_);
+impl_func!(c32, cppsv_);
+impl_func!(c64, zppsv_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct PPSV_<'b, F>
+where
+    F: PPSVNum,
+{
+    // input/output
+    /// Overwritten by its Cholesky factor on exit.
+    pub ap: PackedMatrix<F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+}
+
+impl<'b, F> PPSV_<'b, F>
+where
+    F: PPSVNum,
+{
+    pub fn driver(self) -> Result<(PackedMatrix<F>, ArrayOut2<'b, F>), LapackError> {
+        let Self { ap, b } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let (ap, n, uplo) = ap.into_raw();
+        let b = b.into_col_array_out();
+        let nrhs = b.view().ncols();
+
+        // 2. dim check
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = PPSV_Driver {
+            uplo: uplo as c_char,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            ap,
+            b,
+            ldb: ldb.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_ppsv(&mut driver)?;
+        }
+
+        // 5. finalize
+        let PPSV_Driver { ap, b, .. } = driver;
+        return Ok((PackedMatrix { ap, n, uplo }, b.clone_to_view_mut()));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type PPSV<'b, F> = PPSV_Builder<'b, F>;
+pub type SPPSV<'b> = PPSV<'b, f32>;
+pub type DPPSV<'b> = PPSV<'b, f64>;
+pub type CPPSV<'b> = PPSV<'b, c32>;
+pub type ZPPSV<'b> = PPSV<'b, c64>;
+
+impl<'b, F> PPSV_Builder<'b, F>
+where
+    F: PPSVNum,
+{
+    pub fn run(self) -> Result<(PackedMatrix<F>, ArrayOut2<'b, F>), LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (ap, b) = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((ap, b));
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,98 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct PPTRF_Driver<F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    n: lapack_int,
+    ap: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait PPTRFNum: LapackFloat {
+    unsafe fn run_pptrf(driver: &mut PPTRF_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl PPTRFNum for $type {
+            unsafe fn run_pptrf(driver: &mut PPTRF_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(&driver.uplo, &driver.n, driver.ap.as_mut_ptr(), &mut driver.info);
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, spptrf_);
+impl_func!(f64, dpptrf_);
+impl_func!(c32, cpptrf_);
+impl_func!(c64, zpptrf_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct PPTRF_<F>
+where
+    F: PPTRFNum,
+{
+    // input/output
+    pub ap: PackedMatrix<F>,
+}
+
+impl<F> PPTRF_<F>
+where
+    F: PPTRFNum,
+{
+    pub fn driver(self) -> Result<PackedMatrix<F>, LapackError> {
+        let Self { ap } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let (ap, n, uplo) = ap.into_raw();
+
+        // 2. struct build
+        let mut driver = PPTRF_Driver { uplo: uplo as c_char, n: n.try_into()?, ap, info: 0 };
+
+        // 3. perform comput
+        unsafe {
+            F::run_pptrf(&mut driver)?;
+        }
+
+        // 4. finalize
+        let PPTRF_Driver { ap, .. } = driver;
+        return Ok(PackedMatrix { ap, n, uplo });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type PPTRF<F> = PPTRF_Builder<F>;
+pub type SPPTRF = PPTRF<f32>;
+pub type DPPTRF = PPTRF<f64>;
+pub type CPPTRF = PPTRF<c32>;
+pub type ZPPTRF = PPTRF<c64>;
+
+impl<F> PPTRF_Builder<F>
+where
+    F: PPTRFNum,
+{
+    pub fn run(self) -> Result<PackedMatrix<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
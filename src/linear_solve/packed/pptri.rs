@@ -0,0 +1,98 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct PPTRI_Driver<F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    n: lapack_int,
+    ap: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait PPTRINum: LapackFloat {
+    unsafe fn run_pptri(driver: &mut PPTRI_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl PPTRINum for $type {
+            unsafe fn run_pptri(driver: &mut PPTRI_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(&driver.uplo, &driver.n, driver.ap.as_mut_ptr(), &mut driver.info);
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, spptri_);
+impl_func!(f64, dpptri_);
+impl_func!(c32, cpptri_);
+impl_func!(c64, zpptri_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct PPTRI_<F>
+where
+    F: PPTRINum,
+{
+    // input/output
+    pub ap: PackedMatrix<F>,
+}
+
+impl<F> PPTRI_<F>
+where
+    F: PPTRINum,
+{
+    pub fn driver(self) -> Result<PackedMatrix<F>, LapackError> {
+        let Self { ap } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let (ap, n, uplo) = ap.into_raw();
+
+        // 2. struct build
+        let mut driver = PPTRI_Driver { uplo: uplo as c_char, n: n.try_into()?, ap, info: 0 };
+
+        // 3. perform comput
+        unsafe {
+            F::run_pptri(&mut driver)?;
+        }
+
+        // 4. finalize
+        let PPTRI_Driver { ap, .. } = driver;
+        return Ok(PackedMatrix { ap, n, uplo });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type PPTRI<F> = PPTRI_Builder<F>;
+pub type SPPTRI = PPTRI<f32>;
+pub type DPPTRI = PPTRI<f64>;
+pub type CPPTRI = PPTRI<c32>;
+pub type ZPPTRI = PPTRI<c64>;
+
+impl<F> PPTRI_Builder<F>
+where
+    F: PPTRINum,
+{
+    pub fn run(self) -> Result<PackedMatrix<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
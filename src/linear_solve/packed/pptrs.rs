@@ -0,0 +1,139 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct PPTRS_Driver<'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    n: lapack_int,
+    nrhs: lapack_int,
+    ap: ArrayView1<'a, F>,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    info: lapack_int,
+}
+
+pub trait PPTRSNum: LapackFloat {
+    unsafe fn run_pptrs(driver: &mut PPTRS_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl PPTRSNum for $type {
+            unsafe fn run_pptrs(driver: &mut PPTRS_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.ap.as_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, spptrs_);
+impl_func!(f64, dpptrs_);
+impl_func!(c32, cpptrs_);
+impl_func!(c64, zpptrs_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct PPTRS_<'a, 'b, F>
+where
+    F: PPTRSNum,
+{
+    // input
+    /// Cholesky factor from `PPTRF`.
+    pub ap: &'a PackedMatrix<F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+}
+
+impl<'a, 'b, F> PPTRS_<'a, 'b, F>
+where
+    F: PPTRSNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let Self { ap, b } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let n = ap.n;
+        let b = b.into_col_array_out();
+        let nrhs = b.view().ncols();
+
+        // 2. dim check
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = PPTRS_Driver {
+            uplo: ap.uplo as c_char,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            ap: ap.view(),
+            b,
+            ldb: ldb.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_pptrs(&mut driver)?;
+        }
+
+        // 5. finalize
+        let PPTRS_Driver { b, .. } = driver;
+        return Ok(b.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type PPTRS<'a, 'b, F> = PPTRS_Builder<'a, 'b, F>;
+pub type SPPTRS<'a, 'b> = PPTRS<'a, 'b, f32>;
+pub type DPPTRS<'a, 'b> = PPTRS<'a, 'b, f64>;
+pub type CPPTRS<'a, 'b> = PPTRS<'a, 'b, c32>;
+pub type ZPPTRS<'a, 'b> = PPTRS<'a, 'b, c64>;
+
+impl<'a, 'b, F> PPTRS_Builder<'a, 'b, F>
+where
+    F: PPTRSNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let b = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(b);
+        }
+    }
+}
+
+/* #endregion */
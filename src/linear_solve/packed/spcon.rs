@@ -0,0 +1,201 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/* #region Lapack driver */
+
+pub struct SPCON_Driver<'a, 'p, F>
+where
+    F: LapackFloat,
+{
+    hermi: bool,
+    uplo: c_char,
+    n: lapack_int,
+    ap: ArrayView1<'a, F>,
+    ipiv: ArrayView1<'p, lapack_int>,
+    anorm: F::RealFloat,
+    rcond: F::RealFloat,
+    work: Array1<F>,
+    iwork: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait SPCONNum: LapackFloat {
+    unsafe fn run_spcon(driver: &mut SPCON_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl SPCONNum for $type {
+            unsafe fn run_spcon(driver: &mut SPCON_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.ap.as_ptr(),
+                    driver.ipiv.as_ptr(),
+                    &driver.anorm,
+                    &mut driver.rcond,
+                    driver.work.as_mut_ptr(),
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func_sy:ident, $func_he:ident) => {
+        impl SPCONNum for $type {
+            unsafe fn run_spcon(driver: &mut SPCON_Driver<Self>) -> Result<(), LapackError> {
+                let func = if driver.hermi { ffi::$func_he } else { ffi::$func_sy };
+                func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.ap.as_ptr(),
+                    driver.ipiv.as_ptr(),
+                    &driver.anorm,
+                    &mut driver.rcond,
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sspcon_);
+impl_func_real!(f64, dspcon_);
+impl_func_comp!(c32, cspcon_, chpcon_);
+impl_func_comp!(c64, zspcon_, zhpcon_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct SPCON_<'a, 'p, F>
+where
+    F: SPCONNum,
+{
+    // input
+    /// Factor from `SPTRF`.
+    pub ap: &'a PackedMatrix<F>,
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+    /// 1-norm of the original matrix.
+    pub anorm: F::RealFloat,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct HPCON_<'a, 'p, F>
+where
+    F: SPCONNum,
+{
+    // input
+    /// Factor from `HPTRF`.
+    pub ap: &'a PackedMatrix<F>,
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+    /// 1-norm of the original matrix.
+    pub anorm: F::RealFloat,
+}
+
+impl<'a, 'p, F> SPCON_<'a, 'p, F>
+where
+    F: SPCONNum,
+{
+    /// Reciprocal of the condition number (in the 1-norm).
+    pub fn driver(self) -> Result<F::RealFloat, LapackError> {
+        self.driver_hermi(false)
+    }
+
+    fn driver_hermi(self, hermi: bool) -> Result<F::RealFloat, LapackError> {
+        let Self { ap, ipiv, anorm } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let n = ap.n;
+        let ipiv = ipiv.to_seq_layout()?;
+
+        // 2. dim check & buffer alloc
+        lapack_assert_eq!(ipiv.len(), n, InvalidDim)?;
+        let work = Array1::zeros(2 * n);
+        let iwork = Array1::zeros(n);
+
+        // 3. struct build
+        let mut driver = SPCON_Driver {
+            hermi,
+            uplo: ap.uplo as c_char,
+            n: n.try_into()?,
+            ap: ap.view(),
+            ipiv: ipiv.view(),
+            anorm,
+            rcond: F::RealFloat::zero(),
+            work,
+            iwork,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_spcon(&mut driver)?;
+        }
+
+        // 5. finalize
+        return Ok(driver.rcond);
+    }
+}
+
+impl<'a, 'p, F> HPCON_<'a, 'p, F>
+where
+    F: SPCONNum,
+{
+    /// Reciprocal of the condition number (in the 1-norm).
+    pub fn driver(self) -> Result<F::RealFloat, LapackError> {
+        let Self { ap, ipiv, anorm } = self;
+        SPCON_ { ap, ipiv, anorm }.driver_hermi(true)
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type SPCON<'a, 'p, F> = SPCON_Builder<'a, 'p, F>;
+pub type HPCON<'a, 'p, F> = HPCON_Builder<'a, 'p, F>;
+pub type SSPCON<'a, 'p> = SPCON<'a, 'p, f32>;
+pub type DSPCON<'a, 'p> = SPCON<'a, 'p, f64>;
+pub type CSPCON<'a, 'p> = SPCON<'a, 'p, c32>;
+pub type ZSPCON<'a, 'p> = SPCON<'a, 'p, c64>;
+pub type CHPCON<'a, 'p> = HPCON<'a, 'p, c32>;
+pub type ZHPCON<'a, 'p> = HPCON<'a, 'p, c64>;
+
+impl<'a, 'p, F> SPCON_Builder<'a, 'p, F>
+where
+    F: SPCONNum,
+{
+    pub fn run(self) -> Result<F::RealFloat, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+impl<'a, 'p, F> HPCON_Builder<'a, 'p, F>
+where
+    F: SPCONNum,
+{
+    pub fn run(self) -> Result<F::RealFloat, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
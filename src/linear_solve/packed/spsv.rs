@@ -0,0 +1,218 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct SPSV_Driver<'b, F>
+where
+    F: LapackFloat,
+{
+    hermi: bool,
+    uplo: c_char,
+    n: lapack_int,
+    nrhs: lapack_int,
+    ap: Array1<F>,
+    ipiv: Array1<lapack_int>,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    info: lapack_int,
+}
+
+pub trait SPSVNum: LapackFloat {
+    unsafe fn run_spsv(driver: &mut SPSV_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl SPSVNum for $type {
+            unsafe fn run_spsv(driver: &mut SPSV_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.ap.as_mut_ptr(),
+                    driver.ipiv.as_mut_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func_sy:ident, $func_he:ident) => {
+        impl SPSVNum for $type {
+            unsafe fn run_spsv(driver: &mut SPSV_Driver<Self>) -> Result<(), LapackError> {
+                let func = if driver.hermi { ffi::$func_he } else { ffi::$func_sy };
+                func(
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.ap.as_mut_ptr(),
+                    driver.ipiv.as_mut_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sspsv_);
+impl_func_real!(f64, dspsv_);
+impl_func_comp!(c32, cspsv_, chpsv_);
+impl_func_comp!(c64, zspsv_, zhpsv_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`SPSV`] and [`HPSV`]: factorization `(ap, ipiv)` and solution `b`.
+pub type SPSV_Output<'b, F> = (PackedMatrix<F>, Array1<lapack_int>, ArrayOut2<'b, F>);
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct SPSV_<'b, F>
+where
+    F: SPSVNum,
+{
+    // input/output
+    /// Overwritten by its factor on exit.
+    pub ap: PackedMatrix<F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct HPSV_<'b, F>
+where
+    F: SPSVNum,
+{
+    // input/output
+    /// Overwritten by its factor on exit.
+    pub ap: PackedMatrix<F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+}
+
+impl<'b, F> SPSV_<'b, F>
+where
+    F: SPSVNum,
+{
+    pub fn driver(self) -> Result<SPSV_Output<'b, F>, LapackError> {
+        self.driver_hermi(false)
+    }
+
+    fn driver_hermi(self, hermi: bool) -> Result<SPSV_Output<'b, F>, LapackError> {
+        let Self { ap, b } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let (ap, n, uplo) = ap.into_raw();
+        let b = b.into_col_array_out();
+        let nrhs = b.view().ncols();
+
+        // 2. dim check & alloc
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+        let ipiv = Array1::zeros(n);
+
+        // 3. struct build
+        let mut driver = SPSV_Driver {
+            hermi,
+            uplo: uplo as c_char,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            ap,
+            ipiv,
+            b,
+            ldb: ldb.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_spsv(&mut driver)?;
+        }
+
+        // 5. finalize
+        let SPSV_Driver { ap, ipiv, b, .. } = driver;
+        return Ok((PackedMatrix { ap, n, uplo }, ipiv, b.clone_to_view_mut()));
+    }
+}
+
+impl<'b, F> HPSV_<'b, F>
+where
+    F: SPSVNum,
+{
+    pub fn driver(self) -> Result<SPSV_Output<'b, F>, LapackError> {
+        let Self { ap, b } = self;
+        SPSV_ { ap, b }.driver_hermi(true)
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type SPSV<'b, F> = SPSV_Builder<'b, F>;
+pub type HPSV<'b, F> = HPSV_Builder<'b, F>;
+pub type SSPSV<'b> = SPSV<'b, f32>;
+pub type DSPSV<'b> = SPSV<'b, f64>;
+pub type CSPSV<'b> = SPSV<'b, c32>;
+pub type ZSPSV<'b> = SPSV<'b, c64>;
+pub type CHPSV<'b> = HPSV<'b, c32>;
+pub type ZHPSV<'b> = HPSV<'b, c64>;
+
+impl<'b, F> SPSV_Builder<'b, F>
+where
+    F: SPSVNum,
+{
+    pub fn run(self) -> Result<SPSV_Output<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (ap, ipiv, b) = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((ap, ipiv, b));
+        }
+    }
+}
+
+impl<'b, F> HPSV_Builder<'b, F>
+where
+    F: SPSVNum,
+{
+    pub fn run(self) -> Result<SPSV_Output<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (ap, ipiv, b) = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((ap, ipiv, b));
+        }
+    }
+}
+
+/* #endregion */
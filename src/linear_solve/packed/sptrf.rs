@@ -0,0 +1,164 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct SPTRF_Driver<F>
+where
+    F: LapackFloat,
+{
+    hermi: bool,
+    uplo: c_char,
+    n: lapack_int,
+    ap: Array1<F>,
+    ipiv: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait SPTRFNum: LapackFloat {
+    unsafe fn run_sptrf(driver: &mut SPTRF_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl SPTRFNum for $type {
+            unsafe fn run_sptrf(driver: &mut SPTRF_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.ap.as_mut_ptr(),
+                    driver.ipiv.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func_sy:ident, $func_he:ident) => {
+        impl SPTRFNum for $type {
+            unsafe fn run_sptrf(driver: &mut SPTRF_Driver<Self>) -> Result<(), LapackError> {
+                let func = if driver.hermi { ffi::$func_he } else { ffi::$func_sy };
+                func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.ap.as_mut_ptr(),
+                    driver.ipiv.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, ssptrf_);
+impl_func_real!(f64, dsptrf_);
+impl_func_comp!(c32, csptrf_, chptrf_);
+impl_func_comp!(c64, zsptrf_, zhptrf_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct SPTRF_<F>
+where
+    F: SPTRFNum,
+{
+    // input/output
+    pub ap: PackedMatrix<F>,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct HPTRF_<F>
+where
+    F: SPTRFNum,
+{
+    // input/output
+    pub ap: PackedMatrix<F>,
+}
+
+impl<F> SPTRF_<F>
+where
+    F: SPTRFNum,
+{
+    pub fn driver(self) -> Result<(PackedMatrix<F>, Array1<lapack_int>), LapackError> {
+        self.driver_hermi(false)
+    }
+
+    fn driver_hermi(self, hermi: bool) -> Result<(PackedMatrix<F>, Array1<lapack_int>), LapackError> {
+        let Self { ap } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let (ap, n, uplo) = ap.into_raw();
+
+        // 2. alloc
+        let ipiv = Array1::zeros(n);
+
+        // 3. struct build
+        let mut driver = SPTRF_Driver { hermi, uplo: uplo as c_char, n: n.try_into()?, ap, ipiv, info: 0 };
+
+        // 4. perform comput
+        unsafe {
+            F::run_sptrf(&mut driver)?;
+        }
+
+        // 5. finalize
+        let SPTRF_Driver { ap, ipiv, .. } = driver;
+        return Ok((PackedMatrix { ap, n, uplo }, ipiv));
+    }
+}
+
+impl<F> HPTRF_<F>
+where
+    F: SPTRFNum,
+{
+    pub fn driver(self) -> Result<(PackedMatrix<F>, Array1<lapack_int>), LapackError> {
+        let Self { ap } = self;
+        SPTRF_ { ap }.driver_hermi(true)
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type SPTRF<F> = SPTRF_Builder<F>;
+pub type HPTRF<F> = HPTRF_Builder<F>;
+pub type SSPTRF = SPTRF<f32>;
+pub type DSPTRF = SPTRF<f64>;
+pub type CSPTRF = SPTRF<c32>;
+pub type ZSPTRF = SPTRF<c64>;
+pub type CHPTRF = HPTRF<c32>;
+pub type ZHPTRF = HPTRF<c64>;
+
+impl<F> SPTRF_Builder<F>
+where
+    F: SPTRFNum,
+{
+    pub fn run(self) -> Result<(PackedMatrix<F>, Array1<lapack_int>), LapackError> {
+        self.build()?.driver()
+    }
+}
+
+impl<F> HPTRF_Builder<F>
+where
+    F: SPTRFNum,
+{
+    pub fn run(self) -> Result<(PackedMatrix<F>, Array1<lapack_int>), LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,187 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct SPTRI_Driver<'p, F>
+where
+    F: LapackFloat,
+{
+    hermi: bool,
+    uplo: c_char,
+    n: lapack_int,
+    ap: Array1<F>,
+    ipiv: ArrayView1<'p, lapack_int>,
+    work: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait SPTRINum: LapackFloat {
+    unsafe fn run_sptri(driver: &mut SPTRI_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl SPTRINum for $type {
+            unsafe fn run_sptri(driver: &mut SPTRI_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.ap.as_mut_ptr(),
+                    driver.ipiv.as_ptr(),
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func_sy:ident, $func_he:ident) => {
+        impl SPTRINum for $type {
+            unsafe fn run_sptri(driver: &mut SPTRI_Driver<Self>) -> Result<(), LapackError> {
+                let func = if driver.hermi { ffi::$func_he } else { ffi::$func_sy };
+                func(
+                    &driver.uplo,
+                    &driver.n,
+                    driver.ap.as_mut_ptr(),
+                    driver.ipiv.as_ptr(),
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, ssptri_);
+impl_func_real!(f64, dsptri_);
+impl_func_comp!(c32, csptri_, chptri_);
+impl_func_comp!(c64, zsptri_, zhptri_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct SPTRI_<'p, F>
+where
+    F: SPTRINum,
+{
+    // input/output
+    /// Factor from `SPTRF`, overwritten by the inverse on exit.
+    pub ap: PackedMatrix<F>,
+
+    // input
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct HPTRI_<'p, F>
+where
+    F: SPTRINum,
+{
+    // input/output
+    /// Factor from `HPTRF`, overwritten by the inverse on exit.
+    pub ap: PackedMatrix<F>,
+
+    // input
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+}
+
+impl<'p, F> SPTRI_<'p, F>
+where
+    F: SPTRINum,
+{
+    pub fn driver(self) -> Result<PackedMatrix<F>, LapackError> {
+        self.driver_hermi(false)
+    }
+
+    fn driver_hermi(self, hermi: bool) -> Result<PackedMatrix<F>, LapackError> {
+        let Self { ap, ipiv } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let (ap, n, uplo) = ap.into_raw();
+        let ipiv = ipiv.to_seq_layout()?;
+
+        // 2. dim check & buffer alloc
+        lapack_assert_eq!(ipiv.len(), n, InvalidDim)?;
+        let work = Array1::zeros(n);
+
+        // 3. struct build
+        let mut driver = SPTRI_Driver {
+            hermi,
+            uplo: uplo as c_char,
+            n: n.try_into()?,
+            ap,
+            ipiv: ipiv.view(),
+            work,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_sptri(&mut driver)?;
+        }
+
+        // 5. finalize
+        let SPTRI_Driver { ap, .. } = driver;
+        return Ok(PackedMatrix { ap, n, uplo });
+    }
+}
+
+impl<'p, F> HPTRI_<'p, F>
+where
+    F: SPTRINum,
+{
+    pub fn driver(self) -> Result<PackedMatrix<F>, LapackError> {
+        let Self { ap, ipiv } = self;
+        SPTRI_ { ap, ipiv }.driver_hermi(true)
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type SPTRI<'p, F> = SPTRI_Builder<'p, F>;
+pub type HPTRI<'p, F> = HPTRI_Builder<'p, F>;
+pub type SSPTRI<'p> = SPTRI<'p, f32>;
+pub type DSPTRI<'p> = SPTRI<'p, f64>;
+pub type CSPTRI<'p> = SPTRI<'p, c32>;
+pub type ZSPTRI<'p> = SPTRI<'p, c64>;
+pub type CHPTRI<'p> = HPTRI<'p, c32>;
+pub type ZHPTRI<'p> = HPTRI<'p, c64>;
+
+impl<'p, F> SPTRI_Builder<'p, F>
+where
+    F: SPTRINum,
+{
+    pub fn run(self) -> Result<PackedMatrix<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+impl<'p, F> HPTRI_Builder<'p, F>
+where
+    F: SPTRINum,
+{
+    pub fn run(self) -> Result<PackedMatrix<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,224 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct SPTRS_Driver<'a, 'p, 'b, F>
+where
+    F: LapackFloat,
+{
+    hermi: bool,
+    uplo: c_char,
+    n: lapack_int,
+    nrhs: lapack_int,
+    ap: ArrayView1<'a, F>,
+    ipiv: ArrayView1<'p, lapack_int>,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    info: lapack_int,
+}
+
+pub trait SPTRSNum: LapackFloat {
+    unsafe fn run_sptrs(driver: &mut SPTRS_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl SPTRSNum for $type {
+            unsafe fn run_sptrs(driver: &mut SPTRS_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.ap.as_ptr(),
+                    driver.ipiv.as_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func_sy:ident, $func_he:ident) => {
+        impl SPTRSNum for $type {
+            unsafe fn run_sptrs(driver: &mut SPTRS_Driver<Self>) -> Result<(), LapackError> {
+                let func = if driver.hermi { ffi::$func_he } else { ffi::$func_sy };
+                func(
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.ap.as_ptr(),
+                    driver.ipiv.as_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, ssptrs_);
+impl_func_real!(f64, dsptrs_);
+impl_func_comp!(c32, csptrs_, chptrs_);
+impl_func_comp!(c64, zsptrs_, zhptrs_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct SPTRS_<'a, 'p, 'b, F>
+where
+    F: SPTRSNum,
+{
+    // input
+    /// Factor from `SPTRF`.
+    pub ap: &'a PackedMatrix<F>,
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct HPTRS_<'a, 'p, 'b, F>
+where
+    F: SPTRSNum,
+{
+    // input
+    /// Factor from `HPTRF`.
+    pub ap: &'a PackedMatrix<F>,
+    #[builder(setter(into))]
+    pub ipiv: ArrayView1<'p, lapack_int>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+}
+
+impl<'a, 'p, 'b, F> SPTRS_<'a, 'p, 'b, F>
+where
+    F: SPTRSNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        self.driver_hermi(false)
+    }
+
+    fn driver_hermi(self, hermi: bool) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let Self { ap, ipiv, b } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let n = ap.n;
+        let ipiv = ipiv.to_seq_layout()?;
+        let b = b.into_col_array_out();
+        let nrhs = b.view().ncols();
+
+        // 2. dim check
+        lapack_assert_eq!(ipiv.len(), n, InvalidDim)?;
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = SPTRS_Driver {
+            hermi,
+            uplo: ap.uplo as c_char,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            ap: ap.view(),
+            ipiv: ipiv.view(),
+            b,
+            ldb: ldb.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_sptrs(&mut driver)?;
+        }
+
+        // 5. finalize
+        let SPTRS_Driver { b, .. } = driver;
+        return Ok(b.clone_to_view_mut());
+    }
+}
+
+impl<'a, 'p, 'b, F> HPTRS_<'a, 'p, 'b, F>
+where
+    F: SPTRSNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let Self { ap, ipiv, b } = self;
+        SPTRS_ { ap, ipiv, b }.driver_hermi(true)
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type SPTRS<'a, 'p, 'b, F> = SPTRS_Builder<'a, 'p, 'b, F>;
+pub type HPTRS<'a, 'p, 'b, F> = HPTRS_Builder<'a, 'p, 'b, F>;
+pub type SSPTRS<'a, 'p, 'b> = SPTRS<'a, 'p, 'b, f32>;
+pub type DSPTRS<'a, 'p, 'b> = SPTRS<'a, 'p, 'b, f64>;
+pub type CSPTRS<'a, 'p, 'b> = SPTRS<'a, 'p, 'b, c32>;
+pub type ZSPTRS<'a, 'p, 'b> = SPTRS<'a, 'p, 'b, c64>;
+pub type CHPTRS<'a, 'p, 'b> = HPTRS<'a, 'p, 'b, c32>;
+pub type ZHPTRS<'a, 'p, 'b> = HPTRS<'a, 'p, 'b, c64>;
+
+impl<'a, 'p, 'b, F> SPTRS_Builder<'a, 'p, 'b, F>
+where
+    F: SPTRSNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let b = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(b);
+        }
+    }
+}
+
+impl<'a, 'p, 'b, F> HPTRS_Builder<'a, 'p, 'b, F>
+where
+    F: SPTRSNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let b = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(b);
+        }
+    }
+}
+
+/* #endregion */
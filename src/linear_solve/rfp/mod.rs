@@ -0,0 +1,13 @@
+pub mod pftrf;
+pub mod pftri;
+pub mod pftrs;
+pub mod rfp_matrix;
+pub mod sfrk;
+pub mod tfsm;
+
+pub use pftrf::*;
+pub use pftri::*;
+pub use pftrs::*;
+pub use rfp_matrix::*;
+pub use sfrk::*;
+pub use tfsm::*;
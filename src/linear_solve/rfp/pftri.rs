@@ -0,0 +1,100 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::rfp::RfpMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct PFTRI_Driver<F>
+where
+    F: LapackFloat,
+{
+    transr: c_char,
+    uplo: c_char,
+    n: lapack_int,
+    a: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait PFTRINum: LapackFloat {
+    unsafe fn run_pftri(driver: &mut PFTRI_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl PFTRINum for $type {
+            unsafe fn run_pftri(driver: &mut PFTRI_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(&driver.transr, &driver.uplo, &driver.n, driver.a.as_mut_ptr(), &mut driver.info);
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, spftri_);
+impl_func!(f64, dpftri_);
+impl_func!(c32, cpftri_);
+impl_func!(c64, zpftri_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct PFTRI_<F>
+where
+    F: PFTRINum,
+{
+    // input/output
+    pub a: RfpMatrix<F>,
+}
+
+impl<F> PFTRI_<F>
+where
+    F: PFTRINum,
+{
+    pub fn driver(self) -> Result<RfpMatrix<F>, LapackError> {
+        let Self { a } = self;
+
+        // 1. dim assign, flag check
+        a.check()?;
+        let (a, n, transr, uplo) = a.into_raw();
+
+        // 2. struct build
+        let mut driver =
+            PFTRI_Driver { transr: transr as c_char, uplo: uplo as c_char, n: n.try_into()?, a, info: 0 };
+
+        // 3. perform comput
+        unsafe {
+            F::run_pftri(&mut driver)?;
+        }
+
+        // 4. finalize
+        let PFTRI_Driver { a, .. } = driver;
+        return Ok(RfpMatrix { arf: a, n, transr, uplo });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type PFTRI<F> = PFTRI_Builder<F>;
+pub type SPFTRI = PFTRI<f32>;
+pub type DPFTRI = PFTRI<f64>;
+pub type CPFTRI = PFTRI<c32>;
+pub type ZPFTRI = PFTRI<c64>;
+
+impl<F> PFTRI_Builder<F>
+where
+    F: PFTRINum,
+{
+    pub fn run(self) -> Result<RfpMatrix<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,142 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::rfp::RfpMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct PFTRS_Driver<'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    transr: c_char,
+    uplo: c_char,
+    n: lapack_int,
+    nrhs: lapack_int,
+    a: ArrayView1<'a, F>,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    info: lapack_int,
+}
+
+pub trait PFTRSNum: LapackFloat {
+    unsafe fn run_pftrs(driver: &mut PFTRS_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl PFTRSNum for $type {
+            unsafe fn run_pftrs(driver: &mut PFTRS_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.transr,
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.a.as_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, spftrs_);
+impl_func!(f64, dpftrs_);
+impl_func!(c32, cpftrs_);
+impl_func!(c64, zpftrs_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct PFTRS_<'a, 'b, F>
+where
+    F: PFTRSNum,
+{
+    // input
+    /// Cholesky factor from `PFTRF`.
+    pub a: &'a RfpMatrix<F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+}
+
+impl<'a, 'b, F> PFTRS_<'a, 'b, F>
+where
+    F: PFTRSNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let Self { a, b } = self;
+
+        // 1. dim assign, flag check
+        a.check()?;
+        let n = a.n;
+        let b = b.into_col_array_out();
+        let nrhs = b.view().ncols();
+
+        // 2. dim check
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = PFTRS_Driver {
+            transr: a.transr as c_char,
+            uplo: a.uplo as c_char,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            a: a.view(),
+            b,
+            ldb: ldb.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_pftrs(&mut driver)?;
+        }
+
+        // 5. finalize
+        let PFTRS_Driver { b, .. } = driver;
+        return Ok(b.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type PFTRS<'a, 'b, F> = PFTRS_Builder<'a, 'b, F>;
+pub type SPFTRS<'a, 'b> = PFTRS<'a, 'b, f32>;
+pub type DPFTRS<'a, 'b> = PFTRS<'a, 'b, f64>;
+pub type CPFTRS<'a, 'b> = PFTRS<'a, 'b, c32>;
+pub type ZPFTRS<'a, 'b> = PFTRS<'a, 'b, c64>;
+
+impl<'a, 'b, F> PFTRS_Builder<'a, 'b, F>
+where
+    F: PFTRSNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let b = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(b);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,258 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::{packed_dim, PackedMatrix};
+use crate::{lapack_info, util::*};
+use core::cmp::max;
+use ndarray::prelude::*;
+
+/* #region RfpMatrix */
+
+/// Triangle of a square matrix stored in LAPACK Rectangular Full Packed (RFP) format.
+///
+/// Only one triangle (given by `uplo`) of the `n x n` matrix is kept, in an array of length `n * (n + 1) / 2`.
+/// `transr` tells whether the RFP array is stored in normal (`'N'`) or transposed (`'T'` for real, `'C'` for
+/// complex) form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RfpMatrix<F> {
+    pub arf: Array1<F>,
+    pub n: usize,
+    pub transr: char,
+    pub uplo: char,
+}
+
+pub trait RfpMatrixNum: LapackFloat {
+    unsafe fn run_trttf(
+        transr: c_char,
+        uplo: c_char,
+        n: lapack_int,
+        a: *const Self,
+        lda: lapack_int,
+        arf: *mut Self,
+    ) -> Result<(), LapackError>;
+    unsafe fn run_tfttr(
+        transr: c_char,
+        uplo: c_char,
+        n: lapack_int,
+        arf: *const Self,
+        a: *mut Self,
+        lda: lapack_int,
+    ) -> Result<(), LapackError>;
+    unsafe fn run_tpttf(
+        transr: c_char,
+        uplo: c_char,
+        n: lapack_int,
+        ap: *const Self,
+        arf: *mut Self,
+    ) -> Result<(), LapackError>;
+    unsafe fn run_tfttp(
+        transr: c_char,
+        uplo: c_char,
+        n: lapack_int,
+        arf: *const Self,
+        ap: *mut Self,
+    ) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $trttf:ident, $tfttr:ident, $tpttf:ident, $tfttp:ident) => {
+        impl RfpMatrixNum for $type {
+            unsafe fn run_trttf(
+                transr: c_char,
+                uplo: c_char,
+                n: lapack_int,
+                a: *const Self,
+                lda: lapack_int,
+                arf: *mut Self,
+            ) -> Result<(), LapackError> {
+                let mut info = 0;
+                ffi::$trttf(&transr, &uplo, &n, a, &lda, arf, &mut info);
+                lapack_info!(info)?;
+                return Ok(());
+            }
+
+            unsafe fn run_tfttr(
+                transr: c_char,
+                uplo: c_char,
+                n: lapack_int,
+                arf: *const Self,
+                a: *mut Self,
+                lda: lapack_int,
+            ) -> Result<(), LapackError> {
+                let mut info = 0;
+                ffi::$tfttr(&transr, &uplo, &n, arf, a, &lda, &mut info);
+                lapack_info!(info)?;
+                return Ok(());
+            }
+
+            unsafe fn run_tpttf(
+                transr: c_char,
+                uplo: c_char,
+                n: lapack_int,
+                ap: *const Self,
+                arf: *mut Self,
+            ) -> Result<(), LapackError> {
+                let mut info = 0;
+                ffi::$tpttf(&transr, &uplo, &n, ap, arf, &mut info);
+                lapack_info!(info)?;
+                return Ok(());
+            }
+
+            unsafe fn run_tfttp(
+                transr: c_char,
+                uplo: c_char,
+                n: lapack_int,
+                arf: *const Self,
+                ap: *mut Self,
+            ) -> Result<(), LapackError> {
+                let mut info = 0;
+                ffi::$tfttp(&transr, &uplo, &n, arf, ap, &mut info);
+                lapack_info!(info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, strttf_, stfttr_, stpttf_, stfttp_);
+impl_func!(f64, dtrttf_, dtfttr_, dtpttf_, dtfttp_);
+impl_func!(c32, ctrttf_, ctfttr_, ctpttf_, ctfttp_);
+impl_func!(c64, ztrttf_, ztfttr_, ztpttf_, ztfttp_);
+
+/// Normalize `transr` flag: `'T'` for real and `'C'` for complex are both accepted as the transposed form.
+fn normalize_transr<F>(transr: char) -> Result<char, LapackError>
+where
+    F: LapackFloat,
+{
+    let transr = transr.to_ascii_uppercase();
+    lapack_check_flag!(transr, ['N', 'T', 'C'])?;
+    return match (transr, F::is_complex()) {
+        ('N', _) => Ok('N'),
+        (_, true) => Ok('C'),
+        (_, false) => Ok('T'),
+    };
+}
+
+impl<F> RfpMatrix<F>
+where
+    F: LapackFloat,
+{
+    /// Wrap RFP array `arf`; dimension `n` is deduced from its length.
+    pub fn new(arf: Array1<F>, transr: char, uplo: char) -> Result<Self, LapackError> {
+        let transr = normalize_transr::<F>(transr)?;
+        let uplo = uplo.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        let n = match packed_dim(arf.len()) {
+            Some(n) => n,
+            None => lapack_raise!(InvalidDim, "length of RFP array is not n * (n + 1) / 2")?,
+        };
+        let arf = match arf.is_standard_layout() {
+            true => arf,
+            false => arf.as_standard_layout().into_owned(),
+        };
+        return Ok(Self { arf, n, transr, uplo });
+    }
+
+    /// Check consistency of flags, dimension and memory layout, before passing to LAPACK.
+    pub fn check(&self) -> Result<(), LapackError> {
+        lapack_assert_eq!(self.transr, normalize_transr::<F>(self.transr)?, InvalidFlag)?;
+        lapack_check_flag!(self.uplo, ['U', 'L'])?;
+        lapack_assert_eq!(self.arf.len(), self.n * (self.n + 1) / 2, InvalidDim)?;
+        lapack_assert!(self.arf.is_standard_layout(), InvalidDim, "RFP array should be contiguous")?;
+        return Ok(());
+    }
+
+    pub fn view(&self) -> ArrayView1<'_, F> {
+        self.arf.view()
+    }
+
+    pub fn view_mut(&mut self) -> ArrayViewMut1<'_, F> {
+        self.arf.view_mut()
+    }
+
+    pub fn into_raw(self) -> (Array1<F>, usize, char, char) {
+        let Self { arf, n, transr, uplo } = self;
+        return (arf, n, transr, uplo);
+    }
+}
+
+impl<F> RfpMatrix<F>
+where
+    F: RfpMatrixNum,
+{
+    /// Convert triangle `uplo` of dense square matrix `a` to RFP format (trttf).
+    ///
+    /// Elements in the other triangle of `a` are not referenced.
+    pub fn from_dense(a: &ArrayView2<F>, transr: char, uplo: char) -> Result<Self, LapackError> {
+        let transr = normalize_transr::<F>(transr)?;
+        let uplo = uplo.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        let n = a.nrows();
+        lapack_assert_eq!(a.dim(), (n, n), InvalidDim)?;
+        let a = a.to_col_layout()?;
+        let lda = max(a.stride_of(Axis(1)), max(n, 1) as isize);
+        let mut arf = Array1::<F>::zeros(n * (n + 1) / 2);
+        unsafe {
+            F::run_trttf(
+                transr as c_char,
+                uplo as c_char,
+                n.try_into()?,
+                a.as_ptr(),
+                lda.try_into()?,
+                arf.as_mut_ptr(),
+            )?;
+        }
+        return Ok(Self { arf, n, transr, uplo });
+    }
+
+    /// Convert to dense column-major matrix (tfttr).
+    ///
+    /// Only triangle `uplo` is filled; the other triangle is zero.
+    pub fn to_dense(&self) -> Result<Array2<F>, LapackError> {
+        self.check()?;
+        let n = self.n;
+        let mut a = Array2::<F>::zeros((n, n).f());
+        let lda = max(n, 1);
+        unsafe {
+            F::run_tfttr(
+                self.transr as c_char,
+                self.uplo as c_char,
+                n.try_into()?,
+                self.arf.as_ptr(),
+                a.as_mut_ptr(),
+                lda.try_into()?,
+            )?;
+        }
+        return Ok(a);
+    }
+
+    /// Convert from standard packed format (tpttf); `uplo` is taken from `ap`.
+    pub fn from_packed(ap: &PackedMatrix<F>, transr: char) -> Result<Self, LapackError> {
+        ap.check()?;
+        let transr = normalize_transr::<F>(transr)?;
+        let n = ap.n;
+        let uplo = ap.uplo;
+        let mut arf = Array1::<F>::zeros(n * (n + 1) / 2);
+        unsafe {
+            F::run_tpttf(transr as c_char, uplo as c_char, n.try_into()?, ap.ap.as_ptr(), arf.as_mut_ptr())?;
+        }
+        return Ok(Self { arf, n, transr, uplo });
+    }
+
+    /// Convert to standard packed format (tfttp).
+    pub fn to_packed(&self) -> Result<PackedMatrix<F>, LapackError> {
+        self.check()?;
+        let n = self.n;
+        let mut ap = Array1::<F>::zeros(n * (n + 1) / 2);
+        unsafe {
+            F::run_tfttp(
+                self.transr as c_char,
+                self.uplo as c_char,
+                n.try_into()?,
+                self.arf.as_ptr(),
+                ap.as_mut_ptr(),
+            )?;
+        }
+        return Ok(PackedMatrix { ap, n, uplo: self.uplo });
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,163 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::rfp::RfpMatrix;
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::{One, Zero};
+
+/* #region Lapack driver */
+
+pub struct SFRK_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    transr: c_char,
+    uplo: c_char,
+    trans: c_char,
+    n: lapack_int,
+    k: lapack_int,
+    alpha: F::RealFloat,
+    a: ArrayView2<'a, F>,
+    lda: lapack_int,
+    beta: F::RealFloat,
+    c: Array1<F>,
+}
+
+pub trait SFRKNum: LapackFloat {
+    unsafe fn run_sfrk(driver: &mut SFRK_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl SFRKNum for $type {
+            unsafe fn run_sfrk(driver: &mut SFRK_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.transr,
+                    &driver.uplo,
+                    &driver.trans,
+                    &driver.n,
+                    &driver.k,
+                    &driver.alpha,
+                    driver.a.as_ptr(),
+                    &driver.lda,
+                    &driver.beta,
+                    driver.c.as_mut_ptr(),
+                );
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, ssfrk_);
+impl_func!(f64, dsfrk_);
+impl_func!(c32, chfrk_);
+impl_func!(c64, zhfrk_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Symmetric (real) or Hermitian (complex) rank-k update of matrix in RFP format.
+///
+/// `C = alpha * A * A^H + beta * C` for `trans = 'N'`, or `C = alpha * A^H * A + beta * C` for `trans = 'C'`. For real
+/// matrices, `trans = 'T'` is the same as `'C'`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct SFRK_<'a, F>
+where
+    F: SFRKNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub a: ArrayView2<'a, F>,
+
+    // input/output
+    pub c: RfpMatrix<F>,
+
+    // option
+    #[builder(default = "F::RealFloat::one()")]
+    pub alpha: F::RealFloat,
+    #[builder(default = "F::RealFloat::zero()")]
+    pub beta: F::RealFloat,
+    #[builder(default = "'N'")]
+    pub trans: char,
+}
+
+impl<'a, F> SFRK_<'a, F>
+where
+    F: SFRKNum,
+{
+    pub fn driver(self) -> Result<RfpMatrix<F>, LapackError> {
+        let Self { a, c, alpha, beta, trans } = self;
+
+        // 1. dim assign, flag check
+        c.check()?;
+        let (c, n, transr, uplo) = c.into_raw();
+        let trans = match (trans.to_ascii_uppercase(), F::is_complex()) {
+            ('C', false) => 'T',
+            (trans, _) => trans,
+        };
+        match F::is_complex() {
+            true => lapack_check_flag!(trans, ['N', 'C'])?,
+            false => lapack_check_flag!(trans, ['N', 'T'])?,
+        };
+        let a = a.to_col_layout()?;
+        let k = match trans {
+            'N' => a.ncols(),
+            _ => a.nrows(),
+        };
+
+        // 2. dim check
+        match trans {
+            'N' => lapack_assert_eq!(a.dim(), (n, k), InvalidDim)?,
+            _ => lapack_assert_eq!(a.dim(), (k, n), InvalidDim)?,
+        };
+        let lda = a.stride_of(Axis(1)).max(a.nrows().max(1) as isize);
+
+        // 3. struct build
+        let mut driver = SFRK_Driver {
+            transr: transr as c_char,
+            uplo: uplo as c_char,
+            trans: trans as c_char,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            alpha,
+            a: a.view(),
+            lda: lda.try_into()?,
+            beta,
+            c,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_sfrk(&mut driver)?;
+        }
+
+        // 5. finalize
+        let SFRK_Driver { c, .. } = driver;
+        return Ok(RfpMatrix { arf: c, n, transr, uplo });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type SFRK<'a, F> = SFRK_Builder<'a, F>;
+pub type HFRK<'a, F> = SFRK_Builder<'a, F>;
+pub type SSFRK<'a> = SFRK<'a, f32>;
+pub type DSFRK<'a> = SFRK<'a, f64>;
+pub type CHFRK<'a> = HFRK<'a, c32>;
+pub type ZHFRK<'a> = HFRK<'a, c64>;
+
+impl<'a, F> SFRK_Builder<'a, F>
+where
+    F: SFRKNum,
+{
+    pub fn run(self) -> Result<RfpMatrix<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
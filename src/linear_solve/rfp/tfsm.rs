@@ -0,0 +1,175 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::rfp::RfpMatrix;
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TFSM_Driver<'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    transr: c_char,
+    side: c_char,
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    alpha: F,
+    a: ArrayView1<'a, F>,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+}
+
+pub trait TFSMNum: LapackFloat {
+    unsafe fn run_tfsm(driver: &mut TFSM_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TFSMNum for $type {
+            unsafe fn run_tfsm(driver: &mut TFSM_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.transr,
+                    &driver.side,
+                    &driver.uplo,
+                    &driver.trans,
+                    &driver.diag,
+                    &driver.m,
+                    &driver.n,
+                    &driver.alpha,
+                    driver.a.as_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                );
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, stfsm_);
+impl_func!(f64, dtfsm_);
+impl_func!(c32, ctfsm_);
+impl_func!(c64, ztfsm_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TFSM_<'a, 'b, F>
+where
+    F: TFSMNum,
+{
+    // input
+    /// Triangular matrix in RFP format.
+    pub a: &'a RfpMatrix<F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    #[builder(default = "F::one()")]
+    pub alpha: F,
+    #[builder(default = "'L'")]
+    pub side: char,
+    #[builder(default = "'N'")]
+    pub trans: char,
+    #[builder(default = "'N'")]
+    pub diag: char,
+}
+
+impl<'a, 'b, F> TFSM_<'a, 'b, F>
+where
+    F: TFSMNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let Self { a, b, alpha, side, trans, diag } = self;
+
+        // 1. dim assign, flag check
+        a.check()?;
+        let b = b.into_col_array_out();
+        let (m, n) = b.view().dim();
+        let side = side.to_ascii_uppercase();
+        let trans = match (trans.to_ascii_uppercase(), F::is_complex()) {
+            ('C', false) => 'T',
+            (trans, _) => trans,
+        };
+        let diag = diag.to_ascii_uppercase();
+        lapack_check_flag!(side, ['L', 'R'])?;
+        match F::is_complex() {
+            true => lapack_check_flag!(trans, ['N', 'C'])?,
+            false => lapack_check_flag!(trans, ['N', 'T'])?,
+        };
+        lapack_check_flag!(diag, ['N', 'U'])?;
+
+        // 2. dim check
+        match side {
+            'L' => lapack_assert_eq!(a.n, m, InvalidDim)?,
+            'R' => lapack_assert_eq!(a.n, n, InvalidDim)?,
+            _ => lapack_invalid!(side)?,
+        };
+        let ldb = b.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = TFSM_Driver {
+            transr: a.transr as c_char,
+            side: side as c_char,
+            uplo: a.uplo as c_char,
+            trans: trans as c_char,
+            diag: diag as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            alpha,
+            a: a.view(),
+            b,
+            ldb: ldb.try_into()?,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_tfsm(&mut driver)?;
+        }
+
+        // 5. finalize
+        let TFSM_Driver { b, .. } = driver;
+        return Ok(b.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TFSM<'a, 'b, F> = TFSM_Builder<'a, 'b, F>;
+pub type STFSM<'a, 'b> = TFSM<'a, 'b, f32>;
+pub type DTFSM<'a, 'b> = TFSM<'a, 'b, f64>;
+pub type CTFSM<'a, 'b> = TFSM<'a, 'b, c32>;
+pub type ZTFSM<'a, 'b> = TFSM<'a, 'b, c64>;
+
+impl<'a, 'b, F> TFSM_Builder<'a, 'b, F>
+where
+    F: TFSMNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let b = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(b);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,13 @@
+pub mod lauum;
+pub mod tbtrs;
+pub mod tptrs;
+pub mod trcon;
+pub mod trtri;
+pub mod trtrs;
+
+pub use lauum::*;
+pub use tbtrs::*;
+pub use tptrs::*;
+pub use trcon::*;
+pub use trtri::*;
+pub use trtrs::*;
@@ -0,0 +1,170 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TBTRS_Driver<'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: lapack_int,
+    kd: lapack_int,
+    nrhs: lapack_int,
+    ab: ArrayView2<'a, F>,
+    ldab: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    info: lapack_int,
+}
+
+pub trait TBTRSNum: LapackFloat {
+    unsafe fn run_tbtrs(driver: &mut TBTRS_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TBTRSNum for $type {
+            unsafe fn run_tbtrs(driver: &mut TBTRS_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.trans,
+                    &driver.diag,
+                    &driver.n,
+                    &driver.kd,
+                    &driver.nrhs,
+                    driver.ab.as_ptr(),
+                    &driver.ldab,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, stbtrs_);
+impl_func!(f64, dtbtrs_);
+impl_func!(c32, ctbtrs_);
+impl_func!(c64, ztbtrs_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Solve triangular system `op(A) X = B`, with `A` in band storage.
+///
+/// `ab` is of dimension `(kd + 1, n)`, in LAPACK (column-major) band storage convention.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TBTRS_<'a, 'b, F>
+where
+    F: TBTRSNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub ab: ArrayView2<'a, F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+    #[builder(default = "'N'")]
+    pub trans: char,
+    #[builder(default = "'N'")]
+    pub diag: char,
+}
+
+impl<'a, 'b, F> TBTRS_<'a, 'b, F>
+where
+    F: TBTRSNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let Self { ab, b, uplo, trans, diag } = self;
+
+        // 1. dim assign, flag check
+        let ab = ab.to_col_layout()?;
+        let b = b.into_col_array_out();
+        let (kd1, n) = ab.dim();
+        let nrhs = b.view().ncols();
+        let uplo = uplo.to_ascii_uppercase();
+        let trans = trans.to_ascii_uppercase();
+        let diag = diag.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        lapack_check_flag!(trans, ['N', 'T', 'C'])?;
+        lapack_check_flag!(diag, ['N', 'U'])?;
+
+        // 2. dim check
+        lapack_assert!(kd1 >= 1, InvalidDim, "band storage should have at least one row")?;
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        let kd = kd1 - 1;
+        let ldab = ab.stride_of(Axis(1)).max(kd1 as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = TBTRS_Driver {
+            uplo: uplo as c_char,
+            trans: trans as c_char,
+            diag: diag as c_char,
+            n: n.try_into()?,
+            kd: kd.try_into()?,
+            nrhs: nrhs.try_into()?,
+            ab: ab.view(),
+            ldab: ldab.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_tbtrs(&mut driver)?;
+        }
+
+        // 5. finalize
+        let TBTRS_Driver { b, .. } = driver;
+        return Ok(b.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TBTRS<'a, 'b, F> = TBTRS_Builder<'a, 'b, F>;
+pub type STBTRS<'a, 'b> = TBTRS<'a, 'b, f32>;
+pub type DTBTRS<'a, 'b> = TBTRS<'a, 'b, f64>;
+pub type CTBTRS<'a, 'b> = TBTRS<'a, 'b, c32>;
+pub type ZTBTRS<'a, 'b> = TBTRS<'a, 'b, c64>;
+
+impl<'a, 'b, F> TBTRS_Builder<'a, 'b, F>
+where
+    F: TBTRSNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let b = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(b);
+        }
+    }
+}
+
+/* #endregion */
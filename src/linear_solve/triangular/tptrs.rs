@@ -0,0 +1,155 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::linear_solve::packed::PackedMatrix;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TPTRS_Driver<'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: lapack_int,
+    nrhs: lapack_int,
+    ap: ArrayView1<'a, F>,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    info: lapack_int,
+}
+
+pub trait TPTRSNum: LapackFloat {
+    unsafe fn run_tptrs(driver: &mut TPTRS_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TPTRSNum for $type {
+            unsafe fn run_tptrs(driver: &mut TPTRS_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.trans,
+                    &driver.diag,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.ap.as_ptr(),
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, stptrs_);
+impl_func!(f64, dtptrs_);
+impl_func!(c32, ctptrs_);
+impl_func!(c64, ztptrs_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Solve triangular system `op(A) X = B`, with `A` in packed storage.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TPTRS_<'a, 'b, F>
+where
+    F: TPTRSNum,
+{
+    // input
+    pub ap: &'a PackedMatrix<F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    #[builder(default = "'N'")]
+    pub trans: char,
+    #[builder(default = "'N'")]
+    pub diag: char,
+}
+
+impl<'a, 'b, F> TPTRS_<'a, 'b, F>
+where
+    F: TPTRSNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let Self { ap, b, trans, diag } = self;
+
+        // 1. dim assign, flag check
+        ap.check()?;
+        let n = ap.n;
+        let b = b.into_col_array_out();
+        let nrhs = b.view().ncols();
+        let trans = trans.to_ascii_uppercase();
+        let diag = diag.to_ascii_uppercase();
+        lapack_check_flag!(trans, ['N', 'T', 'C'])?;
+        lapack_check_flag!(diag, ['N', 'U'])?;
+
+        // 2. dim check
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = TPTRS_Driver {
+            uplo: ap.uplo as c_char,
+            trans: trans as c_char,
+            diag: diag as c_char,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            ap: ap.view(),
+            b,
+            ldb: ldb.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_tptrs(&mut driver)?;
+        }
+
+        // 5. finalize
+        let TPTRS_Driver { b, .. } = driver;
+        return Ok(b.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TPTRS<'a, 'b, F> = TPTRS_Builder<'a, 'b, F>;
+pub type STPTRS<'a, 'b> = TPTRS<'a, 'b, f32>;
+pub type DTPTRS<'a, 'b> = TPTRS<'a, 'b, f64>;
+pub type CTPTRS<'a, 'b> = TPTRS<'a, 'b, c32>;
+pub type ZTPTRS<'a, 'b> = TPTRS<'a, 'b, c64>;
+
+impl<'a, 'b, F> TPTRS_Builder<'a, 'b, F>
+where
+    F: TPTRSNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let b = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(b);
+        }
+    }
+}
+
+/* #endregion */
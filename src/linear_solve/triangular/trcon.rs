@@ -0,0 +1,178 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/* #region Lapack driver */
+
+pub struct TRCON_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    norm: c_char,
+    uplo: c_char,
+    diag: c_char,
+    n: lapack_int,
+    a: ArrayView2<'a, F>,
+    lda: lapack_int,
+    rcond: F::RealFloat,
+    work: Array1<F>,
+    rwork: Array1<F::RealFloat>,
+    iwork: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait TRCONNum: LapackFloat {
+    unsafe fn run_trcon(driver: &mut TRCON_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl TRCONNum for $type {
+            unsafe fn run_trcon(driver: &mut TRCON_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.norm,
+                    &driver.uplo,
+                    &driver.diag,
+                    &driver.n,
+                    driver.a.as_ptr(),
+                    &driver.lda,
+                    &mut driver.rcond,
+                    driver.work.as_mut_ptr(),
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl TRCONNum for $type {
+            unsafe fn run_trcon(driver: &mut TRCON_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.norm,
+                    &driver.uplo,
+                    &driver.diag,
+                    &driver.n,
+                    driver.a.as_ptr(),
+                    &driver.lda,
+                    &mut driver.rcond,
+                    driver.work.as_mut_ptr(),
+                    driver.rwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, strcon_);
+impl_func_real!(f64, dtrcon_);
+impl_func_comp!(c32, ctrcon_);
+impl_func_comp!(c64, ztrcon_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Reciprocal condition number of triangular matrix.
+///
+/// Only triangle `uplo` of `a` is referenced.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TRCON_<'a, F>
+where
+    F: TRCONNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub a: ArrayView2<'a, F>,
+
+    // option
+    #[builder(default = "'1'")]
+    pub norm: char,
+    #[builder(default = "'U'")]
+    pub uplo: char,
+    #[builder(default = "'N'")]
+    pub diag: char,
+}
+
+impl<'a, F> TRCON_<'a, F>
+where
+    F: TRCONNum,
+{
+    pub fn driver(self) -> Result<F::RealFloat, LapackError> {
+        let Self { a, norm, uplo, diag } = self;
+
+        // 1. dim assign, flag check
+        let a = a.to_col_layout()?;
+        let n = a.nrows();
+        let norm = norm.to_ascii_uppercase();
+        let uplo = uplo.to_ascii_uppercase();
+        let diag = diag.to_ascii_uppercase();
+        lapack_check_flag!(norm, ['1', 'O', 'I'])?;
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        lapack_check_flag!(diag, ['N', 'U'])?;
+
+        // 2. dim check & buffer alloc
+        lapack_assert_eq!(a.dim(), (n, n), InvalidDim)?;
+        let lda = a.stride_of(Axis(1)).max(n.max(1) as isize);
+        let work = match F::is_complex() {
+            true => Array1::zeros(2 * n),
+            false => Array1::zeros(3 * n),
+        };
+        let rwork = Array1::zeros(n);
+        let iwork = Array1::zeros(n);
+
+        // 3. struct build
+        let mut driver = TRCON_Driver {
+            norm: norm as c_char,
+            uplo: uplo as c_char,
+            diag: diag as c_char,
+            n: n.try_into()?,
+            a: a.view(),
+            lda: lda.try_into()?,
+            rcond: F::RealFloat::zero(),
+            work,
+            rwork,
+            iwork,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_trcon(&mut driver)?;
+        }
+
+        // 5. finalize
+        return Ok(driver.rcond);
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TRCON<'a, F> = TRCON_Builder<'a, F>;
+pub type STRCON<'a> = TRCON<'a, f32>;
+pub type DTRCON<'a> = TRCON<'a, f64>;
+pub type CTRCON<'a> = TRCON<'a, c32>;
+pub type ZTRCON<'a> = TRCON<'a, c64>;
+
+impl<'a, F> TRCON_Builder<'a, F>
+where
+    F: TRCONNum,
+{
+    pub fn run(self) -> Result<F::RealFloat, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,142 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TRTRI_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    diag: c_char,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    info: lapack_int,
+}
+
+pub trait TRTRINum: LapackFloat {
+    unsafe fn run_trtri(driver: &mut TRTRI_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TRTRINum for $type {
+            unsafe fn run_trtri(driver: &mut TRTRI_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.diag,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, strtri_);
+impl_func!(f64, dtrtri_);
+impl_func!(c32, ctrtri_);
+impl_func!(c64, ztrtri_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Inverse of triangular matrix.
+///
+/// Only triangle `uplo` of `a` is referenced and overwritten; the other triangle is left untouched.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TRTRI_<'a, F>
+where
+    F: TRTRINum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+    #[builder(default = "'N'")]
+    pub diag: char,
+}
+
+impl<'a, F> TRTRI_<'a, F>
+where
+    F: TRTRINum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let Self { a, uplo, diag } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let n = a.view().nrows();
+        let uplo = uplo.to_ascii_uppercase();
+        let diag = diag.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        lapack_check_flag!(diag, ['N', 'U'])?;
+
+        // 2. dim check
+        lapack_assert_eq!(a.view().dim(), (n, n), InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = TRTRI_Driver {
+            uplo: uplo as c_char,
+            diag: diag as c_char,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_trtri(&mut driver)?;
+        }
+
+        // 5. finalize
+        let TRTRI_Driver { a, .. } = driver;
+        return Ok(a.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TRTRI<'a, F> = TRTRI_Builder<'a, F>;
+pub type STRTRI<'a> = TRTRI<'a, f32>;
+pub type DTRTRI<'a> = TRTRI<'a, f64>;
+pub type CTRTRI<'a> = TRTRI<'a, c32>;
+pub type ZTRTRI<'a> = TRTRI<'a, c64>;
+
+impl<'a, F> TRTRI_Builder<'a, F>
+where
+    F: TRTRINum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let a = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(a);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,166 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TRTRS_Driver<'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    trans: c_char,
+    diag: c_char,
+    n: lapack_int,
+    nrhs: lapack_int,
+    a: ArrayView2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    info: lapack_int,
+}
+
+pub trait TRTRSNum: LapackFloat {
+    unsafe fn run_trtrs(driver: &mut TRTRS_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TRTRSNum for $type {
+            unsafe fn run_trtrs(driver: &mut TRTRS_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.trans,
+                    &driver.diag,
+                    &driver.n,
+                    &driver.nrhs,
+                    driver.a.as_ptr(),
+                    &driver.lda,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, strtrs_);
+impl_func!(f64, dtrtrs_);
+impl_func!(c32, ctrtrs_);
+impl_func!(c64, ztrtrs_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Solve triangular system `op(A) X = B`.
+///
+/// Only triangle `uplo` of `a` is referenced, so a full matrix holding a factor can be passed directly.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TRTRS_<'a, 'b, F>
+where
+    F: TRTRSNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub a: ArrayView2<'a, F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+    #[builder(default = "'N'")]
+    pub trans: char,
+    #[builder(default = "'N'")]
+    pub diag: char,
+}
+
+impl<'a, 'b, F> TRTRS_<'a, 'b, F>
+where
+    F: TRTRSNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let Self { a, b, uplo, trans, diag } = self;
+
+        // 1. dim assign, flag check
+        let a = a.to_col_layout()?;
+        let b = b.into_col_array_out();
+        let n = a.nrows();
+        let nrhs = b.view().ncols();
+        let uplo = uplo.to_ascii_uppercase();
+        let trans = trans.to_ascii_uppercase();
+        let diag = diag.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        lapack_check_flag!(trans, ['N', 'T', 'C'])?;
+        lapack_check_flag!(diag, ['N', 'U'])?;
+
+        // 2. dim check
+        lapack_assert_eq!(a.dim(), (n, n), InvalidDim)?;
+        lapack_assert_eq!(b.view().nrows(), n, InvalidDim)?;
+        let lda = a.stride_of(Axis(1)).max(n.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(n.max(1) as isize);
+
+        // 3. struct build
+        let mut driver = TRTRS_Driver {
+            uplo: uplo as c_char,
+            trans: trans as c_char,
+            diag: diag as c_char,
+            n: n.try_into()?,
+            nrhs: nrhs.try_into()?,
+            a: a.view(),
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_trtrs(&mut driver)?;
+        }
+
+        // 5. finalize
+        let TRTRS_Driver { b, .. } = driver;
+        return Ok(b.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TRTRS<'a, 'b, F> = TRTRS_Builder<'a, 'b, F>;
+pub type STRTRS<'a, 'b> = TRTRS<'a, 'b, f32>;
+pub type DTRTRS<'a, 'b> = TRTRS<'a, 'b, f64>;
+pub type CTRTRS<'a, 'b> = TRTRS<'a, 'b, c32>;
+pub type ZTRTRS<'a, 'b> = TRTRS<'a, 'b, c64>;
+
+impl<'a, 'b, F> TRTRS_Builder<'a, 'b, F>
+where
+    F: TRTRSNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let b = obj.driver()?;
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(b);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,190 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GEMQRT_Driver<'v, 't, 'c, F>
+where
+    F: LapackFloat,
+{
+    side: c_char,
+    trans: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    k: lapack_int,
+    nb: lapack_int,
+    v: ArrayView2<'v, F>,
+    ldv: lapack_int,
+    t: ArrayView2<'t, F>,
+    ldt: lapack_int,
+    c: ArrayOut2<'c, F>,
+    ldc: lapack_int,
+    work: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait GEMQRTNum: LapackFloat {
+    unsafe fn run_gemqrt(driver: &mut GEMQRT_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GEMQRTNum for $type {
+            unsafe fn run_gemqrt(driver: &mut GEMQRT_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.side,
+                    &driver.trans,
+                    &driver.m,
+                    &driver.n,
+                    &driver.k,
+                    &driver.nb,
+                    driver.v.as_ptr(),
+                    &driver.ldv,
+                    driver.t.as_ptr(),
+                    &driver.ldt,
+                    driver.c.get_data_mut_ptr(),
+                    &driver.ldc,
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgemqrt_);
+impl_func!(f64, dgemqrt_);
+impl_func!(c32, cgemqrt_);
+impl_func!(c64, zgemqrt_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Multiply general matrix `C` by `Q` from `GEQRT`: `op(Q) C` (`side = 'L'`) or `C op(Q)` (`side = 'R'`).
+///
+/// Argument `v` holds `k` reflectors in its first `k` columns, and `t` of shape `(nb, k)` holds the block reflector
+/// factors; `k` and `nb` are taken from shape of `t`. Argument `v` should have `m` rows for `side = 'L'`, or `n`
+/// rows for `side = 'R'`. For real matrices, `trans = 'C'` is regarded as `'T'`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GEMQRT_<'v, 't, 'c, F>
+where
+    F: GEMQRTNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub v: ArrayView2<'v, F>,
+    #[builder(setter(into))]
+    pub t: ArrayView2<'t, F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub c: ArrayViewOrMut2<'c, F>,
+
+    // option
+    #[builder(default = "'L'")]
+    pub side: char,
+    #[builder(default = "'N'")]
+    pub trans: char,
+}
+
+impl<'v, 't, 'c, F> GEMQRT_<'v, 't, 'c, F>
+where
+    F: GEMQRTNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let Self { v, t, c, side, trans } = self;
+
+        // 1. dim assign, flag check
+        let v = v.to_col_layout()?;
+        let t = t.to_col_layout()?;
+        let c = c.into_col_array_out();
+        let (m, n) = c.view().dim();
+        let (nb, k) = t.dim();
+        let side = side.to_ascii_uppercase();
+        let trans = trans.to_ascii_uppercase();
+        let trans = if F::is_complex() || trans != 'C' { trans } else { 'T' };
+        lapack_check_flag!(side, ['L', 'R'])?;
+        match F::is_complex() {
+            true => lapack_check_flag!(trans, ['N', 'C'])?,
+            false => lapack_check_flag!(trans, ['N', 'T'])?,
+        };
+
+        // 2. dim check & buffer alloc
+        let nq = if side == 'L' { m } else { n };
+        lapack_assert_eq!(v.nrows(), nq, InvalidDim)?;
+        lapack_assert_le!(k, v.ncols(), InvalidDim)?;
+        lapack_assert_le!(k, nq, InvalidDim)?;
+        lapack_assert_le!(nb, k.max(1), InvalidDim)?;
+        lapack_assert_le!(1, nb, InvalidDim)?;
+        let ldv = v.stride_of(Axis(1)).max(nq.max(1) as isize);
+        let ldt = t.stride_of(Axis(1)).max(nb as isize);
+        let ldc = c.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let nw = if side == 'L' { n } else { m };
+        let work = Array1::zeros((nw * nb).max(1));
+
+        // 3. struct build
+        let mut driver = GEMQRT_Driver {
+            side: side as c_char,
+            trans: trans as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            nb: nb.try_into()?,
+            v: v.view(),
+            ldv: ldv.try_into()?,
+            t: t.view(),
+            ldt: ldt.try_into()?,
+            c,
+            ldc: ldc.try_into()?,
+            work,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_gemqrt(&mut driver)?;
+        }
+
+        // 5. finalize
+        let GEMQRT_Driver { c, .. } = driver;
+        return Ok(c.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GEMQRT<'v, 't, 'c, F> = GEMQRT_Builder<'v, 't, 'c, F>;
+pub type SGEMQRT<'v, 't, 'c> = GEMQRT<'v, 't, 'c, f32>;
+pub type DGEMQRT<'v, 't, 'c> = GEMQRT<'v, 't, 'c, f64>;
+pub type CGEMQRT<'v, 't, 'c> = GEMQRT<'v, 't, 'c, c32>;
+pub type ZGEMQRT<'v, 't, 'c> = GEMQRT<'v, 't, 'c, c64>;
+
+impl<'v, 't, 'c, F> GEMQRT_Builder<'v, 't, 'c, F>
+where
+    F: GEMQRTNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.c.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let c = obj.driver()?;
+            let c = match c {
+                ArrayOut::Owned(c) => ArrayOut::Owned(c.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(c) => ArrayOut::ViewMut(c),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(c);
+        }
+    }
+}
+
+/* #endregion */
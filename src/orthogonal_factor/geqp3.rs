@@ -0,0 +1,189 @@
+use crate::ffi::{self, lapack_int};
+use crate::orthogonal_factor::{PivotedQrFactorization, QrFactorization};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GEQP3_Driver<'a, 'work, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    jpvt: Array1<lapack_int>,
+    tau: Array1<F>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    rwork: Array1<F::RealFloat>,
+    info: lapack_int,
+}
+
+pub trait GEQP3Num: LapackFloat {
+    unsafe fn run_geqp3(driver: &mut GEQP3_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl GEQP3Num for $type {
+            unsafe fn run_geqp3(driver: &mut GEQP3_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.jpvt.as_mut_ptr(),
+                    driver.tau.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl GEQP3Num for $type {
+            unsafe fn run_geqp3(driver: &mut GEQP3_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.jpvt.as_mut_ptr(),
+                    driver.tau.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    driver.rwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sgeqp3_);
+impl_func_real!(f64, dgeqp3_);
+impl_func_comp!(c32, cgeqp3_);
+impl_func_comp!(c64, zgeqp3_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// QR factorization with column pivoting `A P = Q R` of general matrix.
+///
+/// If `jpvt` is given, columns `j` with `jpvt[j] != 0` are pinned: they are permuted to the front of `A P`
+/// before the factorization, and are not pivoted among the free columns. Output permutation is 0-based.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GEQP3_<'a, 'p, 'work, F>
+where
+    F: GEQP3Num,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // input
+    #[builder(setter(into, strip_option), default = "None")]
+    pub jpvt: Option<ArrayView1<'p, lapack_int>>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'p, 'work, F> GEQP3_<'a, 'p, 'work, F>
+where
+    F: GEQP3Num,
+{
+    pub fn driver(self) -> Result<PivotedQrFactorization<F>, LapackError> {
+        let Self { a, jpvt, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 2. dim check & alloc
+        let jpvt = match jpvt {
+            Some(jpvt) => {
+                lapack_assert_eq!(jpvt.len(), n, InvalidDim)?;
+                jpvt.mapv(|p| (p != 0) as lapack_int)
+            },
+            None => Array1::zeros(n),
+        };
+        let tau = Array1::zeros(m.min(n));
+        let rwork = Array1::zeros(if F::is_complex() { 2 * n } else { 1 }.max(1));
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, (3 * n + 1).max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GEQP3_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            jpvt,
+            tau,
+            work,
+            lwork: lwork.try_into()?,
+            rwork,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_geqp3(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_geqp3(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GEQP3_Driver { a, jpvt, tau, .. } = driver;
+        let a = a.clone_to_view_mut().into_owned();
+        let jpvt = jpvt.mapv(|p| (p - 1) as usize);
+        return Ok(PivotedQrFactorization { qr: QrFactorization { a, tau }, jpvt });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GEQP3<'a, 'p, 'work, F> = GEQP3_Builder<'a, 'p, 'work, F>;
+pub type SGEQP3<'a, 'p, 'work> = GEQP3<'a, 'p, 'work, f32>;
+pub type DGEQP3<'a, 'p, 'work> = GEQP3<'a, 'p, 'work, f64>;
+pub type CGEQP3<'a, 'p, 'work> = GEQP3<'a, 'p, 'work, c32>;
+pub type ZGEQP3<'a, 'p, 'work> = GEQP3<'a, 'p, 'work, c64>;
+
+impl<'a, 'p, 'work, F> GEQP3_Builder<'a, 'p, 'work, F>
+where
+    F: GEQP3Num,
+{
+    pub fn run(self) -> Result<PivotedQrFactorization<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,153 @@
+use crate::ffi::{self, lapack_int};
+use crate::orthogonal_factor::TsqrFactorization;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GEQR_Driver<'a, 'work, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    t: Array1<F>,
+    tsize: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GEQRNum: LapackFloat {
+    unsafe fn run_geqr(driver: &mut GEQR_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GEQRNum for $type {
+            unsafe fn run_geqr(driver: &mut GEQR_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.t.as_mut_ptr(),
+                    &driver.tsize,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgeqr_);
+impl_func!(f64, dgeqr_);
+impl_func!(c32, cgeqr_);
+impl_func!(c64, zgeqr_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// QR factorization `A = Q R`, by communication-avoiding tall-skinny QR (TSQR) if `A` is tall and skinny, or by
+/// blocked QR otherwise.
+///
+/// The block sizes and the size of opaque array `t` are chosen by LAPACK by workspace query; `t` is allocated and
+/// kept in the returned [`TsqrFactorization`], which should be used to apply `Q` (by `GEMQR`).
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GEQR_<'a, 'work, F>
+where
+    F: GEQRNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'work, F> GEQR_<'a, 'work, F>
+where
+    F: GEQRNum,
+{
+    pub fn driver(self) -> Result<TsqrFactorization<F>, LapackError> {
+        let Self { a, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 2. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, 1, true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+        let t = Array1::zeros(5);
+
+        // 3. struct build
+        let mut driver = GEQR_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            t,
+            tsize: -1,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 4. buffer query (opaque `t` is always queried)
+        unsafe {
+            F::run_geqr(&mut driver)?;
+        }
+        let tsize = F::ftoi(driver.t[0]).max(5);
+        driver.tsize = tsize.try_into()?;
+        driver.t = Array1::zeros(tsize as usize);
+        if query_work {
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 5. perform comput
+        unsafe {
+            F::run_geqr(&mut driver)?;
+        }
+
+        // 6. finalize
+        let GEQR_Driver { a, t, .. } = driver;
+        let a = a.clone_to_view_mut().into_owned();
+        return Ok(TsqrFactorization { a, t });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GEQR<'a, 'work, F> = GEQR_Builder<'a, 'work, F>;
+pub type SGEQR<'a, 'work> = GEQR<'a, 'work, f32>;
+pub type DGEQR<'a, 'work> = GEQR<'a, 'work, f64>;
+pub type CGEQR<'a, 'work> = GEQR<'a, 'work, c32>;
+pub type ZGEQR<'a, 'work> = GEQR<'a, 'work, c64>;
+
+impl<'a, 'work, F> GEQR_Builder<'a, 'work, F>
+where
+    F: GEQRNum,
+{
+    pub fn run(self) -> Result<TsqrFactorization<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
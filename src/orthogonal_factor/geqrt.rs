@@ -0,0 +1,151 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GEQRT_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    nb: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    t: Array2<F>,
+    ldt: lapack_int,
+    work: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait GEQRTNum: LapackFloat {
+    unsafe fn run_geqrt(driver: &mut GEQRT_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GEQRTNum for $type {
+            unsafe fn run_geqrt(driver: &mut GEQRT_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    &driver.nb,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.t.as_mut_ptr(),
+                    &driver.ldt,
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgeqrt_);
+impl_func!(f64, dgeqrt_);
+impl_func!(c32, cgeqrt_);
+impl_func!(c64, zgeqrt_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Blocked QR factorization `A = Q R` in compact WY representation, with block size `nb`.
+///
+/// On exit, `a` holds `R` on and above diagonal, and reflectors `V` below diagonal. Output `t` of shape
+/// `(nb, min(m, n))` holds the upper triangular block reflector factors, stored block by block; both `V` and `t`
+/// are to be passed to `GEMQRT`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GEQRT_<'a, F>
+where
+    F: GEQRTNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    /// Block size, `1 <= nb <= min(m, n)`.
+    pub nb: usize,
+}
+
+impl<'a, F> GEQRT_<'a, F>
+where
+    F: GEQRTNum,
+{
+    pub fn driver(self) -> Result<(ArrayOut2<'a, F>, Array2<F>), LapackError> {
+        let Self { a, nb } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let k = m.min(n);
+
+        // 2. dim check & alloc
+        lapack_assert_le!(nb, k.max(1), InvalidDim)?;
+        lapack_assert_le!(1, nb, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let t = Array2::zeros((nb, k).f());
+        let work = Array1::zeros((nb * n).max(1));
+
+        // 3. struct build
+        let mut driver = GEQRT_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            nb: nb.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            t,
+            ldt: nb.try_into()?,
+            work,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_geqrt(&mut driver)?;
+        }
+
+        // 5. finalize
+        let GEQRT_Driver { a, t, .. } = driver;
+        return Ok((a.clone_to_view_mut(), t));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GEQRT<'a, F> = GEQRT_Builder<'a, F>;
+pub type SGEQRT<'a> = GEQRT<'a, f32>;
+pub type DGEQRT<'a> = GEQRT<'a, f64>;
+pub type CGEQRT<'a> = GEQRT<'a, c32>;
+pub type ZGEQRT<'a> = GEQRT<'a, c64>;
+
+impl<'a, F> GEQRT_Builder<'a, F>
+where
+    F: GEQRTNum,
+{
+    pub fn run(self) -> Result<(ArrayOut2<'a, F>, Array2<F>), LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, t) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, t));
+        }
+    }
+}
+
+/* #endregion */
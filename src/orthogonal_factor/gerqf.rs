@@ -0,0 +1,148 @@
+use crate::ffi::{self, lapack_int};
+use crate::orthogonal_factor::RqFactorization;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GERQF_Driver<'a, 'work, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    tau: Array1<F>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GERQFNum: LapackFloat {
+    unsafe fn run_gerqf(driver: &mut GERQF_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GERQFNum for $type {
+            unsafe fn run_gerqf(driver: &mut GERQF_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.tau.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgerqf_);
+impl_func!(f64, dgerqf_);
+impl_func!(c32, cgerqf_);
+impl_func!(c64, zgerqf_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// RQ factorization `A = R Q` of general matrix.
+///
+/// If `a` is given as mutable view, it is also overwritten in place by the factors (`R` in the last `min(m, n)`
+/// rows or columns, reflectors in the rest), in the same way as LAPACK.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GERQF_<'a, 'work, F>
+where
+    F: GERQFNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'work, F> GERQF_<'a, 'work, F>
+where
+    F: GERQFNum,
+{
+    pub fn driver(self) -> Result<RqFactorization<F>, LapackError> {
+        let Self { a, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 2. alloc
+        let tau = Array1::zeros(m.min(n));
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, m.max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GERQF_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            tau,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_gerqf(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_gerqf(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GERQF_Driver { a, tau, .. } = driver;
+        let a = a.clone_to_view_mut().into_owned();
+        return Ok(RqFactorization { a, tau });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GERQF<'a, 'work, F> = GERQF_Builder<'a, 'work, F>;
+pub type SGERQF<'a, 'work> = GERQF<'a, 'work, f32>;
+pub type DGERQF<'a, 'work> = GERQF<'a, 'work, f64>;
+pub type CGERQF<'a, 'work> = GERQF<'a, 'work, c32>;
+pub type ZGERQF<'a, 'work> = GERQF<'a, 'work, c64>;
+
+impl<'a, 'work, F> GERQF_Builder<'a, 'work, F>
+where
+    F: GERQFNum,
+{
+    pub fn run(self) -> Result<RqFactorization<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,184 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GETSQRHRT_Driver<'a, 'work, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    mb1: lapack_int,
+    nb1: lapack_int,
+    nb2: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    t: Array2<F>,
+    ldt: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GETSQRHRTNum: LapackFloat {
+    unsafe fn run_getsqrhrt(driver: &mut GETSQRHRT_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GETSQRHRTNum for $type {
+            unsafe fn run_getsqrhrt(driver: &mut GETSQRHRT_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    &driver.mb1,
+                    &driver.nb1,
+                    &driver.nb2,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.t.as_mut_ptr(),
+                    &driver.ldt,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgetsqrhrt_);
+impl_func!(f64, dgetsqrhrt_);
+impl_func!(c32, cgetsqrhrt_);
+impl_func!(c64, zgetsqrhrt_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Tall-skinny QR factorization `A = Q R` (`m >= n`), with `Q` reconstructed into standard Householder form.
+///
+/// TSQR is performed with row block size `mb1 > n` and column block size `nb1`; its orthonormal factor is then
+/// converted by `ORHR_COL` to reflectors with column block size `nb2`. On exit, `a` holds `R` on and above
+/// diagonal, and reflectors `V` below diagonal; output `t` of shape `(min(nb2, n), n)` holds the block reflector
+/// factors. Both are in the same format as `GEQRT` output, and can be passed to `GEMQRT`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GETSQRHRT_<'a, 'work, F>
+where
+    F: GETSQRHRTNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    /// Row block size of TSQR, `mb1 > n`.
+    pub mb1: usize,
+    /// Column block size of TSQR, `nb1 >= 1`.
+    pub nb1: usize,
+    /// Column block size of output reflectors, `nb2 >= 1`.
+    pub nb2: usize,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'work, F> GETSQRHRT_<'a, 'work, F>
+where
+    F: GETSQRHRTNum,
+{
+    pub fn driver(self) -> Result<(ArrayOut2<'a, F>, Array2<F>), LapackError> {
+        let Self { a, mb1, nb1, nb2, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+
+        // 2. dim check & alloc
+        lapack_assert_le!(n, m, InvalidDim)?;
+        lapack_assert!(mb1 > n, InvalidDim)?;
+        lapack_assert_le!(1, nb1, InvalidDim)?;
+        lapack_assert_le!(1, nb2, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let ldt = nb2.min(n).max(1);
+        let t = Array2::zeros((ldt, n).f());
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, 2, true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GETSQRHRT_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            mb1: mb1.try_into()?,
+            nb1: nb1.try_into()?,
+            nb2: nb2.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            t,
+            ldt: ldt.try_into()?,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_getsqrhrt(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(2);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_getsqrhrt(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GETSQRHRT_Driver { a, t, .. } = driver;
+        return Ok((a.clone_to_view_mut(), t));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GETSQRHRT<'a, 'work, F> = GETSQRHRT_Builder<'a, 'work, F>;
+pub type SGETSQRHRT<'a, 'work> = GETSQRHRT<'a, 'work, f32>;
+pub type DGETSQRHRT<'a, 'work> = GETSQRHRT<'a, 'work, f64>;
+pub type CGETSQRHRT<'a, 'work> = GETSQRHRT<'a, 'work, c32>;
+pub type ZGETSQRHRT<'a, 'work> = GETSQRHRT<'a, 'work, c64>;
+
+impl<'a, 'work, F> GETSQRHRT_Builder<'a, 'work, F>
+where
+    F: GETSQRHRTNum,
+{
+    pub fn run(self) -> Result<(ArrayOut2<'a, F>, Array2<F>), LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, t) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, t));
+        }
+    }
+}
+
+/* #endregion */
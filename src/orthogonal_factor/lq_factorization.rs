@@ -0,0 +1,71 @@
+use crate::orthogonal_factor::{ORGLQNum, ORMLQNum, ORGLQ, ORMLQ};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/// LQ factorization `A = L Q` of `(m, n)` matrix, as computed by `GELQF`.
+///
+/// Array `a` holds `L` on and below diagonal, and `k = min(m, n)` elementary reflectors (which define `Q` together
+/// with `tau`) above diagonal, stored row-wise.
+#[derive(Debug, Clone)]
+pub struct LqFactorization<F> {
+    pub a: Array2<F>,
+    pub tau: Array1<F>,
+}
+
+impl<F> LqFactorization<F>
+where
+    F: LapackFloat,
+{
+    /// Lower trapezoidal factor `L` of shape `(m, k)`, with upper triangle zeroed.
+    pub fn l(&self) -> Array2<F> {
+        let k = self.tau.len();
+        let mut l = self.a.slice(s![.., ..k]).to_owned();
+        l.indexed_iter_mut().filter(|((i, j), _)| i < j).for_each(|(_, x)| *x = F::zero());
+        return l;
+    }
+}
+
+impl<F> LqFactorization<F>
+where
+    F: ORGLQNum + ORMLQNum,
+{
+    /// Economy-size `Q` of shape `(k, n)`, with orthonormal rows.
+    pub fn q(&self) -> Result<Array2<F>, LapackError> {
+        self.q_economy()
+    }
+
+    /// Economy-size `Q` of shape `(k, n)`, with orthonormal rows.
+    pub fn q_economy(&self) -> Result<Array2<F>, LapackError> {
+        let k = self.tau.len();
+        let mut q = Array2::zeros((k, self.a.ncols()).f());
+        q.assign(&self.a.slice(s![..k, ..]));
+        ORGLQ::default().a(q.view_mut()).tau(self.tau.view()).run()?;
+        return Ok(q);
+    }
+
+    /// Complete unitary (orthogonal) `Q` of shape `(n, n)`.
+    pub fn q_complete(&self) -> Result<Array2<F>, LapackError> {
+        let n = self.a.ncols();
+        let k = self.tau.len();
+        let mut q = Array2::zeros((n, n).f());
+        q.slice_mut(s![..k, ..]).assign(&self.a.slice(s![..k, ..]));
+        ORGLQ::default().a(q.view_mut()).tau(self.tau.view()).run()?;
+        return Ok(q);
+    }
+
+    /// Multiply `c` by `Q` without forming it: `op(Q) C` (`side = 'L'`) or `C op(Q)` (`side = 'R'`), with `trans`
+    /// in `'N'`, `'T'` (real) or `'C'`.
+    ///
+    /// Here `Q` is the complete `(n, n)` matrix; so `c` should have `n` rows for `side = 'L'`, or `n` columns for
+    /// `side = 'R'`.
+    pub fn apply_q<'c>(
+        &self,
+        side: char,
+        trans: char,
+        c: impl Into<ArrayViewOrMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let k = self.tau.len();
+        let a = self.a.slice(s![..k, ..]);
+        ORMLQ::default().a(a).tau(self.tau.view()).c(c).side(side).trans(trans).run()
+    }
+}
@@ -0,0 +1,63 @@
+pub mod gelqf;
+pub mod gemqr;
+pub mod gemqrt;
+pub mod geqlf;
+pub mod geqp3;
+pub mod geqr;
+pub mod geqrf;
+pub mod geqrt;
+pub mod gerqf;
+pub mod getsqrhrt;
+pub mod lq_factorization;
+pub mod orglq;
+pub mod orgql;
+pub mod orgqr;
+pub mod orgrq;
+pub mod orhr_col;
+pub mod ormlq;
+pub mod ormql;
+pub mod ormqr;
+pub mod ormrq;
+pub mod ormrz;
+pub mod ql_factorization;
+pub mod qr_factorization;
+pub mod rq_factorization;
+pub mod rz_factorization;
+pub mod tplqt;
+pub mod tpmlqt;
+pub mod tpmqrt;
+pub mod tpqrt;
+pub mod tsqr_factorization;
+pub mod tzrzf;
+
+pub use gelqf::*;
+pub use gemqr::*;
+pub use gemqrt::*;
+pub use geqlf::*;
+pub use geqp3::*;
+pub use geqr::*;
+pub use geqrf::*;
+pub use geqrt::*;
+pub use gerqf::*;
+pub use getsqrhrt::*;
+pub use lq_factorization::*;
+pub use orglq::*;
+pub use orgql::*;
+pub use orgqr::*;
+pub use orgrq::*;
+pub use orhr_col::*;
+pub use ormlq::*;
+pub use ormql::*;
+pub use ormqr::*;
+pub use ormrq::*;
+pub use ormrz::*;
+pub use ql_factorization::*;
+pub use qr_factorization::*;
+pub use rq_factorization::*;
+pub use rz_factorization::*;
+pub use tplqt::*;
+pub use tpmlqt::*;
+pub use tpmqrt::*;
+pub use tpqrt::*;
+pub use tsqr_factorization::*;
+pub use tzrzf::*;
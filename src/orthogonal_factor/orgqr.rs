@@ -0,0 +1,168 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct ORGQR_Driver<'a, 'tau, 'work, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    k: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    tau: ArrayView1<'tau, F>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait ORGQRNum: LapackFloat {
+    unsafe fn run_orgqr(driver: &mut ORGQR_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl ORGQRNum for $type {
+            unsafe fn run_orgqr(driver: &mut ORGQR_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    &driver.k,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.tau.as_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sorgqr_);
+impl_func!(f64, dorgqr_);
+impl_func!(c32, cungqr_);
+impl_func!(c64, zungqr_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Generate `(m, n)` matrix `Q` with orthonormal columns, defined as the first `n` columns of product of `k`
+/// elementary reflectors from `GEQRF`.
+///
+/// On entry, first `k` columns of `a` hold the reflectors; on exit, `a` is overwritten by `Q`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct ORGQR_<'a, 'tau, 'work, F>
+where
+    F: ORGQRNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // input
+    #[builder(setter(into))]
+    pub tau: ArrayView1<'tau, F>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'tau, 'work, F> ORGQR_<'a, 'tau, 'work, F>
+where
+    F: ORGQRNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let Self { a, tau, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let tau = tau.to_seq_layout()?;
+        let (m, n) = a.view().dim();
+        let k = tau.len();
+
+        // 2. dim check
+        lapack_assert_le!(n, m, InvalidDim)?;
+        lapack_assert_le!(k, n, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, n.max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = ORGQR_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            tau: tau.view(),
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_orgqr(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_orgqr(&mut driver)?;
+        }
+
+        // 7. finalize
+        let ORGQR_Driver { a, .. } = driver;
+        return Ok(a.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type ORGQR<'a, 'tau, 'work, F> = ORGQR_Builder<'a, 'tau, 'work, F>;
+pub type UNGQR<'a, 'tau, 'work, F> = ORGQR_Builder<'a, 'tau, 'work, F>;
+pub type SORGQR<'a, 'tau, 'work> = ORGQR<'a, 'tau, 'work, f32>;
+pub type DORGQR<'a, 'tau, 'work> = ORGQR<'a, 'tau, 'work, f64>;
+pub type CUNGQR<'a, 'tau, 'work> = UNGQR<'a, 'tau, 'work, c32>;
+pub type ZUNGQR<'a, 'tau, 'work> = UNGQR<'a, 'tau, 'work, c64>;
+
+impl<'a, 'tau, 'work, F> ORGQR_Builder<'a, 'tau, 'work, F>
+where
+    F: ORGQRNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let a = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(a);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,157 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct ORHR_COL_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    nb: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    t: Array2<F>,
+    ldt: lapack_int,
+    d: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait ORHR_COLNum: LapackFloat {
+    unsafe fn run_orhr_col(driver: &mut ORHR_COL_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl ORHR_COLNum for $type {
+            unsafe fn run_orhr_col(driver: &mut ORHR_COL_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    &driver.nb,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.t.as_mut_ptr(),
+                    &driver.ldt,
+                    driver.d.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sorhr_col_);
+impl_func!(f64, dorhr_col_);
+impl_func!(c32, cunhr_col_);
+impl_func!(c64, zunhr_col_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`ORHR_COL`]: reflectors `v` (in `a`), block reflector factor `t` and signs `d`.
+pub type ORHR_COL_Output<'a, F> = (ArrayOut2<'a, F>, Array2<F>, Array1<F>);
+
+/// Householder reconstruction `Q_in = (I - V T Vᴴ) S` of `(m, n)` matrix `Q_in` (`m >= n`) with orthonormal columns,
+/// e.g. the orthonormal factor of TSQR.
+///
+/// On exit, `a` holds reflectors `V` below diagonal (and the upper triangle of `Q_in - S` on and above
+/// diagonal); output `t` of shape `(min(nb, n), n)` holds the block reflector factors with column block size `nb`,
+/// in the same format as `GEQRT` output. Output `d` holds the diagonal of sign matrix `S` (entries `±1`); if
+/// `A = Q_in R`, then `A = (I - V T Vᴴ) (S R)`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct ORHR_COL_<'a, F>
+where
+    F: ORHR_COLNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    /// Column block size, `nb >= 1`.
+    pub nb: usize,
+}
+
+impl<'a, F> ORHR_COL_<'a, F>
+where
+    F: ORHR_COLNum,
+{
+    pub fn driver(self) -> Result<ORHR_COL_Output<'a, F>, LapackError> {
+        let Self { a, nb } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+
+        // 2. dim check & alloc
+        lapack_assert_le!(n, m, InvalidDim)?;
+        lapack_assert_le!(1, nb, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let ldt = nb.min(n).max(1);
+        let t = Array2::zeros((ldt, n).f());
+        let d = Array1::zeros(n);
+
+        // 3. struct build
+        let mut driver = ORHR_COL_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            nb: nb.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            t,
+            ldt: ldt.try_into()?,
+            d,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_orhr_col(&mut driver)?;
+        }
+
+        // 5. finalize
+        let ORHR_COL_Driver { a, t, d, .. } = driver;
+        return Ok((a.clone_to_view_mut(), t, d));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type ORHR_COL<'a, F> = ORHR_COL_Builder<'a, F>;
+pub type UNHR_COL<'a, F> = ORHR_COL_Builder<'a, F>;
+pub type SORHR_COL<'a> = ORHR_COL<'a, f32>;
+pub type DORHR_COL<'a> = ORHR_COL<'a, f64>;
+pub type CUNHR_COL<'a> = UNHR_COL<'a, c32>;
+pub type ZUNHR_COL<'a> = UNHR_COL<'a, c64>;
+
+impl<'a, F> ORHR_COL_Builder<'a, F>
+where
+    F: ORHR_COLNum,
+{
+    pub fn run(self) -> Result<ORHR_COL_Output<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, t, d) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, t, d));
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,210 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct ORMRZ_Driver<'a, 'tau, 'c, 'work, F>
+where
+    F: LapackFloat,
+{
+    side: c_char,
+    trans: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    k: lapack_int,
+    l: lapack_int,
+    a: ArrayView2<'a, F>,
+    lda: lapack_int,
+    tau: ArrayView1<'tau, F>,
+    c: ArrayOut2<'c, F>,
+    ldc: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait ORMRZNum: LapackFloat {
+    unsafe fn run_ormrz(driver: &mut ORMRZ_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl ORMRZNum for $type {
+            unsafe fn run_ormrz(driver: &mut ORMRZ_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.side,
+                    &driver.trans,
+                    &driver.m,
+                    &driver.n,
+                    &driver.k,
+                    &driver.l,
+                    driver.a.as_ptr(),
+                    &driver.lda,
+                    driver.tau.as_ptr(),
+                    driver.c.get_data_mut_ptr(),
+                    &driver.ldc,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sormrz_);
+impl_func!(f64, dormrz_);
+impl_func!(c32, cunmrz_);
+impl_func!(c64, zunmrz_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Multiply general matrix `C` by `Z` from `TZRZF`: `op(Z) C` (`side = 'L'`) or `C op(Z)` (`side = 'R'`).
+///
+/// Argument `a` holds `k` elementary reflectors in the last `l` columns of its first `k` rows, where `k` is length
+/// of `tau`; it should have `m` columns for `side = 'L'`, or `n` columns for `side = 'R'`. Option `l` defaults to
+/// the number of columns of `a` minus `k`, which is the layout of `TZRZF` output. For real matrices, `trans = 'C'`
+/// is regarded as `'T'`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct ORMRZ_<'a, 'tau, 'c, 'work, F>
+where
+    F: ORMRZNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub a: ArrayView2<'a, F>,
+    #[builder(setter(into))]
+    pub tau: ArrayView1<'tau, F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub c: ArrayViewOrMut2<'c, F>,
+
+    // option
+    #[builder(default = "'L'")]
+    pub side: char,
+    #[builder(default = "'N'")]
+    pub trans: char,
+    #[builder(setter(strip_option), default = "None")]
+    pub l: Option<usize>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'tau, 'c, 'work, F> ORMRZ_<'a, 'tau, 'c, 'work, F>
+where
+    F: ORMRZNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let Self { a, tau, c, side, trans, l, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.to_col_layout()?;
+        let tau = tau.to_seq_layout()?;
+        let c = c.into_col_array_out();
+        let (m, n) = c.view().dim();
+        let k = tau.len();
+        let side = side.to_ascii_uppercase();
+        let trans = trans.to_ascii_uppercase();
+        let trans = if F::is_complex() || trans != 'C' { trans } else { 'T' };
+        lapack_check_flag!(side, ['L', 'R'])?;
+        match F::is_complex() {
+            true => lapack_check_flag!(trans, ['N', 'C'])?,
+            false => lapack_check_flag!(trans, ['N', 'T'])?,
+        };
+
+        // 2. dim check
+        let nq = if side == 'L' { m } else { n };
+        lapack_assert_eq!(a.ncols(), nq, InvalidDim)?;
+        lapack_assert_le!(k, a.nrows(), InvalidDim)?;
+        lapack_assert_le!(k, nq, InvalidDim)?;
+        let l = l.unwrap_or(nq - k);
+        lapack_assert_le!(l, nq, InvalidDim)?;
+        let lda = a.stride_of(Axis(1)).max(k.max(1) as isize);
+        let ldc = c.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 3. buffer check & alloc
+        let nw = if side == 'L' { n } else { m };
+        let (work, query_work) = ArrayOut1::optional_buffer(work, nw.max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = ORMRZ_Driver {
+            side: side as c_char,
+            trans: trans as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            l: l.try_into()?,
+            a: a.view(),
+            lda: lda.try_into()?,
+            tau: tau.view(),
+            c,
+            ldc: ldc.try_into()?,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_ormrz(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_ormrz(&mut driver)?;
+        }
+
+        // 7. finalize
+        let ORMRZ_Driver { c, .. } = driver;
+        return Ok(c.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type ORMRZ<'a, 'tau, 'c, 'work, F> = ORMRZ_Builder<'a, 'tau, 'c, 'work, F>;
+pub type UNMRZ<'a, 'tau, 'c, 'work, F> = ORMRZ_Builder<'a, 'tau, 'c, 'work, F>;
+pub type SORMRZ<'a, 'tau, 'c, 'work> = ORMRZ<'a, 'tau, 'c, 'work, f32>;
+pub type DORMRZ<'a, 'tau, 'c, 'work> = ORMRZ<'a, 'tau, 'c, 'work, f64>;
+pub type CUNMRZ<'a, 'tau, 'c, 'work> = UNMRZ<'a, 'tau, 'c, 'work, c32>;
+pub type ZUNMRZ<'a, 'tau, 'c, 'work> = UNMRZ<'a, 'tau, 'c, 'work, c64>;
+
+impl<'a, 'tau, 'c, 'work, F> ORMRZ_Builder<'a, 'tau, 'c, 'work, F>
+where
+    F: ORMRZNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.c.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let c = obj.driver()?;
+            let c = match c {
+                ArrayOut::Owned(c) => ArrayOut::Owned(c.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(c) => ArrayOut::ViewMut(c),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(c);
+        }
+    }
+}
+
+/* #endregion */
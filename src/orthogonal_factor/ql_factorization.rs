@@ -0,0 +1,75 @@
+use crate::orthogonal_factor::{ORGQLNum, ORMQLNum, ORGQL, ORMQL};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/// QL factorization `A = Q L` of `(m, n)` matrix, as computed by `GEQLF`.
+///
+/// With `k = min(m, n)`, `L` is stored in the last `k` rows of `a` (on and below its `(n - k)`-th superdiagonal),
+/// and `k` elementary reflectors (which define `Q` together with `tau`) are stored in the last `k` columns of `a`,
+/// above `L`.
+#[derive(Debug, Clone)]
+pub struct QlFactorization<F> {
+    pub a: Array2<F>,
+    pub tau: Array1<F>,
+}
+
+impl<F> QlFactorization<F>
+where
+    F: LapackFloat,
+{
+    /// Lower trapezoidal factor `L` of shape `(k, n)`, with elements above its `(n - k)`-th superdiagonal zeroed.
+    pub fn l(&self) -> Array2<F> {
+        let (m, n) = self.a.dim();
+        let k = self.tau.len();
+        let mut l = self.a.slice(s![m - k.., ..]).to_owned();
+        l.indexed_iter_mut().filter(|((i, j), _)| *j > i + (n - k)).for_each(|(_, x)| *x = F::zero());
+        return l;
+    }
+}
+
+impl<F> QlFactorization<F>
+where
+    F: ORGQLNum + ORMQLNum,
+{
+    /// Economy-size `Q` of shape `(m, k)`, with orthonormal columns.
+    pub fn q(&self) -> Result<Array2<F>, LapackError> {
+        self.q_economy()
+    }
+
+    /// Economy-size `Q` of shape `(m, k)`, with orthonormal columns.
+    pub fn q_economy(&self) -> Result<Array2<F>, LapackError> {
+        let n = self.a.ncols();
+        let k = self.tau.len();
+        let mut q = Array2::zeros((self.a.nrows(), k).f());
+        q.assign(&self.a.slice(s![.., n - k..]));
+        ORGQL::default().a(q.view_mut()).tau(self.tau.view()).run()?;
+        return Ok(q);
+    }
+
+    /// Complete unitary (orthogonal) `Q` of shape `(m, m)`.
+    pub fn q_complete(&self) -> Result<Array2<F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let k = self.tau.len();
+        let mut q = Array2::zeros((m, m).f());
+        q.slice_mut(s![.., m - k..]).assign(&self.a.slice(s![.., n - k..]));
+        ORGQL::default().a(q.view_mut()).tau(self.tau.view()).run()?;
+        return Ok(q);
+    }
+
+    /// Multiply `c` by `Q` without forming it: `op(Q) C` (`side = 'L'`) or `C op(Q)` (`side = 'R'`), with `trans`
+    /// in `'N'`, `'T'` (real) or `'C'`.
+    ///
+    /// Here `Q` is the complete `(m, m)` matrix; so `c` should have `m` rows for `side = 'L'`, or `m` columns for
+    /// `side = 'R'`.
+    pub fn apply_q<'c>(
+        &self,
+        side: char,
+        trans: char,
+        c: impl Into<ArrayViewOrMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let n = self.a.ncols();
+        let k = self.tau.len();
+        let a = self.a.slice(s![.., n - k..]);
+        ORMQL::default().a(a).tau(self.tau.view()).c(c).side(side).trans(trans).run()
+    }
+}
@@ -0,0 +1,100 @@
+use crate::orthogonal_factor::{ORGQRNum, ORMQRNum, ORGQR, ORMQR};
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::{NumCast, Zero};
+
+/// QR factorization `A = Q R` of `(m, n)` matrix, as computed by `GEQRF`.
+///
+/// Array `a` holds `R` on and above diagonal, and `k = min(m, n)` elementary reflectors (which define `Q` together
+/// with `tau`) below diagonal.
+#[derive(Debug, Clone)]
+pub struct QrFactorization<F> {
+    pub a: Array2<F>,
+    pub tau: Array1<F>,
+}
+
+impl<F> QrFactorization<F>
+where
+    F: LapackFloat,
+{
+    /// Upper trapezoidal factor `R` of shape `(k, n)`, with lower triangle zeroed.
+    pub fn r(&self) -> Array2<F> {
+        let k = self.tau.len();
+        let mut r = self.a.slice(s![..k, ..]).to_owned();
+        r.indexed_iter_mut().filter(|((i, j), _)| i > j).for_each(|(_, x)| *x = F::zero());
+        return r;
+    }
+}
+
+impl<F> QrFactorization<F>
+where
+    F: ORGQRNum + ORMQRNum,
+{
+    /// Economy-size `Q` of shape `(m, k)`, with orthonormal columns.
+    pub fn q(&self) -> Result<Array2<F>, LapackError> {
+        self.q_economy()
+    }
+
+    /// Economy-size `Q` of shape `(m, k)`, with orthonormal columns.
+    pub fn q_economy(&self) -> Result<Array2<F>, LapackError> {
+        let k = self.tau.len();
+        let mut q = Array2::zeros((self.a.nrows(), k).f());
+        q.assign(&self.a.slice(s![.., ..k]));
+        ORGQR::default().a(q.view_mut()).tau(self.tau.view()).run()?;
+        return Ok(q);
+    }
+
+    /// Complete unitary (orthogonal) `Q` of shape `(m, m)`.
+    pub fn q_complete(&self) -> Result<Array2<F>, LapackError> {
+        let m = self.a.nrows();
+        let k = self.tau.len();
+        let mut q = Array2::zeros((m, m).f());
+        q.slice_mut(s![.., ..k]).assign(&self.a.slice(s![.., ..k]));
+        ORGQR::default().a(q.view_mut()).tau(self.tau.view()).run()?;
+        return Ok(q);
+    }
+
+    /// Multiply `c` by `Q` without forming it: `op(Q) C` (`side = 'L'`) or `C op(Q)` (`side = 'R'`), with `trans`
+    /// in `'N'`, `'T'` (real) or `'C'`.
+    ///
+    /// Here `Q` is the complete `(m, m)` matrix; so `c` should have `m` rows for `side = 'L'`, or `m` columns for
+    /// `side = 'R'`.
+    pub fn apply_q<'c>(
+        &self,
+        side: char,
+        trans: char,
+        c: impl Into<ArrayViewOrMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, LapackError> {
+        ORMQR::default().a(self.a.view()).tau(self.tau.view()).c(c).side(side).trans(trans).run()
+    }
+}
+
+/// QR factorization with column pivoting `A P = Q R` of `(m, n)` matrix, as computed by `GEQP3`.
+///
+/// Permutation `jpvt` is 0-based: column `j` of `A P` is column `jpvt[j]` of `A`.
+#[derive(Debug, Clone)]
+pub struct PivotedQrFactorization<F> {
+    pub qr: QrFactorization<F>,
+    pub jpvt: Array1<usize>,
+}
+
+impl<F> PivotedQrFactorization<F>
+where
+    F: LapackFloat,
+{
+    /// Estimated numerical rank, as number of leading diagonal elements of `R` with `|R_ii| > tol`.
+    ///
+    /// If `tol` is not given, it defaults to `max(m, n) · ε · |R_00|`.
+    pub fn rank(&self, tol: Option<F::RealFloat>) -> usize {
+        let (m, n) = self.qr.a.dim();
+        let diag = self.qr.a.diag();
+        let tol = tol.unwrap_or_else(|| match diag.len() {
+            0 => F::RealFloat::zero(),
+            _ => {
+                let mn: F::RealFloat = NumCast::from(m.max(n)).unwrap();
+                mn * F::EPSILON * F::abs(diag[0])
+            },
+        });
+        return diag.iter().take_while(|&&d| F::abs(d) > tol).count();
+    }
+}
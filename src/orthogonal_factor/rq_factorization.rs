@@ -0,0 +1,75 @@
+use crate::orthogonal_factor::{ORGRQNum, ORMRQNum, ORGRQ, ORMRQ};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/// RQ factorization `A = R Q` of `(m, n)` matrix, as computed by `GERQF`.
+///
+/// With `k = min(m, n)`, `R` is stored in the last `k` columns of `a` (on and above its `(m - k)`-th subdiagonal),
+/// and `k` elementary reflectors (which define `Q` together with `tau`) are stored row-wise in the last `k` rows of
+/// `a`, left to `R`.
+#[derive(Debug, Clone)]
+pub struct RqFactorization<F> {
+    pub a: Array2<F>,
+    pub tau: Array1<F>,
+}
+
+impl<F> RqFactorization<F>
+where
+    F: LapackFloat,
+{
+    /// Upper trapezoidal factor `R` of shape `(m, k)`, with elements below its `(m - k)`-th subdiagonal zeroed.
+    pub fn r(&self) -> Array2<F> {
+        let (m, n) = self.a.dim();
+        let k = self.tau.len();
+        let mut r = self.a.slice(s![.., n - k..]).to_owned();
+        r.indexed_iter_mut().filter(|((i, j), _)| *i > j + (m - k)).for_each(|(_, x)| *x = F::zero());
+        return r;
+    }
+}
+
+impl<F> RqFactorization<F>
+where
+    F: ORGRQNum + ORMRQNum,
+{
+    /// Economy-size `Q` of shape `(k, n)`, with orthonormal rows.
+    pub fn q(&self) -> Result<Array2<F>, LapackError> {
+        self.q_economy()
+    }
+
+    /// Economy-size `Q` of shape `(k, n)`, with orthonormal rows.
+    pub fn q_economy(&self) -> Result<Array2<F>, LapackError> {
+        let m = self.a.nrows();
+        let k = self.tau.len();
+        let mut q = Array2::zeros((k, self.a.ncols()).f());
+        q.assign(&self.a.slice(s![m - k.., ..]));
+        ORGRQ::default().a(q.view_mut()).tau(self.tau.view()).run()?;
+        return Ok(q);
+    }
+
+    /// Complete unitary (orthogonal) `Q` of shape `(n, n)`.
+    pub fn q_complete(&self) -> Result<Array2<F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let k = self.tau.len();
+        let mut q = Array2::zeros((n, n).f());
+        q.slice_mut(s![n - k.., ..]).assign(&self.a.slice(s![m - k.., ..]));
+        ORGRQ::default().a(q.view_mut()).tau(self.tau.view()).run()?;
+        return Ok(q);
+    }
+
+    /// Multiply `c` by `Q` without forming it: `op(Q) C` (`side = 'L'`) or `C op(Q)` (`side = 'R'`), with `trans`
+    /// in `'N'`, `'T'` (real) or `'C'`.
+    ///
+    /// Here `Q` is the complete `(n, n)` matrix; so `c` should have `n` rows for `side = 'L'`, or `n` columns for
+    /// `side = 'R'`.
+    pub fn apply_q<'c>(
+        &self,
+        side: char,
+        trans: char,
+        c: impl Into<ArrayViewOrMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let m = self.a.nrows();
+        let k = self.tau.len();
+        let a = self.a.slice(s![m - k.., ..]);
+        ORMRQ::default().a(a).tau(self.tau.view()).c(c).side(side).trans(trans).run()
+    }
+}
@@ -0,0 +1,71 @@
+use crate::orthogonal_factor::{ORMRZNum, ORMRZ};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/// RZ factorization `A = [R, 0] Z` of `(m, n)` upper trapezoidal matrix (`m <= n`), as computed by `TZRZF`.
+///
+/// Upper triangular `R` of shape `(m, m)` is stored in the first `m` columns of `a`, and `m` elementary reflectors
+/// (which define `Z` together with `tau`) are stored row-wise in the last `n - m` columns of `a`.
+///
+/// Together with a (pivoted) QR factorization, this gives complete orthogonal decompositions such as
+/// `A P = Q [R11, 0; 0, 0] Z`.
+#[derive(Debug, Clone)]
+pub struct RzFactorization<F> {
+    pub a: Array2<F>,
+    pub tau: Array1<F>,
+}
+
+impl<F> RzFactorization<F>
+where
+    F: LapackFloat,
+{
+    /// Upper triangular factor `R` of shape `(m, m)`, with lower triangle zeroed.
+    pub fn r(&self) -> Array2<F> {
+        let m = self.a.nrows();
+        let mut r = self.a.slice(s![.., ..m]).to_owned();
+        r.indexed_iter_mut().filter(|((i, j), _)| i > j).for_each(|(_, x)| *x = F::zero());
+        return r;
+    }
+}
+
+impl<F> RzFactorization<F>
+where
+    F: ORMRZNum,
+{
+    /// Economy-size `Z` of shape `(m, n)` (first `m` rows of complete `Z`), with orthonormal rows; `A = R Z`.
+    pub fn z(&self) -> Result<Array2<F>, LapackError> {
+        self.z_economy()
+    }
+
+    /// Economy-size `Z` of shape `(m, n)` (first `m` rows of complete `Z`), with orthonormal rows; `A = R Z`.
+    pub fn z_economy(&self) -> Result<Array2<F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let mut z = Array2::zeros((m, n).f());
+        z.diag_mut().fill(F::one());
+        self.apply_z('R', 'N', z.view_mut())?;
+        return Ok(z);
+    }
+
+    /// Complete unitary (orthogonal) `Z` of shape `(n, n)`.
+    pub fn z_complete(&self) -> Result<Array2<F>, LapackError> {
+        let n = self.a.ncols();
+        let mut z = Array2::zeros((n, n).f());
+        z.diag_mut().fill(F::one());
+        self.apply_z('R', 'N', z.view_mut())?;
+        return Ok(z);
+    }
+
+    /// Multiply `c` by `Z` without forming it: `op(Z) C` (`side = 'L'`) or `C op(Z)` (`side = 'R'`), with `trans`
+    /// in `'N'`, `'T'` (real) or `'C'`.
+    ///
+    /// Here `Z` is the complete `(n, n)` matrix; so `c` should have `n` rows for `side = 'L'`, or `n` columns for
+    /// `side = 'R'`.
+    pub fn apply_z<'c>(
+        &self,
+        side: char,
+        trans: char,
+        c: impl Into<ArrayViewOrMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, LapackError> {
+        ORMRZ::default().a(self.a.view()).tau(self.tau.view()).c(c).side(side).trans(trans).run()
+    }
+}
@@ -0,0 +1,177 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TPLQT_Driver<'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    l: lapack_int,
+    mb: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    t: Array2<F>,
+    ldt: lapack_int,
+    work: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait TPLQTNum: LapackFloat {
+    unsafe fn run_tplqt(driver: &mut TPLQT_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TPLQTNum for $type {
+            unsafe fn run_tplqt(driver: &mut TPLQT_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    &driver.l,
+                    &driver.mb,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    driver.t.as_mut_ptr(),
+                    &driver.ldt,
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, stplqt_);
+impl_func!(f64, dtplqt_);
+impl_func!(c32, ctplqt_);
+impl_func!(c64, ztplqt_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`TPLQT`]: factor `l` (in `a`), reflectors `v` (in `b`) and block reflector factor `t`.
+pub type TPLQT_Output<'a, 'b, F> = (ArrayOut2<'a, F>, ArrayOut2<'b, F>, Array2<F>);
+
+/// Blocked LQ factorization of triangular-pentagonal matrix `[A, B] = [L, 0] Q`, in compact WY representation.
+///
+/// Matrix `a` is `(m, m)` lower triangular; only its lower triangle is referenced, and it is overwritten by `L`.
+/// Matrix `b` is `(m, n)` pentagonal: rectangular in its first `n - l` columns, and lower trapezoidal in its last
+/// `l` columns (`l = 0` for general rectangular `b`). It is overwritten by reflectors `V` (stored by rows).
+///
+/// Output `t` of shape `(mb, m)` holds the block reflector factors; `V` and `t` are to be passed to `TPMLQT`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TPLQT_<'a, 'b, F>
+where
+    F: TPLQTNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    /// Block size, `1 <= mb <= m`.
+    pub mb: usize,
+    #[builder(default = "0")]
+    pub l: usize,
+}
+
+impl<'a, 'b, F> TPLQT_<'a, 'b, F>
+where
+    F: TPLQTNum,
+{
+    pub fn driver(self) -> Result<TPLQT_Output<'a, 'b, F>, LapackError> {
+        let Self { a, b, mb, l } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let b = b.into_col_array_out();
+        let (m, n) = b.view().dim();
+
+        // 2. dim check & alloc
+        lapack_assert_eq!(a.view().dim(), (m, m), InvalidDim)?;
+        lapack_assert_le!(l, m.min(n), InvalidDim)?;
+        lapack_assert_le!(mb, m.max(1), InvalidDim)?;
+        lapack_assert_le!(1, mb, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let t = Array2::zeros((mb, m).f());
+        let work = Array1::zeros((mb * m).max(1));
+
+        // 3. struct build
+        let mut driver = TPLQT_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            l: l.try_into()?,
+            mb: mb.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            t,
+            ldt: mb.try_into()?,
+            work,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_tplqt(&mut driver)?;
+        }
+
+        // 5. finalize
+        let TPLQT_Driver { a, b, t, .. } = driver;
+        return Ok((a.clone_to_view_mut(), b.clone_to_view_mut(), t));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TPLQT<'a, 'b, F> = TPLQT_Builder<'a, 'b, F>;
+pub type STPLQT<'a, 'b> = TPLQT<'a, 'b, f32>;
+pub type DTPLQT<'a, 'b> = TPLQT<'a, 'b, f64>;
+pub type CTPLQT<'a, 'b> = TPLQT<'a, 'b, c32>;
+pub type ZTPLQT<'a, 'b> = TPLQT<'a, 'b, c64>;
+
+impl<'a, 'b, F> TPLQT_Builder<'a, 'b, F>
+where
+    F: TPLQTNum,
+{
+    pub fn run(self) -> Result<TPLQT_Output<'a, 'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() && obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, b, t) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, b, t));
+        }
+    }
+}
+
+/* #endregion */
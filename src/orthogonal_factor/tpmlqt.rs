@@ -0,0 +1,214 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TPMLQT_Driver<'v, 't, 'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    side: c_char,
+    trans: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    k: lapack_int,
+    l: lapack_int,
+    mb: lapack_int,
+    v: ArrayView2<'v, F>,
+    ldv: lapack_int,
+    t: ArrayView2<'t, F>,
+    ldt: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    work: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait TPMLQTNum: LapackFloat {
+    unsafe fn run_tpmlqt(driver: &mut TPMLQT_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TPMLQTNum for $type {
+            unsafe fn run_tpmlqt(driver: &mut TPMLQT_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.side,
+                    &driver.trans,
+                    &driver.m,
+                    &driver.n,
+                    &driver.k,
+                    &driver.l,
+                    &driver.mb,
+                    driver.v.as_ptr(),
+                    &driver.ldv,
+                    driver.t.as_ptr(),
+                    &driver.ldt,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, stpmlqt_);
+impl_func!(f64, dtpmlqt_);
+impl_func!(c32, ctpmlqt_);
+impl_func!(c64, ztpmlqt_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Multiply stacked matrix `C` by `Q` from `TPLQT`: `op(Q) C` with `C = [A; B]` (`side = 'L'`), or `C op(Q)`
+/// with `C = [A, B]` (`side = 'R'`).
+///
+/// Argument `v` holds `k` reflectors in its first `k` rows, and `t` of shape `(mb, k)` holds the block reflector
+/// factors; `k` and `mb` are taken from shape of `t`. With `b` of shape `(m, n)`, `a` should be `(k, n)` and `v`
+/// should have `m` columns for `side = 'L'`; `a` should be `(m, k)` and `v` should have `n` columns for
+/// `side = 'R'`. Option `l` is the same as the one passed to `TPLQT`. For real matrices, `trans = 'C'` is regarded
+/// as `'T'`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TPMLQT_<'v, 't, 'a, 'b, F>
+where
+    F: TPMLQTNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub v: ArrayView2<'v, F>,
+    #[builder(setter(into))]
+    pub t: ArrayView2<'t, F>,
+
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    #[builder(default = "'L'")]
+    pub side: char,
+    #[builder(default = "'N'")]
+    pub trans: char,
+    #[builder(default = "0")]
+    pub l: usize,
+}
+
+impl<'v, 't, 'a, 'b, F> TPMLQT_<'v, 't, 'a, 'b, F>
+where
+    F: TPMLQTNum,
+{
+    pub fn driver(self) -> Result<(ArrayOut2<'a, F>, ArrayOut2<'b, F>), LapackError> {
+        let Self { v, t, a, b, side, trans, l } = self;
+
+        // 1. dim assign, flag check
+        let v = v.to_col_layout()?;
+        let t = t.to_col_layout()?;
+        let a = a.into_col_array_out();
+        let b = b.into_col_array_out();
+        let (m, n) = b.view().dim();
+        let (mb, k) = t.dim();
+        let side = side.to_ascii_uppercase();
+        let trans = trans.to_ascii_uppercase();
+        let trans = if F::is_complex() || trans != 'C' { trans } else { 'T' };
+        lapack_check_flag!(side, ['L', 'R'])?;
+        match F::is_complex() {
+            true => lapack_check_flag!(trans, ['N', 'C'])?,
+            false => lapack_check_flag!(trans, ['N', 'T'])?,
+        };
+
+        // 2. dim check & buffer alloc
+        let (nq, a_dim) = if side == 'L' { (m, (k, n)) } else { (n, (m, k)) };
+        lapack_assert_eq!(a.view().dim(), a_dim, InvalidDim)?;
+        lapack_assert_eq!(v.ncols(), nq, InvalidDim)?;
+        lapack_assert_le!(k, v.nrows(), InvalidDim)?;
+        lapack_assert_le!(l, k, InvalidDim)?;
+        lapack_assert_le!(mb, k.max(1), InvalidDim)?;
+        lapack_assert_le!(1, mb, InvalidDim)?;
+        let ldv = v.stride_of(Axis(1)).max(k.max(1) as isize);
+        let ldt = t.stride_of(Axis(1)).max(mb as isize);
+        let lda = a.view().stride_of(Axis(1)).max(a_dim.0.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let nw = if side == 'L' { n } else { m };
+        let work = Array1::zeros((nw * mb).max(1));
+
+        // 3. struct build
+        let mut driver = TPMLQT_Driver {
+            side: side as c_char,
+            trans: trans as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            l: l.try_into()?,
+            mb: mb.try_into()?,
+            v: v.view(),
+            ldv: ldv.try_into()?,
+            t: t.view(),
+            ldt: ldt.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            work,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_tpmlqt(&mut driver)?;
+        }
+
+        // 5. finalize
+        let TPMLQT_Driver { a, b, .. } = driver;
+        return Ok((a.clone_to_view_mut(), b.clone_to_view_mut()));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TPMLQT<'v, 't, 'a, 'b, F> = TPMLQT_Builder<'v, 't, 'a, 'b, F>;
+pub type STPMLQT<'v, 't, 'a, 'b> = TPMLQT<'v, 't, 'a, 'b, f32>;
+pub type DTPMLQT<'v, 't, 'a, 'b> = TPMLQT<'v, 't, 'a, 'b, f64>;
+pub type CTPMLQT<'v, 't, 'a, 'b> = TPMLQT<'v, 't, 'a, 'b, c32>;
+pub type ZTPMLQT<'v, 't, 'a, 'b> = TPMLQT<'v, 't, 'a, 'b, c64>;
+
+impl<'v, 't, 'a, 'b, F> TPMLQT_Builder<'v, 't, 'a, 'b, F>
+where
+    F: TPMLQTNum,
+{
+    pub fn run(self) -> Result<(ArrayOut2<'a, F>, ArrayOut2<'b, F>), LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() && obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, b) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, b));
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,177 @@
+use crate::ffi::{self, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TPQRT_Driver<'a, 'b, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    l: lapack_int,
+    nb: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    b: ArrayOut2<'b, F>,
+    ldb: lapack_int,
+    t: Array2<F>,
+    ldt: lapack_int,
+    work: Array1<F>,
+    info: lapack_int,
+}
+
+pub trait TPQRTNum: LapackFloat {
+    unsafe fn run_tpqrt(driver: &mut TPQRT_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TPQRTNum for $type {
+            unsafe fn run_tpqrt(driver: &mut TPQRT_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    &driver.l,
+                    &driver.nb,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.b.get_data_mut_ptr(),
+                    &driver.ldb,
+                    driver.t.as_mut_ptr(),
+                    &driver.ldt,
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, stpqrt_);
+impl_func!(f64, dtpqrt_);
+impl_func!(c32, ctpqrt_);
+impl_func!(c64, ztpqrt_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`TPQRT`]: factor `r` (in `a`), reflectors `v` (in `b`) and block reflector factor `t`.
+pub type TPQRT_Output<'a, 'b, F> = (ArrayOut2<'a, F>, ArrayOut2<'b, F>, Array2<F>);
+
+/// Blocked QR factorization of triangular-pentagonal matrix `[A; B] = Q [R; 0]`, in compact WY representation.
+///
+/// Matrix `a` is `(n, n)` upper triangular; only its upper triangle is referenced, and it is overwritten by `R`.
+/// Matrix `b` is `(m, n)` pentagonal: rectangular in its first `m - l` rows, and upper trapezoidal in its last
+/// `l` rows (`l = 0` for general rectangular `b`). It is overwritten by reflectors `V`.
+///
+/// Output `t` of shape `(nb, n)` holds the block reflector factors; `V` and `t` are to be passed to `TPMQRT`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TPQRT_<'a, 'b, F>
+where
+    F: TPQRTNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(into))]
+    pub b: ArrayViewOrMut2<'b, F>,
+
+    // option
+    /// Block size, `1 <= nb <= n`.
+    pub nb: usize,
+    #[builder(default = "0")]
+    pub l: usize,
+}
+
+impl<'a, 'b, F> TPQRT_<'a, 'b, F>
+where
+    F: TPQRTNum,
+{
+    pub fn driver(self) -> Result<TPQRT_Output<'a, 'b, F>, LapackError> {
+        let Self { a, b, nb, l } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let b = b.into_col_array_out();
+        let (m, n) = b.view().dim();
+
+        // 2. dim check & alloc
+        lapack_assert_eq!(a.view().dim(), (n, n), InvalidDim)?;
+        lapack_assert_le!(l, m.min(n), InvalidDim)?;
+        lapack_assert_le!(nb, n.max(1), InvalidDim)?;
+        lapack_assert_le!(1, nb, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(n.max(1) as isize);
+        let ldb = b.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let t = Array2::zeros((nb, n).f());
+        let work = Array1::zeros((nb * n).max(1));
+
+        // 3. struct build
+        let mut driver = TPQRT_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            l: l.try_into()?,
+            nb: nb.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            b,
+            ldb: ldb.try_into()?,
+            t,
+            ldt: nb.try_into()?,
+            work,
+            info: 0,
+        };
+
+        // 4. perform comput
+        unsafe {
+            F::run_tpqrt(&mut driver)?;
+        }
+
+        // 5. finalize
+        let TPQRT_Driver { a, b, t, .. } = driver;
+        return Ok((a.clone_to_view_mut(), b.clone_to_view_mut(), t));
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TPQRT<'a, 'b, F> = TPQRT_Builder<'a, 'b, F>;
+pub type STPQRT<'a, 'b> = TPQRT<'a, 'b, f32>;
+pub type DTPQRT<'a, 'b> = TPQRT<'a, 'b, f64>;
+pub type CTPQRT<'a, 'b> = TPQRT<'a, 'b, c32>;
+pub type ZTPQRT<'a, 'b> = TPQRT<'a, 'b, c64>;
+
+impl<'a, 'b, F> TPQRT_Builder<'a, 'b, F>
+where
+    F: TPQRTNum,
+{
+    pub fn run(self) -> Result<TPQRT_Output<'a, 'b, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() && obj.b.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let (a, b, t) = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            let b = match b {
+                ArrayOut::Owned(b) => ArrayOut::Owned(b.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(b) => ArrayOut::ViewMut(b),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok((a, b, t));
+        }
+    }
+}
+
+/* #endregion */
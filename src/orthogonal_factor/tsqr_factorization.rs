@@ -0,0 +1,92 @@
+use crate::orthogonal_factor::{GEMQRNum, ORHR_COLNum, GEMQR, ORHR_COL};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/// QR factorization `A = Q R` of `(m, n)` matrix, as computed by `GEQR`.
+///
+/// Array `a` holds `R` on and above diagonal. The representation of `Q` (below diagonal of `a`, together with
+/// opaque array `t`) depends on the algorithm and block sizes chosen by LAPACK, and is only meaningful to `GEMQR`.
+#[derive(Debug, Clone)]
+pub struct TsqrFactorization<F> {
+    pub a: Array2<F>,
+    pub t: Array1<F>,
+}
+
+impl<F> TsqrFactorization<F>
+where
+    F: LapackFloat,
+{
+    /// Upper trapezoidal factor `R` of shape `(k, n)`, with lower triangle zeroed.
+    pub fn r(&self) -> Array2<F> {
+        let k = self.a.nrows().min(self.a.ncols());
+        let mut r = self.a.slice(s![..k, ..]).to_owned();
+        r.indexed_iter_mut().filter(|((i, j), _)| i > j).for_each(|(_, x)| *x = F::zero());
+        return r;
+    }
+}
+
+impl<F> TsqrFactorization<F>
+where
+    F: GEMQRNum,
+{
+    /// Economy-size `Q` of shape `(m, k)`, with orthonormal columns.
+    pub fn q(&self) -> Result<Array2<F>, LapackError> {
+        self.q_economy()
+    }
+
+    /// Economy-size `Q` of shape `(m, k)`, with orthonormal columns.
+    pub fn q_economy(&self) -> Result<Array2<F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let mut q = Array2::zeros((m, m.min(n)).f());
+        q.diag_mut().fill(F::one());
+        self.apply_q('L', 'N', q.view_mut())?;
+        return Ok(q);
+    }
+
+    /// Complete unitary (orthogonal) `Q` of shape `(m, m)`.
+    pub fn q_complete(&self) -> Result<Array2<F>, LapackError> {
+        let m = self.a.nrows();
+        let mut q = Array2::zeros((m, m).f());
+        q.diag_mut().fill(F::one());
+        self.apply_q('L', 'N', q.view_mut())?;
+        return Ok(q);
+    }
+
+    /// Multiply `c` by `Q` without forming it: `op(Q) C` (`side = 'L'`) or `C op(Q)` (`side = 'R'`), with `trans`
+    /// in `'N'`, `'T'` (real) or `'C'`.
+    ///
+    /// Here `Q` is the complete `(m, m)` matrix; so `c` should have `m` rows for `side = 'L'`, or `m` columns for
+    /// `side = 'R'`.
+    pub fn apply_q<'c>(
+        &self,
+        side: char,
+        trans: char,
+        c: impl Into<ArrayViewOrMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, LapackError> {
+        GEMQR::default().a(self.a.view()).t(self.t.view()).c(c).side(side).trans(trans).run()
+    }
+}
+
+impl<F> TsqrFactorization<F>
+where
+    F: GEMQRNum + ORHR_COLNum,
+{
+    /// Convert to standard Householder form by `ORHR_COL` (requires `m >= n`), with column block size `nb`.
+    ///
+    /// Output `(a, t)` is in the same format as `GEQRT` output, and can be passed to `GEMQRT`: `a` holds `R` (with
+    /// signs of rows adjusted) on and above diagonal and reflectors below diagonal, and `t` of shape
+    /// `(min(nb, n), n)` holds the block reflector factors.
+    pub fn to_householder(&self, nb: usize) -> Result<(Array2<F>, Array2<F>), LapackError> {
+        let n = self.a.ncols();
+        let r = self.r();
+        let mut v = self.q_economy()?;
+        lapack_assert_eq!(r.dim(), (n, n), InvalidDim)?;
+        let (_, t, d) = ORHR_COL::default().a(v.view_mut()).nb(nb).run()?;
+        for i in 0..n {
+            for j in i..n {
+                v[[i, j]] = d[i] * r[[i, j]];
+            }
+        }
+        return Ok((v, t));
+    }
+}
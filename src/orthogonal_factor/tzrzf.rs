@@ -0,0 +1,149 @@
+use crate::ffi::{self, lapack_int};
+use crate::orthogonal_factor::RzFactorization;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct TZRZF_Driver<'a, 'work, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    tau: Array1<F>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait TZRZFNum: LapackFloat {
+    unsafe fn run_tzrzf(driver: &mut TZRZF_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl TZRZFNum for $type {
+            unsafe fn run_tzrzf(driver: &mut TZRZF_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.tau.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, stzrzf_);
+impl_func!(f64, dtzrzf_);
+impl_func!(c32, ctzrzf_);
+impl_func!(c64, ztzrzf_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// RZ factorization `A = [R, 0] Z` of `(m, n)` upper trapezoidal matrix (`m <= n`).
+///
+/// Only the upper trapezoid of `a` is referenced. If `a` is given as mutable view, it is also overwritten in place
+/// by the factors (`R` in the first `m` columns, reflectors in the last `n - m` columns), in the same way as LAPACK.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct TZRZF_<'a, 'work, F>
+where
+    F: TZRZFNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'work, F> TZRZF_<'a, 'work, F>
+where
+    F: TZRZFNum,
+{
+    pub fn driver(self) -> Result<RzFactorization<F>, LapackError> {
+        let Self { a, work } = self;
+
+        // 1. dim assign & check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        lapack_assert_le!(m, n, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 2. alloc
+        let tau = Array1::zeros(m);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, m.max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = TZRZF_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            tau,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_tzrzf(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_tzrzf(&mut driver)?;
+        }
+
+        // 7. finalize
+        let TZRZF_Driver { a, tau, .. } = driver;
+        let a = a.clone_to_view_mut().into_owned();
+        return Ok(RzFactorization { a, tau });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type TZRZF<'a, 'work, F> = TZRZF_Builder<'a, 'work, F>;
+pub type STZRZF<'a, 'work> = TZRZF<'a, 'work, f32>;
+pub type DTZRZF<'a, 'work> = TZRZF<'a, 'work, f64>;
+pub type CTZRZF<'a, 'work> = TZRZF<'a, 'work, c32>;
+pub type ZTZRZF<'a, 'work> = TZRZF<'a, 'work, c64>;
+
+impl<'a, 'work, F> TZRZF_Builder<'a, 'work, F>
+where
+    F: TZRZFNum,
+{
+    pub fn run(self) -> Result<RzFactorization<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -1,6 +1,250 @@
 pub use crate::ffi::lapack_int;
 pub use crate::util::*;
 
+// * Linear solve, AX = B
+
+// ** LU: general matrix
+
+// *** -- computational --
+pub use crate::linear_solve::lu::getrf::{GETRFNum, CGETRF, DGETRF, GETRF, SGETRF, ZGETRF};
+pub use crate::linear_solve::lu::getri::{GETRINum, CGETRI, DGETRI, GETRI, SGETRI, ZGETRI};
+
+// ** Cholesky: Hermitian/symmetric positive definite matrix
+
+// *** -- computational --
+pub use crate::linear_solve::cholesky::potrf::{POTRFNum, CPOTRF, DPOTRF, POTRF, SPOTRF, ZPOTRF};
+pub use crate::linear_solve::cholesky::potri::{POTRINum, CPOTRI, DPOTRI, POTRI, SPOTRI, ZPOTRI};
+
+// ** LDL: Hermitian/symmetric indefinite matrix
+
+// *** -- computational --
+pub use crate::linear_solve::ldl::sytrf::{
+    SYTRFNum, CHETRF, CSYTRF, DSYTRF, HETRF, SSYTRF, SYTRF, ZHETRF, ZSYTRF,
+};
+pub use crate::linear_solve::ldl::sytri::{
+    SYTRINum, CHETRI, CSYTRI, DSYTRI, HETRI, SSYTRI, SYTRI, ZHETRI, ZSYTRI,
+};
+
+// ** Determinant
+
+pub use crate::linear_solve::det::{
+    det, det_he, det_po, det_sy, logdet, logdet_he, logdet_po, logdet_sy, slogdet, slogdet_he, slogdet_po,
+    slogdet_sy,
+};
+
+// ** Inverse
+
+pub use crate::linear_solve::inv::{inv, INVNum, MatrixStructure, CINV, DINV, INV, SINV, ZINV};
+
+// ** Packed storage
+
+pub use crate::linear_solve::packed::packed_matrix::{PackedMatrix, PackedMatrixNum};
+
+// *** -- Cholesky: Hermitian/symmetric positive definite --
+pub use crate::linear_solve::packed::ppcon::{PPCONNum, CPPCON, DPPCON, PPCON, SPPCON, ZPPCON};
+pub use crate::linear_solve::packed::ppsv::{PPSVNum, CPPSV, DPPSV, PPSV, SPPSV, ZPPSV};
+pub use crate::linear_solve::packed::pptrf::{PPTRFNum, CPPTRF, DPPTRF, PPTRF, SPPTRF, ZPPTRF};
+pub use crate::linear_solve::packed::pptri::{PPTRINum, CPPTRI, DPPTRI, PPTRI, SPPTRI, ZPPTRI};
+pub use crate::linear_solve::packed::pptrs::{PPTRSNum, CPPTRS, DPPTRS, PPTRS, SPPTRS, ZPPTRS};
+
+// *** -- LDL: Hermitian/symmetric indefinite --
+pub use crate::linear_solve::packed::spcon::{
+    SPCONNum, CHPCON, CSPCON, DSPCON, HPCON, SPCON, SSPCON, ZHPCON, ZSPCON,
+};
+pub use crate::linear_solve::packed::spsv::{
+    SPSVNum, SPSV_Output, CHPSV, CSPSV, DSPSV, HPSV, SPSV, SSPSV, ZHPSV, ZSPSV,
+};
+pub use crate::linear_solve::packed::sptrf::{
+    SPTRFNum, CHPTRF, CSPTRF, DSPTRF, HPTRF, SPTRF, SSPTRF, ZHPTRF, ZSPTRF,
+};
+pub use crate::linear_solve::packed::sptri::{
+    SPTRINum, CHPTRI, CSPTRI, DSPTRI, HPTRI, SPTRI, SSPTRI, ZHPTRI, ZSPTRI,
+};
+pub use crate::linear_solve::packed::sptrs::{
+    SPTRSNum, CHPTRS, CSPTRS, DSPTRS, HPTRS, SPTRS, SSPTRS, ZHPTRS, ZSPTRS,
+};
+
+// ** Rectangular full packed (RFP) storage
+
+pub use crate::linear_solve::rfp::rfp_matrix::{RfpMatrix, RfpMatrixNum};
+
+// *** -- Cholesky: Hermitian/symmetric positive definite --
+pub use crate::linear_solve::rfp::pftrf::{PFTRFNum, CPFTRF, DPFTRF, PFTRF, SPFTRF, ZPFTRF};
+pub use crate::linear_solve::rfp::pftri::{PFTRINum, CPFTRI, DPFTRI, PFTRI, SPFTRI, ZPFTRI};
+pub use crate::linear_solve::rfp::pftrs::{PFTRSNum, CPFTRS, DPFTRS, PFTRS, SPFTRS, ZPFTRS};
+
+// *** -- BLAS-like --
+pub use crate::linear_solve::rfp::sfrk::{SFRKNum, CHFRK, DSFRK, HFRK, SFRK, SSFRK, ZHFRK};
+pub use crate::linear_solve::rfp::tfsm::{TFSMNum, CTFSM, DTFSM, STFSM, TFSM, ZTFSM};
+
+// ** Triangular computational routines
+
+pub use crate::linear_solve::triangular::lauum::{LAUUMNum, CLAUUM, DLAUUM, LAUUM, SLAUUM, ZLAUUM};
+pub use crate::linear_solve::triangular::tbtrs::{TBTRSNum, CTBTRS, DTBTRS, STBTRS, TBTRS, ZTBTRS};
+pub use crate::linear_solve::triangular::tptrs::{TPTRSNum, CTPTRS, DTPTRS, STPTRS, TPTRS, ZTPTRS};
+pub use crate::linear_solve::triangular::trcon::{TRCONNum, CTRCON, DTRCON, STRCON, TRCON, ZTRCON};
+pub use crate::linear_solve::triangular::trtri::{TRTRINum, CTRTRI, DTRTRI, STRTRI, TRTRI, ZTRTRI};
+pub use crate::linear_solve::triangular::trtrs::{TRTRSNum, CTRTRS, DTRTRS, STRTRS, TRTRS, ZTRTRS};
+
+// * Linear least squares, min |AX - B|
+
+// ** Standard least squares
+
+// *** -- full rank --
+pub use crate::least_squares::gels::{GELSNum, GELS_Output, CGELS, DGELS, GELS, SGELS, ZGELS};
+pub use crate::least_squares::getsls::{GETSLSNum, CGETSLS, DGETSLS, GETSLS, SGETSLS, ZGETSLS};
+
+// *** -- rank deficient --
+pub use crate::least_squares::lstsq::{LSTSQNum, LSTSQ_Output, CLSTSQ, DLSTSQ, LSTSQ, SLSTSQ, ZLSTSQ};
+
+// ** Constrained least squares
+
+pub use crate::least_squares::ggglm::{GGGLMNum, CGGGLM, DGGGLM, GGGLM, SGGGLM, ZGGGLM};
+pub use crate::least_squares::gglse::{GGLSENum, CGGLSE, DGGLSE, GGLSE, SGGLSE, ZGGLSE};
+
+// ** Streaming least squares
+
+pub use crate::least_squares::accumulator::{LqAccumulator, QrAccumulator};
+
+// * Orthogonal factors
+
+// ** QR factorization
+
+pub use crate::orthogonal_factor::qr_factorization::{PivotedQrFactorization, QrFactorization};
+
+// *** -- computational --
+pub use crate::orthogonal_factor::geqp3::{GEQP3Num, CGEQP3, DGEQP3, GEQP3, SGEQP3, ZGEQP3};
+pub use crate::orthogonal_factor::geqrf::{GEQRFNum, CGEQRF, DGEQRF, GEQRF, SGEQRF, ZGEQRF};
+pub use crate::orthogonal_factor::orgqr::{ORGQRNum, CUNGQR, DORGQR, ORGQR, SORGQR, UNGQR, ZUNGQR};
+pub use crate::orthogonal_factor::ormqr::{ORMQRNum, CUNMQR, DORMQR, ORMQR, SORMQR, UNMQR, ZUNMQR};
+
+// *** -- compact WY, blocked --
+pub use crate::orthogonal_factor::gemqrt::{GEMQRTNum, CGEMQRT, DGEMQRT, GEMQRT, SGEMQRT, ZGEMQRT};
+pub use crate::orthogonal_factor::geqrt::{GEQRTNum, CGEQRT, DGEQRT, GEQRT, SGEQRT, ZGEQRT};
+pub use crate::orthogonal_factor::tpmqrt::{TPMQRTNum, CTPMQRT, DTPMQRT, STPMQRT, TPMQRT, ZTPMQRT};
+pub use crate::orthogonal_factor::tpqrt::{TPQRTNum, TPQRT_Output, CTPQRT, DTPQRT, STPQRT, TPQRT, ZTPQRT};
+
+// ** Tall-skinny QR factorization
+
+pub use crate::orthogonal_factor::tsqr_factorization::TsqrFactorization;
+
+// *** -- computational --
+pub use crate::orthogonal_factor::gemqr::{GEMQRNum, CGEMQR, DGEMQR, GEMQR, SGEMQR, ZGEMQR};
+pub use crate::orthogonal_factor::geqr::{GEQRNum, CGEQR, DGEQR, GEQR, SGEQR, ZGEQR};
+
+// *** -- Householder reconstruction --
+pub use crate::orthogonal_factor::getsqrhrt::{
+    GETSQRHRTNum, CGETSQRHRT, DGETSQRHRT, GETSQRHRT, SGETSQRHRT, ZGETSQRHRT,
+};
+pub use crate::orthogonal_factor::orhr_col::{
+    ORHR_COLNum, ORHR_COL_Output, CUNHR_COL, DORHR_COL, ORHR_COL, SORHR_COL, UNHR_COL, ZUNHR_COL,
+};
+
+// ** LQ factorization
+
+pub use crate::orthogonal_factor::lq_factorization::LqFactorization;
+
+// *** -- computational --
+pub use crate::orthogonal_factor::gelqf::{GELQFNum, CGELQF, DGELQF, GELQF, SGELQF, ZGELQF};
+pub use crate::orthogonal_factor::orglq::{ORGLQNum, CUNGLQ, DORGLQ, ORGLQ, SORGLQ, UNGLQ, ZUNGLQ};
+pub use crate::orthogonal_factor::ormlq::{ORMLQNum, CUNMLQ, DORMLQ, ORMLQ, SORMLQ, UNMLQ, ZUNMLQ};
+
+// *** -- compact WY, blocked --
+pub use crate::orthogonal_factor::tplqt::{TPLQTNum, TPLQT_Output, CTPLQT, DTPLQT, STPLQT, TPLQT, ZTPLQT};
+pub use crate::orthogonal_factor::tpmlqt::{TPMLQTNum, CTPMLQT, DTPMLQT, STPMLQT, TPMLQT, ZTPMLQT};
+
+// ** QL factorization
+
+pub use crate::orthogonal_factor::ql_factorization::QlFactorization;
+
+// *** -- computational --
+pub use crate::orthogonal_factor::geqlf::{GEQLFNum, CGEQLF, DGEQLF, GEQLF, SGEQLF, ZGEQLF};
+pub use crate::orthogonal_factor::orgql::{ORGQLNum, CUNGQL, DORGQL, ORGQL, SORGQL, UNGQL, ZUNGQL};
+pub use crate::orthogonal_factor::ormql::{ORMQLNum, CUNMQL, DORMQL, ORMQL, SORMQL, UNMQL, ZUNMQL};
+
+// ** RQ factorization
+
+pub use crate::orthogonal_factor::rq_factorization::RqFactorization;
+
+// *** -- computational --
+pub use crate::orthogonal_factor::gerqf::{GERQFNum, CGERQF, DGERQF, GERQF, SGERQF, ZGERQF};
+pub use crate::orthogonal_factor::orgrq::{ORGRQNum, CUNGRQ, DORGRQ, ORGRQ, SORGRQ, UNGRQ, ZUNGRQ};
+pub use crate::orthogonal_factor::ormrq::{ORMRQNum, CUNMRQ, DORMRQ, ORMRQ, SORMRQ, UNMRQ, ZUNMRQ};
+
+// ** RZ factorization: upper trapezoidal matrix
+
+pub use crate::orthogonal_factor::rz_factorization::RzFactorization;
+
+// *** -- computational --
+pub use crate::orthogonal_factor::ormrz::{ORMRZNum, CUNMRZ, DORMRZ, ORMRZ, SORMRZ, UNMRZ, ZUNMRZ};
+pub use crate::orthogonal_factor::tzrzf::{TZRZFNum, CTZRZF, DTZRZF, STZRZF, TZRZF, ZTZRZF};
+
+// * Householder reflectors
+
+// ** Elementary and block reflectors
+
+// *** -- elementary --
+pub use crate::householder::larf::{LARFNum, CLARF, DLARF, LARF, SLARF, ZLARF};
+pub use crate::householder::larfg::{LARFGNum, CLARFG, DLARFG, LARFG, SLARFG, ZLARFG};
+pub use crate::householder::larfx::{LARFXNum, CLARFX, DLARFX, LARFX, SLARFX, ZLARFX};
+
+// *** -- block --
+pub use crate::householder::larfb::{LARFBNum, CLARFB, DLARFB, LARFB, SLARFB, ZLARFB};
+pub use crate::householder::larft::{LARFTNum, CLARFT, DLARFT, LARFT, SLARFT, ZLARFT};
+
+// * Singular value decomposition (SVD)
+
+// ** Standard SVD driver, A = UΣVᴴ
+
+// *** -- QR iteration --
+pub use crate::singular_value::gesvd::{GESVDNum, GESVD_Output, CGESVD, DGESVD, GESVD, SGESVD, ZGESVD};
+
+// *** -- divide and conquer --
+pub use crate::singular_value::gesdd::{GESDDNum, GESDD_Output, CGESDD, DGESDD, GESDD, SGESDD, ZGESDD};
+
+// *** -- selected singular triplets --
+pub use crate::singular_value::gesvdx::{
+    GESVDXNum, GESVDX_Output, CGESVDX, DGESVDX, GESVDX, SGESVDX, ZGESVDX,
+};
+
+// *** -- QR preconditioned --
+pub use crate::singular_value::gesvdq::{
+    GESVDQNum, GESVDQ_Output, CGESVDQ, DGESVDQ, GESVDQ, SGESVDQ, ZGESVDQ,
+};
+
+// ** High-accuracy SVD driver, one-sided Jacobi
+
+pub use crate::singular_value::gejsv::{GEJSVNum, GEJSV_Output, CGEJSV, DGEJSV, GEJSV, SGEJSV, ZGEJSV};
+pub use crate::singular_value::gesvj::{GESVJNum, GESVJ_Output, CGESVJ, DGESVJ, GESVJ, SGESVJ, ZGESVJ};
+
+// ** Bidiagonal reduction, A = QBPᴴ
+
+pub use crate::singular_value::bidiagonal_factorization::BidiagonalFactorization;
+
+// *** -- computational --
+pub use crate::singular_value::gebrd::{GEBRDNum, CGEBRD, DGEBRD, GEBRD, SGEBRD, ZGEBRD};
+pub use crate::singular_value::orgbr::{ORGBRNum, CUNGBR, DORGBR, ORGBR, SORGBR, UNGBR, ZUNGBR};
+pub use crate::singular_value::ormbr::{ORMBRNum, CUNMBR, DORMBR, ORMBR, SORMBR, UNMBR, ZUNMBR};
+
+// ** Bidiagonal SVD, B = QΣPᴴ
+
+pub use crate::singular_value::bdsdc::{BDSDCNum, BDSDC_Output, BDSDC, DBDSDC, SBDSDC};
+pub use crate::singular_value::bdsqr::{BDSQRNum, BDSQR_Output, BDSQR, CBDSQR, DBDSQR, SBDSQR, ZBDSQR};
+pub use crate::singular_value::bdsvdx::{BDSVDXNum, BDSVDX_Output, BDSVDX, DBDSVDX, SBDSVDX};
+
+// ** Randomized truncated SVD, A ≈ UΣVᴴ
+
+pub use crate::singular_value::randomized_svd::{
+    randomized_range_finder, RSVDNum, RSVD_Output, CRSVD, DRSVD, RSVD, SRSVD, ZRSVD,
+};
+
+// *** -- random number generation --
+pub use crate::singular_value::larnv::{LARNVNum, LARNV_Output, CLARNV, DLARNV, LARNV, SLARNV, ZLARNV};
+
+// ** Matrix functions by SVD
+
+pub use crate::singular_value::pinv::{matrix_rank, null_space, orth, pinv};
+
 // * Hermitian/symmetric eigenvalues
 
 // ** Standard eig driver, AV = VΛ
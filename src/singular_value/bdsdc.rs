@@ -0,0 +1,200 @@
+extern crate alloc;
+
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use alloc::string::String;
+use core::fmt::Write;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct BDSDC_Driver<F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    compq: c_char,
+    n: lapack_int,
+    d: Array1<F>,
+    e: Array1<F>,
+    u: Array2<F>,
+    ldu: lapack_int,
+    vt: Array2<F>,
+    ldvt: lapack_int,
+    q: Array1<F>,
+    iq: Array1<lapack_int>,
+    work: Array1<F>,
+    iwork: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait BDSDCNum: LapackFloat {
+    unsafe fn run_bdsdc(driver: &mut BDSDC_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl BDSDCNum for $type {
+            unsafe fn run_bdsdc(driver: &mut BDSDC_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.compq,
+                    &driver.n,
+                    driver.d.as_mut_ptr(),
+                    driver.e.as_mut_ptr(),
+                    driver.u.as_mut_ptr(),
+                    &driver.ldu,
+                    driver.vt.as_mut_ptr(),
+                    &driver.ldvt,
+                    driver.q.as_mut_ptr(),
+                    driver.iq.as_mut_ptr(),
+                    driver.work.as_mut_ptr(),
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sbdsdc_);
+impl_func!(f64, dbdsdc_);
+
+/* #endregion */
+
+/* #region error */
+
+/// Translate positive `info` of `BDSDC` (failure to compute a singular value) into readable error.
+fn bdsdc_check_info(result: Result<(), LapackError>) -> Result<(), LapackError> {
+    match result {
+        Err(LapackError::Info(info)) if info > 0 => {
+            let mut s = String::from(concat!(file!(), ":", line!(), ": ", "LapackError::FailedCheck", " : "));
+            write!(s, "BDSDC failed to compute a singular value (info = {info})").unwrap();
+            Err(LapackError::FailedCheck(s))
+        },
+        _ => result,
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`BDSDC`].
+#[derive(Debug, Clone)]
+pub struct BDSDC_Output<F> {
+    /// Singular values of `B` in decreasing order, of length `n`.
+    pub s: Array1<F>,
+    /// Left singular vectors of `B`, of shape `(n, n)`, for `compq = 'I'`.
+    pub u: Option<Array2<F>>,
+    /// Right singular vectors (transposed) of `B`, of shape `(n, n)`, for `compq = 'I'`.
+    pub vt: Option<Array2<F>>,
+}
+
+/// Singular value decomposition `B = U Σ Vᵀ` of real bidiagonal matrix by divide-and-conquer.
+///
+/// Bidiagonal `B` of order `n` has diagonal `d` and off-diagonal `e` (of length `n - 1`), which is superdiagonal
+/// for `uplo = 'U'` (default) or subdiagonal for `uplo = 'L'`. Option `compq` is `'I'` (compute singular vectors,
+/// default) or `'N'` (singular values only); the compact form `'P'` of LAPACK is not supported.
+///
+/// This routine is only available for real types.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct BDSDC_<'d, 'e, F>
+where
+    F: BDSDCNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub d: ArrayView1<'d, F>,
+    #[builder(setter(into))]
+    pub e: ArrayView1<'e, F>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+    #[builder(default = "'I'")]
+    pub compq: char,
+}
+
+impl<F> BDSDC_<'_, '_, F>
+where
+    F: BDSDCNum,
+{
+    pub fn driver(self) -> Result<BDSDC_Output<F>, LapackError> {
+        let Self { d, e, uplo, compq } = self;
+
+        // 1. dim assign, flag check
+        let n = d.len();
+        let uplo = uplo.to_ascii_uppercase();
+        let compq = compq.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        lapack_check_flag!(compq, ['I', 'N'])?;
+
+        // 2. dim check & alloc
+        lapack_assert_eq!(e.len(), n.saturating_sub(1), InvalidDim)?;
+        let d = d.to_owned();
+        let mut e_ext = Array1::zeros(n.max(1));
+        e_ext.slice_mut(s![..n.saturating_sub(1)]).assign(&e);
+        let uv_dim = if compq == 'I' { n.max(1) } else { 1 };
+        let u = Array2::zeros((uv_dim, uv_dim).f());
+        let vt = Array2::zeros((uv_dim, uv_dim).f());
+
+        // 3. buffer alloc
+        let lwork = if compq == 'I' { 3 * n * n + 4 * n } else { 4 * n };
+        let work = Array1::zeros(lwork.max(1));
+        let iwork = Array1::zeros((8 * n).max(1));
+
+        // 4. struct build
+        let mut driver = BDSDC_Driver {
+            uplo: uplo as c_char,
+            compq: compq as c_char,
+            n: n.try_into()?,
+            d,
+            e: e_ext,
+            u,
+            ldu: uv_dim.try_into()?,
+            vt,
+            ldvt: uv_dim.try_into()?,
+            q: Array1::zeros(1),
+            iq: Array1::zeros(1),
+            work,
+            iwork,
+            info: 0,
+        };
+
+        // 5. perform comput
+        let result = unsafe { F::run_bdsdc(&mut driver) };
+        bdsdc_check_info(result)?;
+
+        // 6. finalize
+        let BDSDC_Driver { d, u, vt, .. } = driver;
+        let (u, vt) = match compq {
+            'I' => (Some(u.slice_move(s![..n, ..n])), Some(vt.slice_move(s![..n, ..n]))),
+            _ => (None, None),
+        };
+        return Ok(BDSDC_Output { s: d, u, vt });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type BDSDC<'d, 'e, F> = BDSDC_Builder<'d, 'e, F>;
+pub type SBDSDC<'d, 'e> = BDSDC<'d, 'e, f32>;
+pub type DBDSDC<'d, 'e> = BDSDC<'d, 'e, f64>;
+
+impl<F> BDSDC_Builder<'_, '_, F>
+where
+    F: BDSDCNum,
+{
+    pub fn run(self) -> Result<BDSDC_Output<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,301 @@
+extern crate alloc;
+
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use alloc::string::String;
+use core::fmt::Write;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct BDSQR_Driver<'vt, 'u, 'c, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    n: lapack_int,
+    ncvt: lapack_int,
+    nru: lapack_int,
+    ncc: lapack_int,
+    d: Array1<F::RealFloat>,
+    e: Array1<F::RealFloat>,
+    vt: ArrayOut2<'vt, F>,
+    ldvt: lapack_int,
+    u: ArrayOut2<'u, F>,
+    ldu: lapack_int,
+    c: ArrayOut2<'c, F>,
+    ldc: lapack_int,
+    work: Array1<F>,
+    rwork: Array1<F::RealFloat>,
+    info: lapack_int,
+}
+
+pub trait BDSQRNum: LapackFloat {
+    unsafe fn run_bdsqr(driver: &mut BDSQR_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl BDSQRNum for $type {
+            unsafe fn run_bdsqr(driver: &mut BDSQR_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.ncvt,
+                    &driver.nru,
+                    &driver.ncc,
+                    driver.d.as_mut_ptr(),
+                    driver.e.as_mut_ptr(),
+                    driver.vt.get_data_mut_ptr(),
+                    &driver.ldvt,
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.c.get_data_mut_ptr(),
+                    &driver.ldc,
+                    driver.work.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl BDSQRNum for $type {
+            unsafe fn run_bdsqr(driver: &mut BDSQR_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.n,
+                    &driver.ncvt,
+                    &driver.nru,
+                    &driver.ncc,
+                    driver.d.as_mut_ptr(),
+                    driver.e.as_mut_ptr(),
+                    driver.vt.get_data_mut_ptr(),
+                    &driver.ldvt,
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.c.get_data_mut_ptr(),
+                    &driver.ldc,
+                    driver.rwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sbdsqr_);
+impl_func_real!(f64, dbdsqr_);
+impl_func_comp!(c32, cbdsqr_);
+impl_func_comp!(c64, zbdsqr_);
+
+/* #endregion */
+
+/* #region error */
+
+/// Translate positive `info` of `BDSQR` into readable error, carrying the unconverged superdiagonal `e`.
+fn bdsqr_check_info<F>(result: Result<(), LapackError>, driver: &BDSQR_Driver<F>) -> Result<(), LapackError>
+where
+    F: LapackFloat,
+{
+    match result {
+        Err(LapackError::Info(info)) if info > 0 => {
+            let mut s = String::from(concat!(file!(), ":", line!(), ": ", "LapackError::FailedCheck", " : "));
+            write!(
+                s,
+                "{info} superdiagonals of bidiagonal matrix did not converge to zero, e = {:?}",
+                driver.e.to_vec()
+            )
+            .unwrap();
+            Err(LapackError::FailedCheck(s))
+        },
+        _ => result,
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`BDSQR`].
+#[derive(Debug)]
+pub struct BDSQR_Output<'vt, 'u, 'c, F>
+where
+    F: LapackFloat,
+{
+    /// Singular values of `B` in decreasing order, of length `n`.
+    pub s: Array1<F::RealFloat>,
+    /// Input `vt` overwritten by `Pᴴ VT`.
+    pub vt: Option<ArrayOut2<'vt, F>>,
+    /// Input `u` overwritten by `U Q`.
+    pub u: Option<ArrayOut2<'u, F>>,
+    /// Input `c` overwritten by `Qᴴ C`.
+    pub c: Option<ArrayOut2<'c, F>>,
+}
+
+/// Singular value decomposition `B = Q Σ Pᴴ` of real bidiagonal matrix by implicit zero-shift QR iteration.
+///
+/// Bidiagonal `B` of order `n` has diagonal `d` and off-diagonal `e` (of length `n - 1`), which is superdiagonal
+/// for `uplo = 'U'` (default) or subdiagonal for `uplo = 'L'`.
+///
+/// Optionally, `vt` of shape `(n, ncvt)` is overwritten by `Pᴴ VT`, `u` of shape `(nru, n)` by `U Q`, and `c` of
+/// shape `(n, ncc)` by `Qᴴ C`. If `B` comes from `GEBRD` of `A = Q_A B P_Aᴴ`, giving `u` as `Q_A` and `vt` as
+/// `P_Aᴴ` results in SVD of `A`; giving identity matrices results in singular vectors of `B` itself.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct BDSQR_<'d, 'e, 'vt, 'u, 'c, F>
+where
+    F: BDSQRNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub d: ArrayView1<'d, F::RealFloat>,
+    #[builder(setter(into))]
+    pub e: ArrayView1<'e, F::RealFloat>,
+
+    // input/output
+    #[builder(setter(into, strip_option), default = "None")]
+    pub vt: Option<ArrayViewOrMut2<'vt, F>>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub u: Option<ArrayViewOrMut2<'u, F>>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub c: Option<ArrayViewOrMut2<'c, F>>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+}
+
+impl<'d, 'e, 'vt, 'u, 'c, F> BDSQR_<'d, 'e, 'vt, 'u, 'c, F>
+where
+    F: BDSQRNum,
+{
+    pub fn driver(self) -> Result<BDSQR_Output<'vt, 'u, 'c, F>, LapackError> {
+        let Self { d, e, vt, u, c, uplo } = self;
+
+        // 1. dim assign, flag check
+        let n = d.len();
+        let uplo = uplo.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        let (has_vt, has_u, has_c) = (vt.is_some(), u.is_some(), c.is_some());
+        let vt = match vt {
+            Some(vt) => vt.into_col_array_out(),
+            None => ArrayOut::Owned(Array2::zeros((1, 1).f())),
+        };
+        let u = match u {
+            Some(u) => u.into_col_array_out(),
+            None => ArrayOut::Owned(Array2::zeros((1, 1).f())),
+        };
+        let c = match c {
+            Some(c) => c.into_col_array_out(),
+            None => ArrayOut::Owned(Array2::zeros((1, 1).f())),
+        };
+
+        // 2. dim check & alloc
+        lapack_assert_eq!(e.len(), n.saturating_sub(1), InvalidDim)?;
+        let ncvt = if has_vt { vt.view().ncols() } else { 0 };
+        let nru = if has_u { u.view().nrows() } else { 0 };
+        let ncc = if has_c { c.view().ncols() } else { 0 };
+        if has_vt {
+            lapack_assert_eq!(vt.view().nrows(), n, InvalidDim)?;
+        }
+        if has_u {
+            lapack_assert_eq!(u.view().ncols(), n, InvalidDim)?;
+        }
+        if has_c {
+            lapack_assert_eq!(c.view().nrows(), n, InvalidDim)?;
+        }
+        let ldvt = vt.view().stride_of(Axis(1)).max(vt.view().nrows().max(1) as isize);
+        let ldu = u.view().stride_of(Axis(1)).max(u.view().nrows().max(1) as isize);
+        let ldc = c.view().stride_of(Axis(1)).max(c.view().nrows().max(1) as isize);
+        let d = d.to_owned();
+        let mut e_ext = Array1::zeros(n.max(1));
+        e_ext.slice_mut(s![..n.saturating_sub(1)]).assign(&e);
+
+        // 3. buffer alloc
+        let work = Array1::zeros(if F::is_complex() { 1 } else { (4 * n).max(1) });
+        let rwork = Array1::zeros(if F::is_complex() { (4 * n).max(1) } else { 1 });
+
+        // 4. struct build
+        let mut driver = BDSQR_Driver {
+            uplo: uplo as c_char,
+            n: n.try_into()?,
+            ncvt: ncvt.try_into()?,
+            nru: nru.try_into()?,
+            ncc: ncc.try_into()?,
+            d,
+            e: e_ext,
+            vt,
+            ldvt: ldvt.try_into()?,
+            u,
+            ldu: ldu.try_into()?,
+            c,
+            ldc: ldc.try_into()?,
+            work,
+            rwork,
+            info: 0,
+        };
+
+        // 5. perform comput
+        let result = unsafe { F::run_bdsqr(&mut driver) };
+        bdsqr_check_info(result, &driver)?;
+
+        // 6. finalize
+        let BDSQR_Driver { d, vt, u, c, .. } = driver;
+        return Ok(BDSQR_Output {
+            s: d,
+            vt: has_vt.then(|| vt.clone_to_view_mut()),
+            u: has_u.then(|| u.clone_to_view_mut()),
+            c: has_c.then(|| c.clone_to_view_mut()),
+        });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+/// Owned output (copied from non-F-contiguous input) is returned in row-major layout.
+fn into_input_layout<F>(x: ArrayOut2<'_, F>, fpref: bool) -> ArrayOut2<'_, F>
+where
+    F: LapackFloat,
+{
+    match x {
+        ArrayOut::Owned(x) if !fpref => ArrayOut::Owned(x.as_standard_layout().into_owned()),
+        _ => x,
+    }
+}
+
+pub type BDSQR<'d, 'e, 'vt, 'u, 'c, F> = BDSQR_Builder<'d, 'e, 'vt, 'u, 'c, F>;
+pub type SBDSQR<'d, 'e, 'vt, 'u, 'c> = BDSQR<'d, 'e, 'vt, 'u, 'c, f32>;
+pub type DBDSQR<'d, 'e, 'vt, 'u, 'c> = BDSQR<'d, 'e, 'vt, 'u, 'c, f64>;
+pub type CBDSQR<'d, 'e, 'vt, 'u, 'c> = BDSQR<'d, 'e, 'vt, 'u, 'c, c32>;
+pub type ZBDSQR<'d, 'e, 'vt, 'u, 'c> = BDSQR<'d, 'e, 'vt, 'u, 'c, c64>;
+
+impl<'d, 'e, 'vt, 'u, 'c, F> BDSQR_Builder<'d, 'e, 'vt, 'u, 'c, F>
+where
+    F: BDSQRNum,
+{
+    pub fn run(self) -> Result<BDSQR_Output<'vt, 'u, 'c, F>, LapackError> {
+        let obj = self.build()?;
+        let is_fpref = |x: &Option<ArrayViewOrMut2<F>>| match x {
+            Some(x) => x.view().is_fpref(),
+            None => true,
+        };
+        let layout = (is_fpref(&obj.vt), is_fpref(&obj.u), is_fpref(&obj.c));
+        let BDSQR_Output { s, vt, u, c } = obj.driver()?;
+        let vt = vt.map(|vt| into_input_layout(vt, layout.0));
+        let u = u.map(|u| into_input_layout(u, layout.1));
+        let c = c.map(|c| into_input_layout(c, layout.2));
+        return Ok(BDSQR_Output { s, vt, u, c });
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,258 @@
+extern crate alloc;
+
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use alloc::string::String;
+use core::fmt::Write;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct BDSVDX_Driver<'d, 'e, F>
+where
+    F: LapackFloat,
+{
+    uplo: c_char,
+    jobz: c_char,
+    range: c_char,
+    n: lapack_int,
+    d: ArrayView1<'d, F>,
+    e: ArrayView1<'e, F>,
+    vl: F,
+    vu: F,
+    il: lapack_int,
+    iu: lapack_int,
+    ns: lapack_int,
+    s: Array1<F>,
+    z: Array2<F>,
+    ldz: lapack_int,
+    work: Array1<F>,
+    iwork: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait BDSVDXNum: LapackFloat + PartialOrd {
+    unsafe fn run_bdsvdx(driver: &mut BDSVDX_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl BDSVDXNum for $type {
+            unsafe fn run_bdsvdx(driver: &mut BDSVDX_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.uplo,
+                    &driver.jobz,
+                    &driver.range,
+                    &driver.n,
+                    driver.d.as_ptr(),
+                    driver.e.as_ptr(),
+                    &driver.vl,
+                    &driver.vu,
+                    &driver.il,
+                    &driver.iu,
+                    &mut driver.ns,
+                    driver.s.as_mut_ptr(),
+                    driver.z.as_mut_ptr(),
+                    &driver.ldz,
+                    driver.work.as_mut_ptr(),
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sbdsvdx_);
+impl_func!(f64, dbdsvdx_);
+
+/* #endregion */
+
+/* #region error */
+
+/// Translate positive `info` of `BDSVDX` into readable error, carrying the indices of singular vectors that failed
+/// to converge (stored in `iwork`).
+fn bdsvdx_check_info<F>(result: Result<(), LapackError>, driver: &BDSVDX_Driver<F>) -> Result<(), LapackError>
+where
+    F: LapackFloat,
+{
+    match result {
+        Err(LapackError::Info(info)) if info > 0 => {
+            let mut s = String::from(concat!(file!(), ":", line!(), ": ", "LapackError::FailedCheck", " : "));
+            if info > 2 * driver.n as i64 {
+                write!(s, "internal error in BDSVDX (info = {info})").unwrap();
+            } else {
+                let failed = driver.iwork.slice(s![..(info as usize).min(driver.iwork.len())]).to_vec();
+                write!(s, "{info} singular vectors failed to converge in BDSVDX, indices = {failed:?}")
+                    .unwrap();
+            }
+            Err(LapackError::FailedCheck(s))
+        },
+        _ => result,
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`BDSVDX`].
+///
+/// Only the `ns` computed singular triplets are returned, where `ns` is the length of `s`.
+#[derive(Debug, Clone)]
+pub struct BDSVDX_Output<F> {
+    /// Selected singular values of `B` in decreasing order, of length `ns`.
+    pub s: Array1<F>,
+    /// Left singular vectors of `B`, of shape `(n, ns)`, for `jobz = 'V'`.
+    pub u: Option<Array2<F>>,
+    /// Right singular vectors (transposed) of `B`, of shape `(ns, n)`, for `jobz = 'V'`.
+    pub vt: Option<Array2<F>>,
+}
+
+/// Selected singular values and vectors of real bidiagonal matrix by bisection and inverse iteration on the
+/// associated Golub-Kahan tridiagonal matrix.
+///
+/// Bidiagonal `B` of order `n` has diagonal `d` and off-diagonal `e` (of length `n - 1`), which is superdiagonal
+/// for `uplo = 'U'` (default) or subdiagonal for `uplo = 'L'`. Option `jobz` is `'V'` (default) or `'N'`.
+///
+/// Option `range` could be
+/// - `'A'`: all singular values (default);
+/// - `'V'`: singular values in half-open interval `(vl, vu]`, where `0 <= vl < vu`;
+/// - `'I'`: the `il`-th through `iu`-th largest singular values, 1-based and inclusive as in LAPACK, where
+///   `1 <= il <= iu <= n`.
+///
+/// This routine is only available for real types.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct BDSVDX_<'d, 'e, F>
+where
+    F: BDSVDXNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub d: ArrayView1<'d, F>,
+    #[builder(setter(into))]
+    pub e: ArrayView1<'e, F>,
+
+    // option
+    #[builder(default = "'U'")]
+    pub uplo: char,
+    #[builder(default = "'V'")]
+    pub jobz: char,
+    #[builder(default = "'A'")]
+    pub range: char,
+    #[builder(default = "F::zero()")]
+    pub vl: F,
+    #[builder(default = "F::zero()")]
+    pub vu: F,
+    #[builder(default = "1")]
+    pub il: usize,
+    #[builder(default = "1")]
+    pub iu: usize,
+}
+
+impl<'d, 'e, F> BDSVDX_<'d, 'e, F>
+where
+    F: BDSVDXNum,
+{
+    pub fn driver(self) -> Result<BDSVDX_Output<F>, LapackError> {
+        let Self { d, e, uplo, jobz, range, vl, vu, il, iu } = self;
+
+        // 1. dim assign, flag check
+        let n = d.len();
+        let uplo = uplo.to_ascii_uppercase();
+        let jobz = jobz.to_ascii_uppercase();
+        let range = range.to_ascii_uppercase();
+        lapack_check_flag!(uplo, ['U', 'L'])?;
+        lapack_check_flag!(jobz, ['V', 'N'])?;
+        lapack_check_flag!(range, ['A', 'V', 'I'])?;
+
+        // 2. dim check & alloc
+        lapack_assert_eq!(e.len(), n.saturating_sub(1), InvalidDim)?;
+        let ns_max = match range {
+            'V' => {
+                lapack_assert!(vl >= F::zero() && vl < vu, InvalidFlag)?;
+                n
+            },
+            'I' => {
+                lapack_assert!(1 <= il && il <= iu && iu <= n, InvalidDim)?;
+                iu - il + 1
+            },
+            _ => n,
+        };
+        let (il, iu) = if range == 'I' { (il, iu) } else { (1, 1) };
+        let d = d.to_seq_layout()?;
+        let mut e_ext = Array1::zeros(n.max(1));
+        e_ext.slice_mut(s![..n.saturating_sub(1)]).assign(&e);
+        let s = Array1::zeros(n.max(1));
+        // one extra column of `z` is required by LAPACK
+        let z_dim = if jobz == 'V' { ((2 * n).max(1), ns_max + 1) } else { (1, 1) };
+        let z = Array2::zeros(z_dim.f());
+
+        // 3. buffer alloc
+        let work = Array1::zeros((14 * n).max(1));
+        let iwork = Array1::zeros((12 * n).max(1));
+
+        // 4. struct build
+        let mut driver = BDSVDX_Driver {
+            uplo: uplo as c_char,
+            jobz: jobz as c_char,
+            range: range as c_char,
+            n: n.try_into()?,
+            d: d.view(),
+            e: e_ext.view(),
+            vl,
+            vu,
+            il: il.try_into()?,
+            iu: iu.try_into()?,
+            ns: 0,
+            s,
+            z,
+            ldz: z_dim.0.try_into()?,
+            work,
+            iwork,
+            info: 0,
+        };
+
+        // 5. perform comput
+        let result = unsafe { F::run_bdsvdx(&mut driver) };
+        bdsvdx_check_info(result, &driver)?;
+
+        // 6. finalize
+        let BDSVDX_Driver { ns, s, z, .. } = driver;
+        let ns = ns as usize;
+        lapack_assert_le!(ns, ns_max, RuntimeError)?;
+        let s = s.slice(s![..ns]).into_owned();
+        let (u, vt) = match jobz {
+            'V' => {
+                let u = z.slice(s![..n, ..ns]).into_owned();
+                let vt = z.slice(s![n.., ..ns]).t().as_standard_layout().into_owned();
+                (Some(u), Some(vt))
+            },
+            _ => (None, None),
+        };
+        return Ok(BDSVDX_Output { s, u, vt });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type BDSVDX<'d, 'e, F> = BDSVDX_Builder<'d, 'e, F>;
+pub type SBDSVDX<'d, 'e> = BDSVDX<'d, 'e, f32>;
+pub type DBDSVDX<'d, 'e> = BDSVDX<'d, 'e, f64>;
+
+impl<'d, 'e, F> BDSVDX_Builder<'d, 'e, F>
+where
+    F: BDSVDXNum,
+{
+    pub fn run(self) -> Result<BDSVDX_Output<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,139 @@
+use crate::singular_value::{ORGBRNum, ORMBRNum, ORGBR, ORMBR};
+use crate::util::*;
+use ndarray::prelude::*;
+
+/// Bidiagonal reduction `A = Q B Pᴴ` of `(m, n)` matrix, as computed by `GEBRD`.
+///
+/// With `k = min(m, n)`, real bidiagonal `B` of order `k` has diagonal `d` and off-diagonal `e` (of length
+/// `k - 1`), which is superdiagonal if `m >= n` and subdiagonal if `m < n`. Array `a` holds the elementary
+/// reflectors defining `Q` (with `tauq`) below the diagonal, and `P` (with `taup`) above the diagonal.
+#[derive(Debug, Clone)]
+pub struct BidiagonalFactorization<F>
+where
+    F: LapackFloat,
+{
+    pub a: Array2<F>,
+    pub d: Array1<F::RealFloat>,
+    pub e: Array1<F::RealFloat>,
+    pub tauq: Array1<F>,
+    pub taup: Array1<F>,
+}
+
+impl<F> BidiagonalFactorization<F>
+where
+    F: LapackFloat,
+{
+    /// Whether `B` is upper (`'U'`, `m >= n`) or lower (`'L'`, `m < n`) bidiagonal; to be passed to bidiagonal
+    /// SVD routines such as `BDSQR`.
+    pub fn uplo(&self) -> char {
+        let (m, n) = self.a.dim();
+        if m >= n {
+            'U'
+        } else {
+            'L'
+        }
+    }
+
+    /// Bidiagonal factor `B` of shape `(k, k)`.
+    pub fn b(&self) -> Array2<F> {
+        let k = self.d.len();
+        let mut b = Array2::zeros((k, k));
+        for (i, &d) in self.d.iter().enumerate() {
+            b[[i, i]] = F::from_real(d);
+        }
+        for (i, &e) in self.e.iter().enumerate() {
+            match self.uplo() {
+                'U' => b[[i, i + 1]] = F::from_real(e),
+                _ => b[[i + 1, i]] = F::from_real(e),
+            }
+        }
+        return b;
+    }
+}
+
+impl<F> BidiagonalFactorization<F>
+where
+    F: ORGBRNum + ORMBRNum,
+{
+    /// Economy-size `Q` of shape `(m, k)`, with orthonormal columns.
+    pub fn q(&self) -> Result<Array2<F>, LapackError> {
+        self.q_economy()
+    }
+
+    /// Economy-size `Q` of shape `(m, k)`, with orthonormal columns.
+    pub fn q_economy(&self) -> Result<Array2<F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let k = m.min(n);
+        let mut q = Array2::zeros((m, k).f());
+        q.assign(&self.a.slice(s![.., ..k]));
+        ORGBR::default().a(q.view_mut()).tau(self.tauq.view()).k(n).vect('Q').run()?;
+        return Ok(q);
+    }
+
+    /// Complete unitary (orthogonal) `Q` of shape `(m, m)`.
+    pub fn q_complete(&self) -> Result<Array2<F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let k = m.min(n);
+        let mut q = Array2::zeros((m, m).f());
+        q.slice_mut(s![.., ..k]).assign(&self.a.slice(s![.., ..k]));
+        ORGBR::default().a(q.view_mut()).tau(self.tauq.view()).k(n).vect('Q').run()?;
+        return Ok(q);
+    }
+
+    /// Economy-size `Pᴴ` of shape `(k, n)`, with orthonormal rows.
+    pub fn pt(&self) -> Result<Array2<F>, LapackError> {
+        self.pt_economy()
+    }
+
+    /// Economy-size `Pᴴ` of shape `(k, n)`, with orthonormal rows.
+    pub fn pt_economy(&self) -> Result<Array2<F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let k = m.min(n);
+        let mut pt = Array2::zeros((k, n).f());
+        pt.assign(&self.a.slice(s![..k, ..]));
+        ORGBR::default().a(pt.view_mut()).tau(self.taup.view()).k(m).vect('P').run()?;
+        return Ok(pt);
+    }
+
+    /// Complete unitary (orthogonal) `Pᴴ` of shape `(n, n)`.
+    pub fn pt_complete(&self) -> Result<Array2<F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let k = m.min(n);
+        let mut pt = Array2::zeros((n, n).f());
+        pt.slice_mut(s![..k, ..]).assign(&self.a.slice(s![..k, ..]));
+        ORGBR::default().a(pt.view_mut()).tau(self.taup.view()).k(m).vect('P').run()?;
+        return Ok(pt);
+    }
+
+    /// Multiply `c` by `Q` without forming it: `op(Q) C` (`side = 'L'`) or `C op(Q)` (`side = 'R'`), with `trans`
+    /// in `'N'`, `'T'` (real) or `'C'`.
+    ///
+    /// Here `Q` is the complete `(m, m)` matrix; so `c` should have `m` rows for `side = 'L'`, or `m` columns for
+    /// `side = 'R'`.
+    pub fn apply_q<'c>(
+        &self,
+        side: char,
+        trans: char,
+        c: impl Into<ArrayViewOrMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let a = self.a.slice(s![.., ..m.min(n)]);
+        ORMBR::default().a(a).tau(self.tauq.view()).c(c).k(n).vect('Q').side(side).trans(trans).run()
+    }
+
+    /// Multiply `c` by `P` without forming it: `op(P) C` (`side = 'L'`) or `C op(P)` (`side = 'R'`), with `trans`
+    /// in `'N'`, `'T'` (real) or `'C'`.
+    ///
+    /// Here `P` is the complete `(n, n)` matrix; so `c` should have `n` rows for `side = 'L'`, or `n` columns for
+    /// `side = 'R'`.
+    pub fn apply_p<'c>(
+        &self,
+        side: char,
+        trans: char,
+        c: impl Into<ArrayViewOrMut2<'c, F>>,
+    ) -> Result<ArrayOut2<'c, F>, LapackError> {
+        let (m, n) = self.a.dim();
+        let a = self.a.slice(s![..m.min(n), ..]);
+        ORMBR::default().a(a).tau(self.taup.view()).c(c).k(m).vect('P').side(side).trans(trans).run()
+    }
+}
@@ -0,0 +1,162 @@
+use crate::ffi::{self, lapack_int};
+use crate::singular_value::bidiagonal_factorization::BidiagonalFactorization;
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GEBRD_Driver<'a, 'work, F>
+where
+    F: LapackFloat,
+{
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    d: Array1<F::RealFloat>,
+    e: Array1<F::RealFloat>,
+    tauq: Array1<F>,
+    taup: Array1<F>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GEBRDNum: LapackFloat {
+    unsafe fn run_gebrd(driver: &mut GEBRD_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GEBRDNum for $type {
+            unsafe fn run_gebrd(driver: &mut GEBRD_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.d.as_mut_ptr(),
+                    driver.e.as_mut_ptr(),
+                    driver.tauq.as_mut_ptr(),
+                    driver.taup.as_mut_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgebrd_);
+impl_func!(f64, dgebrd_);
+impl_func!(c32, cgebrd_);
+impl_func!(c64, zgebrd_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Reduction of general matrix to real bidiagonal form `A = Q B Pᴴ`.
+///
+/// `B` is upper bidiagonal if `m >= n`, and lower bidiagonal if `m < n`. If `a` is given as mutable view, it is
+/// also overwritten in place by the reflectors, in the same way as LAPACK.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GEBRD_<'a, 'work, F>
+where
+    F: GEBRDNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'work, F> GEBRD_<'a, 'work, F>
+where
+    F: GEBRDNum,
+{
+    pub fn driver(self) -> Result<BidiagonalFactorization<F>, LapackError> {
+        let Self { a, work } = self;
+
+        // 1. dim assign
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let k = m.min(n);
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 2. alloc
+        let d = Array1::zeros(k);
+        let e = Array1::zeros(k.max(1));
+        let tauq = Array1::zeros(k);
+        let taup = Array1::zeros(k);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, m.max(n).max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GEBRD_Driver {
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            d,
+            e,
+            tauq,
+            taup,
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_gebrd(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_gebrd(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GEBRD_Driver { a, d, e, tauq, taup, .. } = driver;
+        let a = a.clone_to_view_mut().into_owned();
+        let e = e.slice_move(s![..k.saturating_sub(1)]);
+        return Ok(BidiagonalFactorization { a, d, e, tauq, taup });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GEBRD<'a, 'work, F> = GEBRD_Builder<'a, 'work, F>;
+pub type SGEBRD<'a, 'work> = GEBRD<'a, 'work, f32>;
+pub type DGEBRD<'a, 'work> = GEBRD<'a, 'work, f64>;
+pub type CGEBRD<'a, 'work> = GEBRD<'a, 'work, c32>;
+pub type ZGEBRD<'a, 'work> = GEBRD<'a, 'work, c64>;
+
+impl<'a, 'work, F> GEBRD_Builder<'a, 'work, F>
+where
+    F: GEBRDNum,
+{
+    pub fn run(self) -> Result<BidiagonalFactorization<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
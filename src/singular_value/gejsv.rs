@@ -0,0 +1,375 @@
+extern crate alloc;
+
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use alloc::string::String;
+use core::fmt::Write;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GEJSV_Driver<'a, 'u, 'v, F>
+where
+    F: LapackFloat,
+{
+    joba: c_char,
+    jobu: c_char,
+    jobv: c_char,
+    jobr: c_char,
+    jobt: c_char,
+    jobp: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    sva: Array1<F::RealFloat>,
+    u: ArrayOut2<'u, F>,
+    ldu: lapack_int,
+    v: ArrayOut2<'v, F>,
+    ldv: lapack_int,
+    work: Array1<F>,
+    lwork: lapack_int,
+    rwork: Array1<F::RealFloat>,
+    lrwork: lapack_int,
+    iwork: Array1<lapack_int>,
+    /// The 7 statistics of `work` (real) or `rwork` (complex) on exit.
+    stat: Array1<F::RealFloat>,
+    info: lapack_int,
+}
+
+pub trait GEJSVNum: LapackFloat {
+    unsafe fn run_gejsv(driver: &mut GEJSV_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl GEJSVNum for $type {
+            unsafe fn run_gejsv(driver: &mut GEJSV_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.joba,
+                    &driver.jobu,
+                    &driver.jobv,
+                    &driver.jobr,
+                    &driver.jobt,
+                    &driver.jobp,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.sva.as_mut_ptr(),
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.v.get_data_mut_ptr(),
+                    &driver.ldv,
+                    driver.work.as_mut_ptr(),
+                    &driver.lwork,
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                driver.stat.assign(&driver.work.slice(s![..7]));
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl GEJSVNum for $type {
+            unsafe fn run_gejsv(driver: &mut GEJSV_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.joba,
+                    &driver.jobu,
+                    &driver.jobv,
+                    &driver.jobr,
+                    &driver.jobt,
+                    &driver.jobp,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.sva.as_mut_ptr(),
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.v.get_data_mut_ptr(),
+                    &driver.ldv,
+                    driver.work.as_mut_ptr(),
+                    &driver.lwork,
+                    driver.rwork.as_mut_ptr(),
+                    &driver.lrwork,
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                driver.stat.assign(&driver.rwork.slice(s![..7]));
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sgejsv_);
+impl_func_real!(f64, dgejsv_);
+impl_func_comp!(c32, cgejsv_);
+impl_func_comp!(c64, zgejsv_);
+
+/* #endregion */
+
+/* #region error */
+
+/// Translate positive `info` of `GEJSV` (no convergence of Jacobi sweeps) into readable error.
+fn gejsv_check_info(result: Result<(), LapackError>) -> Result<(), LapackError> {
+    match result {
+        Err(LapackError::Info(info)) if info > 0 => {
+            let mut s = String::from(concat!(file!(), ":", line!(), ": ", "LapackError::FailedCheck", " : "));
+            write!(s, "GEJSV did not converge in the maximal allowed number of sweeps (info = {info})")
+                .unwrap();
+            Err(LapackError::FailedCheck(s))
+        },
+        _ => result,
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`GEJSV`].
+///
+/// Singular values are `scale * sva` (see [`GEJSV_Output::s`]), in decreasing order. Statistics are parsed from
+/// `work` (real) or `rwork` (complex), and `iwork` on exit.
+#[derive(Debug)]
+pub struct GEJSV_Output<'u, 'v, F>
+where
+    F: LapackFloat,
+{
+    /// Scaled singular values, of length `n`.
+    pub sva: Array1<F::RealFloat>,
+    /// Left singular vectors, of shape `(m, n)` for `jobu = 'U'` or `(m, m)` for `jobu = 'F'`.
+    pub u: Option<ArrayOut2<'u, F>>,
+    /// Right singular vectors `V` (not conjugate transposed) of shape `(n, n)`, for `jobv = 'V'` or `'J'`.
+    pub v: Option<ArrayOut2<'v, F>>,
+    /// Scaling factor, such that singular values are `scale * sva`.
+    pub scale: F::RealFloat,
+    /// Estimate of `sqrt(‖(Rᴴ R)⁻¹‖₁)` of column equilibrated `A` (`joba = 'E'` or `'G'`); `-1` if numerical
+    /// rank is smaller than `n` and the smallest singular values might be lost.
+    pub sconda: F::RealFloat,
+    /// Estimate of scaled condition number of triangular factor in the first QR factorization.
+    pub cond_r1: F::RealFloat,
+    /// Estimate of scaled condition number of triangular factor in the second QR factorization.
+    pub cond_r2: F::RealFloat,
+    /// Shannon entropies of `diag(Aᴴ A) / tr(Aᴴ A)` and `diag(A Aᴴ) / tr(A Aᴴ)` (`jobt = 'T'`).
+    pub entropy: Option<(F::RealFloat, F::RealFloat)>,
+    /// Numerical rank determined after the initial QR factorization with pivoting.
+    pub rank: usize,
+    /// Number of computed nonzero singular values.
+    pub n_nonzero: usize,
+    /// Some column norms of `A` were denormalized floats, so high accuracy is not warranted by data.
+    pub denormalized: bool,
+    /// The procedure worked on `Aᴴ` (`jobt = 'T'`). Only reported by complex routines (`CGEJSV`, `ZGEJSV`);
+    /// `None` for real types.
+    pub transposed: Option<bool>,
+}
+
+impl<F> GEJSV_Output<'_, '_, F>
+where
+    F: LapackFloat,
+{
+    /// Singular values `scale * sva`.
+    pub fn s(&self) -> Array1<F::RealFloat> {
+        self.sva.mapv(|x| x * self.scale)
+    }
+}
+
+/// Singular value decomposition `A = U Σ Vᴴ` of general matrix (`m >= n`) by preconditioned one-sided Jacobi
+/// method, with high relative accuracy.
+///
+/// - `joba`: accuracy level and rank determination, `'C'` (default), `'E'`, `'F'`, `'G'`, `'A'` or `'R'`.
+/// - `jobu`: `'U'` (`n` left singular vectors, default), `'F'` (full `U`), `'W'` (`U` used as internal workspace,
+///   only with `jobv = 'V'` or `'J'`), or `'N'`.
+/// - `jobv`: `'V'` (default), `'J'` (by Jacobi rotations accumulation, only with `jobu = 'U'` or `'F'`), `'W'`
+///   (internal workspace, only with `jobu = 'U'` or `'F'`), or `'N'`.
+/// - `jobr`: `'R'` (restrict range of singular values to avoid underflow, default) or `'N'`.
+/// - `jobt`: `'T'` (transpose if `Aᴴ` is expected to converge faster) or `'N'` (default).
+/// - `jobp`: `'P'` (perturb tiny entries to avoid denormals) or `'N'` (default).
+///
+/// Matrix `a` is destroyed on exit.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GEJSV_<'a, 'u, 'v, F>
+where
+    F: GEJSVNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // output
+    #[builder(setter(strip_option), default = "None")]
+    pub u: Option<ArrayViewMut2<'u, F>>,
+    #[builder(setter(strip_option), default = "None")]
+    pub v: Option<ArrayViewMut2<'v, F>>,
+
+    // option
+    #[builder(default = "'C'")]
+    pub joba: char,
+    #[builder(default = "'U'")]
+    pub jobu: char,
+    #[builder(default = "'V'")]
+    pub jobv: char,
+    #[builder(default = "'R'")]
+    pub jobr: char,
+    #[builder(default = "'N'")]
+    pub jobt: char,
+    #[builder(default = "'N'")]
+    pub jobp: char,
+}
+
+impl<'a, 'u, 'v, F> GEJSV_<'a, 'u, 'v, F>
+where
+    F: GEJSVNum,
+{
+    pub fn driver(self) -> Result<GEJSV_Output<'u, 'v, F>, LapackError> {
+        let Self { a, u, v, joba, jobu, jobv, jobr, jobt, jobp } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let joba = joba.to_ascii_uppercase();
+        let jobu = jobu.to_ascii_uppercase();
+        let jobv = jobv.to_ascii_uppercase();
+        let jobr = jobr.to_ascii_uppercase();
+        let jobt = jobt.to_ascii_uppercase();
+        let jobp = jobp.to_ascii_uppercase();
+        lapack_check_flag!(joba, ['C', 'E', 'F', 'G', 'A', 'R'])?;
+        lapack_check_flag!(jobu, ['U', 'F', 'W', 'N'])?;
+        lapack_check_flag!(jobv, ['V', 'J', 'W', 'N'])?;
+        lapack_check_flag!(jobr, ['N', 'R'])?;
+        lapack_check_flag!(jobt, ['T', 'N'])?;
+        lapack_check_flag!(jobp, ['P', 'N'])?;
+        lapack_assert!(jobu != 'W' || ['V', 'J'].contains(&jobv), InvalidFlag)?;
+        lapack_assert!(!['J', 'W'].contains(&jobv) || ['U', 'F'].contains(&jobu), InvalidFlag)?;
+
+        // 2. dim check & alloc
+        lapack_assert_le!(n, m, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let u_dim = match jobu {
+            'U' | 'W' => (m, n),
+            'F' => (m, m),
+            _ => (1, 1),
+        };
+        let u = match jobu {
+            'U' | 'F' => ArrayOut2::optional_alloc_fpref(u, u_dim, true)?,
+            _ => {
+                lapack_assert!(u.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros(u_dim.f()))
+            },
+        };
+        let v = match jobv {
+            'V' | 'J' => ArrayOut2::optional_alloc_fpref(v, (n, n), true)?,
+            'W' => {
+                lapack_assert!(v.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros((n, n).f()))
+            },
+            _ => {
+                lapack_assert!(v.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros((1, 1).f()))
+            },
+        };
+        let ldu = u.view().stride_of(Axis(1)).max(u.view().nrows().max(1) as isize);
+        let ldv = v.view().stride_of(Axis(1)).max(v.view().nrows().max(1) as isize);
+        let sva = Array1::zeros(n);
+
+        // 3. buffer check & alloc
+        // workspace query is not supported by real GEJSV, so documented minimum sizes (over all job paths) are
+        // allocated
+        let lwork = (2 * m + n).max(6 * n + 2 * n * n).max(n * n + 2 * n + 6).max(7);
+        let lrwork = if F::is_complex() { (2 * m + n).max(7) } else { 1 };
+        let iwork = Array1::zeros((m + 3 * n).max(4));
+
+        // 4. struct build
+        let mut driver = GEJSV_Driver {
+            joba: joba as c_char,
+            jobu: jobu as c_char,
+            jobv: jobv as c_char,
+            jobr: jobr as c_char,
+            jobt: jobt as c_char,
+            jobp: jobp as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            sva,
+            u,
+            ldu: ldu.try_into()?,
+            v,
+            ldv: ldv.try_into()?,
+            work: Array1::zeros(lwork),
+            lwork: lwork.try_into()?,
+            rwork: Array1::zeros(lrwork),
+            lrwork: lrwork.try_into()?,
+            iwork,
+            stat: Array1::zeros(7),
+            info: 0,
+        };
+
+        // 5. perform comput
+        let result = unsafe { F::run_gejsv(&mut driver) };
+        gejsv_check_info(result)?;
+
+        // 6. finalize
+        let GEJSV_Driver { a, sva, u, v, stat, iwork, .. } = driver;
+        a.clone_to_view_mut(); // write back if `a` was cloned for layout
+        return Ok(GEJSV_Output {
+            sva,
+            u: ['U', 'F'].contains(&jobu).then(|| u.clone_to_view_mut()),
+            v: ['V', 'J'].contains(&jobv).then(|| v.clone_to_view_mut()),
+            scale: stat[0] / stat[1],
+            sconda: stat[2],
+            cond_r1: stat[3],
+            cond_r2: stat[4],
+            entropy: (jobt == 'T').then(|| (stat[5], stat[6])),
+            rank: iwork[0].max(0) as usize,
+            n_nonzero: iwork[1].max(0) as usize,
+            denormalized: iwork[2] != 0,
+            transposed: F::is_complex().then(|| iwork[3] == 1),
+        });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GEJSV<'a, 'u, 'v, F> = GEJSV_Builder<'a, 'u, 'v, F>;
+pub type SGEJSV<'a, 'u, 'v> = GEJSV<'a, 'u, 'v, f32>;
+pub type DGEJSV<'a, 'u, 'v> = GEJSV<'a, 'u, 'v, f64>;
+pub type CGEJSV<'a, 'u, 'v> = GEJSV<'a, 'u, 'v, c32>;
+pub type ZGEJSV<'a, 'u, 'v> = GEJSV<'a, 'u, 'v, c64>;
+
+impl<'a, 'u, 'v, F> GEJSV_Builder<'a, 'u, 'v, F>
+where
+    F: GEJSVNum,
+{
+    pub fn run(self) -> Result<GEJSV_Output<'u, 'v, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let mut output = obj.driver()?;
+            output.u = output.u.map(|u| match u {
+                ArrayOut::Owned(u) => ArrayOut::Owned(u.as_standard_layout().into_owned()),
+                _ => u,
+            });
+            output.v = output.v.map(|v| match v {
+                ArrayOut::Owned(v) => ArrayOut::Owned(v.as_standard_layout().into_owned()),
+                _ => v,
+            });
+            return Ok(output);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,321 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GESDD_Driver<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: LapackFloat,
+{
+    jobz: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    s: ArrayOut1<'s, F::RealFloat>,
+    u: ArrayOut2<'u, F>,
+    ldu: lapack_int,
+    vt: ArrayOut2<'vt, F>,
+    ldvt: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    rwork: Array1<F::RealFloat>,
+    iwork: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait GESDDNum: LapackFloat {
+    unsafe fn run_gesdd(driver: &mut GESDD_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl GESDDNum for $type {
+            unsafe fn run_gesdd(driver: &mut GESDD_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.jobz,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.s.get_data_mut_ptr(),
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.vt.get_data_mut_ptr(),
+                    &driver.ldvt,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl GESDDNum for $type {
+            unsafe fn run_gesdd(driver: &mut GESDD_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.jobz,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.s.get_data_mut_ptr(),
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.vt.get_data_mut_ptr(),
+                    &driver.ldvt,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    driver.rwork.as_mut_ptr(),
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sgesdd_);
+impl_func_real!(f64, dgesdd_);
+impl_func_comp!(c32, cgesdd_);
+impl_func_comp!(c64, zgesdd_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`GESDD`].
+///
+/// For `jobz = 'O'`, either `U` (if `m >= n`) or `Vᴴ` (if `m < n`) is not stored in field `u` or `vt`, but written
+/// to `a`; use [`GESDD_Output::u`] and [`GESDD_Output::vt`] to obtain it as view into the overwritten `a`.
+#[derive(Debug)]
+pub struct GESDD_Output<'a, 's, 'u, 'vt, F>
+where
+    F: LapackFloat,
+{
+    /// Input matrix `a`, overwritten (destroyed unless `jobz = 'O'`).
+    pub a: ArrayOut2<'a, F>,
+    /// Singular values in decreasing order, of length `min(m, n)`.
+    pub s: ArrayOut1<'s, F::RealFloat>,
+    /// Left singular vectors, of shape `(m, m)` for `jobz = 'A'` (or `'O'` with `m < n`), or `(m, min(m, n))` for
+    /// `jobz = 'S'`.
+    pub u: Option<ArrayOut2<'u, F>>,
+    /// Right singular vectors (conjugate transposed), of shape `(n, n)` for `jobz = 'A'` (or `'O'` with `m >= n`),
+    /// or `(min(m, n), n)` for `jobz = 'S'`.
+    pub vt: Option<ArrayOut2<'vt, F>>,
+    pub jobz: char,
+}
+
+impl<F> GESDD_Output<'_, '_, '_, '_, F>
+where
+    F: LapackFloat,
+{
+    /// Left singular vectors, either from field `u`, or the first `n` columns of `a` for `jobz = 'O'` and
+    /// `m >= n`.
+    pub fn u(&self) -> Option<ArrayView2<'_, F>> {
+        let (m, n) = self.a.view().dim();
+        match self.jobz {
+            'O' if m >= n => Some(self.a.view().slice_move(s![.., ..n])),
+            _ => self.u.as_ref().map(|u| u.view()),
+        }
+    }
+
+    /// Right singular vectors (conjugate transposed), either from field `vt`, or the first `m` rows of `a` for
+    /// `jobz = 'O'` and `m < n`.
+    pub fn vt(&self) -> Option<ArrayView2<'_, F>> {
+        let (m, n) = self.a.view().dim();
+        match self.jobz {
+            'O' if m < n => Some(self.a.view().slice_move(s![..m, ..])),
+            _ => self.vt.as_ref().map(|vt| vt.view()),
+        }
+    }
+}
+
+/// Singular value decomposition `A = U Σ Vᴴ` of general matrix by divide-and-conquer.
+///
+/// Option `jobz` is in `'A'` (all columns of `U` and rows of `Vᴴ`), `'S'` (the first `min(m, n)` ones), `'O'`
+/// (the first `min(m, n)` ones, with `U` overwriting `a` if `m >= n`, or `Vᴴ` overwriting `a` if `m < n`) or `'N'`
+/// (not computed). Arrays `s`, `u` and `vt`, if given, should have the shapes documented in [`GESDD_Output`].
+///
+/// Since workspace query of some LAPACK versions may return insufficient `lwork`, the queried size is raised to
+/// the documented minimum if necessary.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GESDD_<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESDDNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // output
+    #[builder(setter(strip_option), default = "None")]
+    pub s: Option<ArrayViewMut1<'s, F::RealFloat>>,
+    #[builder(setter(strip_option), default = "None")]
+    pub u: Option<ArrayViewMut2<'u, F>>,
+    #[builder(setter(strip_option), default = "None")]
+    pub vt: Option<ArrayViewMut2<'vt, F>>,
+
+    // option
+    #[builder(default = "'S'")]
+    pub jobz: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 's, 'u, 'vt, 'work, F> GESDD_<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESDDNum,
+{
+    pub fn driver(self) -> Result<GESDD_Output<'a, 's, 'u, 'vt, F>, LapackError> {
+        let Self { a, s, u, vt, jobz, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let (mn, mx) = (m.min(n), m.max(n));
+        let jobz = jobz.to_ascii_uppercase();
+        lapack_check_flag!(jobz, ['A', 'S', 'O', 'N'])?;
+
+        // 2. dim check & alloc
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let s = ArrayOut1::optional_alloc(s, mn, true)?;
+        let (u_dim, vt_dim) = match jobz {
+            'A' => (Some((m, m)), Some((n, n))),
+            'S' => (Some((m, mn)), Some((mn, n))),
+            'O' if m >= n => (None, Some((n, n))),
+            'O' => (Some((m, m)), None),
+            _ => (None, None),
+        };
+        let u = match u_dim {
+            Some(u_dim) => ArrayOut2::optional_alloc_fpref(u, u_dim, true)?,
+            None => {
+                lapack_assert!(u.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros((1, 1).f()))
+            },
+        };
+        let vt = match vt_dim {
+            Some(vt_dim) => ArrayOut2::optional_alloc_fpref(vt, vt_dim, true)?,
+            None => {
+                lapack_assert!(vt.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros((1, 1).f()))
+            },
+        };
+        let ldu = u.view().stride_of(Axis(1)).max(u.view().nrows().max(1) as isize);
+        let ldvt = vt.view().stride_of(Axis(1)).max(vt.view().nrows().max(1) as isize);
+
+        // 3. buffer check & alloc
+        let lwork_min = match (F::is_complex(), jobz) {
+            (false, 'N') => 3 * mn + mx.max(7 * mn),
+            (false, 'O') => 3 * mn + mx.max(5 * mn * mn + 4 * mn),
+            (false, 'S') => 4 * mn * mn + 7 * mn,
+            (false, _) => 4 * mn * mn + 6 * mn + mx,
+            (true, 'N') => 2 * mn + mx,
+            (true, 'O') => 2 * mn * mn + 2 * mn + mx,
+            (true, _) => mn * mn + 2 * mn + mx,
+        }
+        .max(1);
+        let (work, query_work) = ArrayOut1::optional_buffer(work, lwork_min, true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+        let rwork_size = match (F::is_complex(), jobz) {
+            (false, _) => 1,
+            (true, 'N') => 7 * mn,
+            (true, _) => (5 * mn * mn + 5 * mn).max(2 * mx * mn + 2 * mn * mn + mn),
+        };
+        let rwork = Array1::zeros(rwork_size.max(1));
+        let iwork = Array1::zeros((8 * mn).max(1));
+
+        // 4. struct build
+        let mut driver = GESDD_Driver {
+            jobz: jobz as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            s,
+            u,
+            ldu: ldu.try_into()?,
+            vt,
+            ldvt: ldvt.try_into()?,
+            work,
+            lwork: lwork.try_into()?,
+            rwork,
+            iwork,
+            info: 0,
+        };
+
+        // 5. buffer query (guard against too small `lwork` returned by query)
+        if query_work {
+            unsafe {
+                F::run_gesdd(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(lwork_min as isize);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_gesdd(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GESDD_Driver { a, s, u, vt, .. } = driver;
+        let u = u_dim.map(|_| u.clone_to_view_mut());
+        let vt = vt_dim.map(|_| vt.clone_to_view_mut());
+        return Ok(GESDD_Output { a: a.clone_to_view_mut(), s: s.clone_to_view_mut(), u, vt, jobz });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GESDD<'a, 's, 'u, 'vt, 'work, F> = GESDD_Builder<'a, 's, 'u, 'vt, 'work, F>;
+pub type SGESDD<'a, 's, 'u, 'vt, 'work> = GESDD<'a, 's, 'u, 'vt, 'work, f32>;
+pub type DGESDD<'a, 's, 'u, 'vt, 'work> = GESDD<'a, 's, 'u, 'vt, 'work, f64>;
+pub type CGESDD<'a, 's, 'u, 'vt, 'work> = GESDD<'a, 's, 'u, 'vt, 'work, c32>;
+pub type ZGESDD<'a, 's, 'u, 'vt, 'work> = GESDD<'a, 's, 'u, 'vt, 'work, c64>;
+
+impl<'a, 's, 'u, 'vt, 'work, F> GESDD_Builder<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESDDNum,
+{
+    pub fn run(self) -> Result<GESDD_Output<'a, 's, 'u, 'vt, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let GESDD_Output { a, s, u, vt, jobz } = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            let u = u.map(|u| match u {
+                ArrayOut::Owned(u) => ArrayOut::Owned(u.as_standard_layout().into_owned()),
+                _ => u,
+            });
+            let vt = vt.map(|vt| match vt {
+                ArrayOut::Owned(vt) => ArrayOut::Owned(vt.as_standard_layout().into_owned()),
+                _ => vt,
+            });
+            return Ok(GESDD_Output { a, s, u, vt, jobz });
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,349 @@
+extern crate alloc;
+
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use alloc::string::String;
+use core::fmt::Write;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GESVD_Driver<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: LapackFloat,
+{
+    jobu: c_char,
+    jobvt: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    s: ArrayOut1<'s, F::RealFloat>,
+    u: ArrayOut2<'u, F>,
+    ldu: lapack_int,
+    vt: ArrayOut2<'vt, F>,
+    ldvt: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    rwork: Array1<F::RealFloat>,
+    info: lapack_int,
+}
+
+pub trait GESVDNum: LapackFloat {
+    unsafe fn run_gesvd(driver: &mut GESVD_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl GESVDNum for $type {
+            unsafe fn run_gesvd(driver: &mut GESVD_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.jobu,
+                    &driver.jobvt,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.s.get_data_mut_ptr(),
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.vt.get_data_mut_ptr(),
+                    &driver.ldvt,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl GESVDNum for $type {
+            unsafe fn run_gesvd(driver: &mut GESVD_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.jobu,
+                    &driver.jobvt,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.s.get_data_mut_ptr(),
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.vt.get_data_mut_ptr(),
+                    &driver.ldvt,
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    driver.rwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sgesvd_);
+impl_func_real!(f64, dgesvd_);
+impl_func_comp!(c32, cgesvd_);
+impl_func_comp!(c64, zgesvd_);
+
+/* #endregion */
+
+/* #region error */
+
+/// Translate positive `info` of `GESVD` into readable error, carrying the unconverged superdiagonal `e` of the
+/// intermediate bidiagonal form (from `work[1..mn]` for real, or `rwork[..mn - 1]` for complex matrices).
+fn gesvd_check_info<F>(
+    result: Result<(), LapackError>,
+    driver: &GESVD_Driver<F>,
+    mn: usize,
+) -> Result<(), LapackError>
+where
+    F: LapackFloat,
+{
+    match result {
+        Err(LapackError::Info(info)) if info > 0 => {
+            let mut s = String::from(concat!(file!(), ":", line!(), ": ", "LapackError::FailedCheck", " : "));
+            write!(s, "{info} superdiagonals of intermediate bidiagonal form did not converge to zero, e = ")
+                .unwrap();
+            let e_len = mn.saturating_sub(1);
+            match F::is_complex() {
+                false => write!(s, "{:?}", driver.work.view().slice(s![1..1 + e_len]).to_vec()),
+                true => write!(s, "{:?}", driver.rwork.slice(s![..e_len]).to_vec()),
+            }
+            .unwrap();
+            Err(LapackError::FailedCheck(s))
+        },
+        _ => result,
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`GESVD`].
+///
+/// For `jobu = 'O'` (or `jobvt = 'O'`), `U` (or `Vᴴ`) is not stored in field `u` (or `vt`), but written to `a`;
+/// use [`GESVD_Output::u`] (or [`GESVD_Output::vt`]) to obtain it as view into the overwritten `a`.
+#[derive(Debug)]
+pub struct GESVD_Output<'a, 's, 'u, 'vt, F>
+where
+    F: LapackFloat,
+{
+    /// Input matrix `a`, overwritten (destroyed unless `jobu` or `jobvt` is `'O'`).
+    pub a: ArrayOut2<'a, F>,
+    /// Singular values in decreasing order, of length `min(m, n)`.
+    pub s: ArrayOut1<'s, F::RealFloat>,
+    /// Left singular vectors, of shape `(m, m)` for `jobu = 'A'` or `(m, min(m, n))` for `jobu = 'S'`.
+    pub u: Option<ArrayOut2<'u, F>>,
+    /// Right singular vectors (conjugate transposed), of shape `(n, n)` for `jobvt = 'A'` or `(min(m, n), n)` for
+    /// `jobvt = 'S'`.
+    pub vt: Option<ArrayOut2<'vt, F>>,
+    pub jobu: char,
+    pub jobvt: char,
+}
+
+impl<F> GESVD_Output<'_, '_, '_, '_, F>
+where
+    F: LapackFloat,
+{
+    /// Left singular vectors, either from field `u`, or the first `min(m, n)` columns of `a` for `jobu = 'O'`.
+    pub fn u(&self) -> Option<ArrayView2<'_, F>> {
+        match self.jobu {
+            'O' => {
+                let (m, n) = self.a.view().dim();
+                Some(self.a.view().slice_move(s![.., ..m.min(n)]))
+            },
+            _ => self.u.as_ref().map(|u| u.view()),
+        }
+    }
+
+    /// Right singular vectors (conjugate transposed), either from field `vt`, or the first `min(m, n)` rows of `a`
+    /// for `jobvt = 'O'`.
+    pub fn vt(&self) -> Option<ArrayView2<'_, F>> {
+        match self.jobvt {
+            'O' => {
+                let (m, n) = self.a.view().dim();
+                Some(self.a.view().slice_move(s![..m.min(n), ..]))
+            },
+            _ => self.vt.as_ref().map(|vt| vt.view()),
+        }
+    }
+}
+
+/// Singular value decomposition `A = U Σ Vᴴ` of general matrix by QR iteration.
+///
+/// Options `jobu` and `jobvt` are in `'A'` (all columns of `U` or rows of `Vᴴ`), `'S'` (the first `min(m, n)`
+/// ones), `'O'` (the first `min(m, n)` ones, overwriting `a`) or `'N'` (not computed); `jobu` and `jobvt` cannot
+/// both be `'O'`. Arrays `s`, `u` and `vt`, if given, should have the shapes documented in [`GESVD_Output`].
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GESVD_<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESVDNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // output
+    #[builder(setter(strip_option), default = "None")]
+    pub s: Option<ArrayViewMut1<'s, F::RealFloat>>,
+    #[builder(setter(strip_option), default = "None")]
+    pub u: Option<ArrayViewMut2<'u, F>>,
+    #[builder(setter(strip_option), default = "None")]
+    pub vt: Option<ArrayViewMut2<'vt, F>>,
+
+    // option
+    #[builder(default = "'S'")]
+    pub jobu: char,
+    #[builder(default = "'S'")]
+    pub jobvt: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 's, 'u, 'vt, 'work, F> GESVD_<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESVDNum,
+{
+    pub fn driver(self) -> Result<GESVD_Output<'a, 's, 'u, 'vt, F>, LapackError> {
+        let Self { a, s, u, vt, jobu, jobvt, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let (mn, mx) = (m.min(n), m.max(n));
+        let jobu = jobu.to_ascii_uppercase();
+        let jobvt = jobvt.to_ascii_uppercase();
+        lapack_check_flag!(jobu, ['A', 'S', 'O', 'N'])?;
+        lapack_check_flag!(jobvt, ['A', 'S', 'O', 'N'])?;
+        lapack_assert!(!(jobu == 'O' && jobvt == 'O'), InvalidFlag)?;
+
+        // 2. dim check & alloc
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let s = ArrayOut1::optional_alloc(s, mn, true)?;
+        let u = match jobu {
+            'A' => ArrayOut2::optional_alloc_fpref(u, (m, m), true)?,
+            'S' => ArrayOut2::optional_alloc_fpref(u, (m, mn), true)?,
+            _ => {
+                lapack_assert!(u.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros((1, 1).f()))
+            },
+        };
+        let vt = match jobvt {
+            'A' => ArrayOut2::optional_alloc_fpref(vt, (n, n), true)?,
+            'S' => ArrayOut2::optional_alloc_fpref(vt, (mn, n), true)?,
+            _ => {
+                lapack_assert!(vt.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros((1, 1).f()))
+            },
+        };
+        let ldu = u.view().stride_of(Axis(1)).max(u.view().nrows().max(1) as isize);
+        let ldvt = vt.view().stride_of(Axis(1)).max(vt.view().nrows().max(1) as isize);
+
+        // 3. buffer check & alloc
+        let lwork_min = match F::is_complex() {
+            false => (3 * mn + mx).max(5 * mn).max(1),
+            true => (2 * mn + mx).max(1),
+        };
+        let (work, query_work) = ArrayOut1::optional_buffer(work, lwork_min, true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+        let rwork = Array1::zeros(if F::is_complex() { (5 * mn).max(1) } else { 1 });
+
+        // 4. struct build
+        let mut driver = GESVD_Driver {
+            jobu: jobu as c_char,
+            jobvt: jobvt as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            s,
+            u,
+            ldu: ldu.try_into()?,
+            vt,
+            ldvt: ldvt.try_into()?,
+            work,
+            lwork: lwork.try_into()?,
+            rwork,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_gesvd(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(lwork_min as isize);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        let result = unsafe { F::run_gesvd(&mut driver) };
+        gesvd_check_info(result, &driver, mn)?;
+
+        // 7. finalize
+        let GESVD_Driver { a, s, u, vt, .. } = driver;
+        let u = match jobu {
+            'A' | 'S' => Some(u.clone_to_view_mut()),
+            _ => None,
+        };
+        let vt = match jobvt {
+            'A' | 'S' => Some(vt.clone_to_view_mut()),
+            _ => None,
+        };
+        return Ok(GESVD_Output { a: a.clone_to_view_mut(), s: s.clone_to_view_mut(), u, vt, jobu, jobvt });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GESVD<'a, 's, 'u, 'vt, 'work, F> = GESVD_Builder<'a, 's, 'u, 'vt, 'work, F>;
+pub type SGESVD<'a, 's, 'u, 'vt, 'work> = GESVD<'a, 's, 'u, 'vt, 'work, f32>;
+pub type DGESVD<'a, 's, 'u, 'vt, 'work> = GESVD<'a, 's, 'u, 'vt, 'work, f64>;
+pub type CGESVD<'a, 's, 'u, 'vt, 'work> = GESVD<'a, 's, 'u, 'vt, 'work, c32>;
+pub type ZGESVD<'a, 's, 'u, 'vt, 'work> = GESVD<'a, 's, 'u, 'vt, 'work, c64>;
+
+impl<'a, 's, 'u, 'vt, 'work, F> GESVD_Builder<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESVDNum,
+{
+    pub fn run(self) -> Result<GESVD_Output<'a, 's, 'u, 'vt, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let GESVD_Output { a, s, u, vt, jobu, jobvt } = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            let u = u.map(|u| match u {
+                ArrayOut::Owned(u) => ArrayOut::Owned(u.as_standard_layout().into_owned()),
+                _ => u,
+            });
+            let vt = vt.map(|vt| match vt {
+                ArrayOut::Owned(vt) => ArrayOut::Owned(vt.as_standard_layout().into_owned()),
+                _ => vt,
+            });
+            return Ok(GESVD_Output { a, s, u, vt, jobu, jobvt });
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,346 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/* #region Lapack driver */
+
+pub struct GESVDQ_Driver<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: LapackFloat,
+{
+    joba: c_char,
+    jobp: c_char,
+    jobr: c_char,
+    jobu: c_char,
+    jobv: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    s: ArrayOut1<'s, F::RealFloat>,
+    u: ArrayOut2<'u, F>,
+    ldu: lapack_int,
+    vt: ArrayOut2<'vt, F>,
+    ldvt: lapack_int,
+    numrank: lapack_int,
+    iwork: Array1<lapack_int>,
+    liwork: lapack_int,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    rwork: Array1<F::RealFloat>,
+    lrwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait GESVDQNum: LapackFloat {
+    unsafe fn run_gesvdq(driver: &mut GESVDQ_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl GESVDQNum for $type {
+            unsafe fn run_gesvdq(driver: &mut GESVDQ_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.joba,
+                    &driver.jobp,
+                    &driver.jobr,
+                    &driver.jobu,
+                    &driver.jobv,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.s.get_data_mut_ptr(),
+                    driver.u.get_data_mut_ptr(),
+                    &driver.ldu,
+                    driver.vt.get_data_mut_ptr(),
+                    &driver.ldvt,
+                    &mut driver.numrank,
+                    driver.iwork.as_mut_ptr(),
+                    &driver.liwork,
+                    driver.work.get_data_mut_ptr(),
+                    &mut driver.lwork,
+                    driver.rwork.as_mut_ptr(),
+                    &driver.lrwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sgesvdq_);
+impl_func!(f64, dgesvdq_);
+impl_func!(c32, cgesvdq_);
+impl_func!(c64, zgesvdq_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Minimal `lwork` of `GESVDQ` as documented by LAPACK, for `joba`, `jobu` and `jobv` already validated.
+fn gesvdq_lwork_min<F>(m: usize, n: usize, joba: char, jobu: char, jobv: char) -> usize
+where
+    F: LapackFloat,
+{
+    let (lwqp3, lwcon, lwsvd, lwsvd2) = match F::is_complex() {
+        false => (3 * n + 1, 3 * n, (5 * n).max(1), (5 * (n / 2)).max(1)),
+        true => (n + 1, 2 * n, (3 * n).max(1), (3 * (n / 2)).max(1)),
+    };
+    let lworq = if jobu == 'A' { m.max(1) } else { n.max(1) };
+    let lwcon = if joba == 'E' { lwcon } else { 0 };
+    let lwork_min = match (jobu != 'N', jobv != 'N') {
+        (false, false) => (n + lwqp3).max(lwcon).max(lwsvd),
+        (true, false) => n + lwqp3.max(lwcon).max(lwsvd).max(lworq),
+        (false, true) => n + lwqp3.max(lwcon).max(lwsvd),
+        (true, true) => {
+            let lwork_min = n + lwqp3.max(lwcon).max(lwsvd).max(lworq);
+            match jobv {
+                // full SVD of `R` of size `n / 2` by QR or LQ (`LWQRF`/`LWLQF`, `LWSVD2`, `LWORQ2`/`LWORLQ`)
+                'A' | 'V' => {
+                    let half = n / 2;
+                    let lwork_min2 = lwqp3
+                        .max(lwcon)
+                        .max(half + half.max(1))
+                        .max(half + lwsvd2)
+                        .max(half + n.max(1))
+                        .max(lworq);
+                    lwork_min.max(n + lwork_min2)
+                },
+                _ => lwork_min,
+            }
+        },
+    };
+    return lwork_min.max(2);
+}
+
+/// Output of [`GESVDQ`].
+#[derive(Debug)]
+pub struct GESVDQ_Output<'s, 'u, 'vt, F>
+where
+    F: LapackFloat,
+{
+    /// Singular values in decreasing order, of length `n`; those after `numrank` are set to zero.
+    pub s: ArrayOut1<'s, F::RealFloat>,
+    /// Left singular vectors, of shape `(m, m)` for `jobu = 'A'`, or `(m, n)` for `jobu = 'S'`, `'U'` or `'R'`
+    /// (only the first `numrank` columns are computed for `'R'`).
+    pub u: Option<ArrayOut2<'u, F>>,
+    /// Right singular vectors (conjugate transposed), of shape `(n, n)` for `jobv = 'A'`, `'V'` or `'R'` (only the
+    /// first `numrank` rows are computed for `'R'`).
+    pub vt: Option<ArrayOut2<'vt, F>>,
+    /// Numerical rank determined from the rank revealing QR factorization.
+    pub numrank: usize,
+    /// Estimate of condition number of column scaled `A` (`joba = 'E'`); `None` if not estimated, or `A` is
+    /// estimated to be rank deficient.
+    pub sconda: Option<F::RealFloat>,
+}
+
+/// Singular value decomposition `A = U Σ Vᴴ` of general matrix (`m >= n`) with QR preconditioning.
+///
+/// - `joba`: accuracy level and numerical rank determination of pivoted QR,
+///   `'H'` (high accuracy, no truncation, default), `'M'` (medium, truncate `|R(i, i)| <= ε |R(1, 1)|`),
+///   `'A'` (aggressive, truncate `|R(i, i)| <= sqrt(n) ε |R(1, 1)|`), or `'E'` (as `'A'`, also estimating
+///   condition number).
+/// - `jobp`: `'P'` (row pivoting, for rows of widely different norms) or `'N'` (default).
+/// - `jobr`: `'T'` (compute SVD of `Rᴴ` instead of `R`) or `'N'` (default).
+/// - `jobu`: `'A'` (all `m` left singular vectors), `'S'`/`'U'` (`n` vectors, default), `'R'` (`numrank` vectors)
+///   or `'N'`.
+/// - `jobv`: `'A'`/`'V'` (all `n` right singular vectors, default), `'R'` (`numrank` vectors) or `'N'`.
+///
+/// Sizes of `iwork` and `rwork` are always obtained from workspace query. Buffer `work` is used if it has at least
+/// the minimal `lwork` documented by LAPACK for the given job options; otherwise its size is also queried. Matrix
+/// `a` is destroyed on exit.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GESVDQ_<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESVDQNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // output
+    #[builder(setter(strip_option), default = "None")]
+    pub s: Option<ArrayViewMut1<'s, F::RealFloat>>,
+    #[builder(setter(strip_option), default = "None")]
+    pub u: Option<ArrayViewMut2<'u, F>>,
+    #[builder(setter(strip_option), default = "None")]
+    pub vt: Option<ArrayViewMut2<'vt, F>>,
+
+    // option
+    #[builder(default = "'H'")]
+    pub joba: char,
+    #[builder(default = "'N'")]
+    pub jobp: char,
+    #[builder(default = "'N'")]
+    pub jobr: char,
+    #[builder(default = "'S'")]
+    pub jobu: char,
+    #[builder(default = "'V'")]
+    pub jobv: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 's, 'u, 'vt, 'work, F> GESVDQ_<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESVDQNum,
+{
+    pub fn driver(self) -> Result<GESVDQ_Output<'s, 'u, 'vt, F>, LapackError> {
+        let Self { a, s, u, vt, joba, jobp, jobr, jobu, jobv, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let joba = joba.to_ascii_uppercase();
+        let jobp = jobp.to_ascii_uppercase();
+        let jobr = jobr.to_ascii_uppercase();
+        let jobu = jobu.to_ascii_uppercase();
+        let jobv = jobv.to_ascii_uppercase();
+        lapack_check_flag!(joba, ['A', 'H', 'M', 'E'])?;
+        lapack_check_flag!(jobp, ['P', 'N'])?;
+        lapack_check_flag!(jobr, ['T', 'N'])?;
+        lapack_check_flag!(jobu, ['A', 'S', 'U', 'R', 'N'])?;
+        lapack_check_flag!(jobv, ['A', 'V', 'R', 'N'])?;
+
+        // 2. dim check & alloc
+        lapack_assert_le!(n, m, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let s = ArrayOut1::optional_alloc(s, n, true)?;
+        let u = match jobu {
+            'A' => ArrayOut2::optional_alloc_fpref(u, (m, m), true)?,
+            'S' | 'U' | 'R' => ArrayOut2::optional_alloc_fpref(u, (m, n), true)?,
+            _ => {
+                lapack_assert!(u.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros((1, 1).f()))
+            },
+        };
+        let vt = match jobv {
+            'N' => {
+                lapack_assert!(vt.is_none(), InvalidFlag)?;
+                ArrayOut::Owned(Array2::zeros((1, 1).f()))
+            },
+            _ => ArrayOut2::optional_alloc_fpref(vt, (n, n), true)?,
+        };
+        let ldu = u.view().stride_of(Axis(1)).max(u.view().nrows().max(1) as isize);
+        let ldvt = vt.view().stride_of(Axis(1)).max(vt.view().nrows().max(1) as isize);
+
+        // 3. buffer check & alloc
+        // iwork and rwork are always obtained from workspace query
+        let lwork_min = gesvdq_lwork_min::<F>(m, n, joba, jobu, jobv);
+        let (work, query_work) = ArrayOut1::optional_buffer(work, lwork_min, true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = GESVDQ_Driver {
+            joba: joba as c_char,
+            jobp: jobp as c_char,
+            jobr: jobr as c_char,
+            jobu: jobu as c_char,
+            jobv: jobv as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            s,
+            u,
+            ldu: ldu.try_into()?,
+            vt,
+            ldvt: ldvt.try_into()?,
+            numrank: 0,
+            iwork: Array1::zeros(1),
+            liwork: -1,
+            work,
+            lwork: lwork.try_into()?,
+            rwork: Array1::zeros(1),
+            lrwork: -1,
+            info: 0,
+        };
+
+        // 5. buffer query
+        // `work[0]` and `work[1]` are optimal and minimal lwork, `iwork[0]` and `rwork[0]` are minimal liwork and
+        // lrwork
+        {
+            let lwork_user = driver.lwork;
+            let work_user = core::mem::replace(&mut driver.work, ArrayOut1::Owned(Array1::zeros(2)));
+            driver.lwork = -1;
+            unsafe {
+                F::run_gesvdq(&mut driver)?;
+            }
+            let liwork = driver.iwork[0].max(1);
+            let lrwork = F::ftoi(F::from_real(driver.rwork[0])).max(2);
+            driver.liwork = liwork;
+            driver.iwork = Array1::zeros(liwork as usize);
+            driver.lrwork = lrwork.try_into()?;
+            driver.rwork = Array1::zeros(lrwork as usize);
+            if query_work {
+                let lwork = F::ftoi(driver.work.view()[0]).max(F::ftoi(driver.work.view()[1]));
+                let lwork = lwork.max(lwork_min as isize);
+                driver.lwork = lwork.try_into()?;
+                driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+            } else {
+                driver.lwork = lwork_user;
+                driver.work = work_user;
+            }
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_gesvdq(&mut driver)?;
+        }
+
+        // 7. finalize
+        let GESVDQ_Driver { a, s, u, vt, numrank, rwork, .. } = driver;
+        a.clone_to_view_mut(); // write back if `a` was cloned for layout
+        let sconda = (joba == 'E' && rwork[0] >= F::RealFloat::zero()).then(|| rwork[0]);
+        return Ok(GESVDQ_Output {
+            s: s.clone_to_view_mut(),
+            u: (jobu != 'N').then(|| u.clone_to_view_mut()),
+            vt: (jobv != 'N').then(|| vt.clone_to_view_mut()),
+            numrank: numrank.max(0) as usize,
+            sconda,
+        });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GESVDQ<'a, 's, 'u, 'vt, 'work, F> = GESVDQ_Builder<'a, 's, 'u, 'vt, 'work, F>;
+pub type SGESVDQ<'a, 's, 'u, 'vt, 'work> = GESVDQ<'a, 's, 'u, 'vt, 'work, f32>;
+pub type DGESVDQ<'a, 's, 'u, 'vt, 'work> = GESVDQ<'a, 's, 'u, 'vt, 'work, f64>;
+pub type CGESVDQ<'a, 's, 'u, 'vt, 'work> = GESVDQ<'a, 's, 'u, 'vt, 'work, c32>;
+pub type ZGESVDQ<'a, 's, 'u, 'vt, 'work> = GESVDQ<'a, 's, 'u, 'vt, 'work, c64>;
+
+impl<'a, 's, 'u, 'vt, 'work, F> GESVDQ_Builder<'a, 's, 'u, 'vt, 'work, F>
+where
+    F: GESVDQNum,
+{
+    pub fn run(self) -> Result<GESVDQ_Output<'s, 'u, 'vt, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let mut output = obj.driver()?;
+            output.u = output.u.map(|u| match u {
+                ArrayOut::Owned(u) => ArrayOut::Owned(u.as_standard_layout().into_owned()),
+                _ => u,
+            });
+            output.vt = output.vt.map(|vt| match vt {
+                ArrayOut::Owned(vt) => ArrayOut::Owned(vt.as_standard_layout().into_owned()),
+                _ => vt,
+            });
+            return Ok(output);
+        }
+    }
+}
+
+/* #endregion */
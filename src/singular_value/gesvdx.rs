@@ -0,0 +1,328 @@
+extern crate alloc;
+
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use alloc::string::String;
+use core::fmt::Write;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use num_traits::Zero;
+
+/* #region Lapack driver */
+
+pub struct GESVDX_Driver<'a, F>
+where
+    F: LapackFloat,
+{
+    jobu: c_char,
+    jobvt: c_char,
+    range: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    vl: F::RealFloat,
+    vu: F::RealFloat,
+    il: lapack_int,
+    iu: lapack_int,
+    ns: lapack_int,
+    s: Array1<F::RealFloat>,
+    u: Array2<F>,
+    ldu: lapack_int,
+    vt: Array2<F>,
+    ldvt: lapack_int,
+    work: Array1<F>,
+    lwork: lapack_int,
+    rwork: Array1<F::RealFloat>,
+    iwork: Array1<lapack_int>,
+    info: lapack_int,
+}
+
+pub trait GESVDXNum: LapackFloat {
+    unsafe fn run_gesvdx(driver: &mut GESVDX_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl GESVDXNum for $type {
+            unsafe fn run_gesvdx(driver: &mut GESVDX_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.jobu,
+                    &driver.jobvt,
+                    &driver.range,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    &driver.vl,
+                    &driver.vu,
+                    &driver.il,
+                    &driver.iu,
+                    &mut driver.ns,
+                    driver.s.as_mut_ptr(),
+                    driver.u.as_mut_ptr(),
+                    &driver.ldu,
+                    driver.vt.as_mut_ptr(),
+                    &driver.ldvt,
+                    driver.work.as_mut_ptr(),
+                    &driver.lwork,
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl GESVDXNum for $type {
+            unsafe fn run_gesvdx(driver: &mut GESVDX_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.jobu,
+                    &driver.jobvt,
+                    &driver.range,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    &driver.vl,
+                    &driver.vu,
+                    &driver.il,
+                    &driver.iu,
+                    &mut driver.ns,
+                    driver.s.as_mut_ptr(),
+                    driver.u.as_mut_ptr(),
+                    &driver.ldu,
+                    driver.vt.as_mut_ptr(),
+                    &driver.ldvt,
+                    driver.work.as_mut_ptr(),
+                    &driver.lwork,
+                    driver.rwork.as_mut_ptr(),
+                    driver.iwork.as_mut_ptr(),
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sgesvdx_);
+impl_func_real!(f64, dgesvdx_);
+impl_func_comp!(c32, cgesvdx_);
+impl_func_comp!(c64, zgesvdx_);
+
+/* #endregion */
+
+/* #region error */
+
+/// Translate positive `info` of `GESVDX` into readable error, carrying the indices of singular vectors that failed
+/// to converge in `BDSVDX` (stored in `iwork`).
+fn gesvdx_check_info<F>(result: Result<(), LapackError>, driver: &GESVDX_Driver<F>) -> Result<(), LapackError>
+where
+    F: LapackFloat,
+{
+    match result {
+        Err(LapackError::Info(info)) if info > 0 => {
+            let mut s = String::from(concat!(file!(), ":", line!(), ": ", "LapackError::FailedCheck", " : "));
+            let n = driver.m.min(driver.n) as i64;
+            if info > 2 * n {
+                write!(s, "internal error in BDSVDX (info = {info})").unwrap();
+            } else {
+                let failed = driver.iwork.slice(s![..(info as usize).min(driver.iwork.len())]).to_vec();
+                write!(s, "{info} singular vectors failed to converge in BDSVDX, indices = {failed:?}")
+                    .unwrap();
+            }
+            Err(LapackError::FailedCheck(s))
+        },
+        _ => result,
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`GESVDX`].
+///
+/// Only the `ns` computed singular triplets are returned, where `ns` is the length of `s`.
+#[derive(Debug, Clone)]
+pub struct GESVDX_Output<F>
+where
+    F: LapackFloat,
+{
+    /// Selected singular values in decreasing order, of length `ns`.
+    pub s: Array1<F::RealFloat>,
+    /// Left singular vectors of shape `(m, ns)`, for `jobu = 'V'`.
+    pub u: Option<Array2<F>>,
+    /// Right singular vectors (conjugate transposed) of shape `(ns, n)`, for `jobvt = 'V'`.
+    pub vt: Option<Array2<F>>,
+}
+
+/// Selected singular values and vectors of general matrix by bisection and inverse iteration (`BDSVDX`).
+///
+/// Option `range` could be
+/// - `'A'`: all singular values (default);
+/// - `'V'`: singular values in half-open interval `(vl, vu]`, where `0 <= vl < vu`;
+/// - `'I'`: the `il`-th through `iu`-th largest singular values, 1-based and inclusive as in LAPACK, where
+///   `1 <= il <= iu <= min(m, n)`.
+///
+/// Options `jobu` and `jobvt` are in `'V'` (compute, default) or `'N'`. Matrix `a` is destroyed on exit.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GESVDX_<'a, F>
+where
+    F: GESVDXNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // option
+    #[builder(default = "'V'")]
+    pub jobu: char,
+    #[builder(default = "'V'")]
+    pub jobvt: char,
+    #[builder(default = "'A'")]
+    pub range: char,
+    #[builder(default = "F::RealFloat::zero()")]
+    pub vl: F::RealFloat,
+    #[builder(default = "F::RealFloat::zero()")]
+    pub vu: F::RealFloat,
+    #[builder(default = "1")]
+    pub il: usize,
+    #[builder(default = "1")]
+    pub iu: usize,
+}
+
+impl<'a, F> GESVDX_<'a, F>
+where
+    F: GESVDXNum,
+{
+    pub fn driver(self) -> Result<GESVDX_Output<F>, LapackError> {
+        let Self { a, jobu, jobvt, range, vl, vu, il, iu } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let (mn, mx) = (m.min(n), m.max(n));
+        let jobu = jobu.to_ascii_uppercase();
+        let jobvt = jobvt.to_ascii_uppercase();
+        let range = range.to_ascii_uppercase();
+        lapack_check_flag!(jobu, ['V', 'N'])?;
+        lapack_check_flag!(jobvt, ['V', 'N'])?;
+        lapack_check_flag!(range, ['A', 'V', 'I'])?;
+
+        // 2. dim check & alloc
+        let ns_max = match range {
+            'V' => {
+                lapack_assert!(vl >= F::RealFloat::zero() && vl < vu, InvalidFlag)?;
+                mn
+            },
+            'I' => {
+                lapack_assert!(1 <= il && il <= iu && iu <= mn, InvalidDim)?;
+                iu - il + 1
+            },
+            _ => mn,
+        };
+        let (il, iu) = if range == 'I' { (il, iu) } else { (1, 1) };
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let s = Array1::zeros(mn.max(1));
+        let u_dim = if jobu == 'V' { (m.max(1), ns_max.max(1)) } else { (1, 1) };
+        let vt_dim = if jobvt == 'V' { (ns_max.max(1), n.max(1)) } else { (1, 1) };
+        let u = Array2::zeros(u_dim.f());
+        let vt = Array2::zeros(vt_dim.f());
+        let (ldu, ldvt) = (u_dim.0, vt_dim.0);
+
+        // 3. buffer check & alloc
+        let lwork_min = match F::is_complex() {
+            false => (mn * (3 * mn + 20)).max(4 * mn + mx),
+            true => (mn * (mn + 5)).max(2 * mn + mx),
+        }
+        .max(1);
+        let rwork_size = if F::is_complex() { 17 * mn * mn } else { 1 };
+        let rwork = Array1::zeros(rwork_size.max(1));
+        let iwork = Array1::zeros((12 * mn).max(1));
+
+        // 4. struct build
+        let mut driver = GESVDX_Driver {
+            jobu: jobu as c_char,
+            jobvt: jobvt as c_char,
+            range: range as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            vl,
+            vu,
+            il: il.try_into()?,
+            iu: iu.try_into()?,
+            ns: 0,
+            s,
+            u,
+            ldu: ldu.try_into()?,
+            vt,
+            ldvt: ldvt.try_into()?,
+            work: Array1::zeros(1),
+            lwork: -1,
+            rwork,
+            iwork,
+            info: 0,
+        };
+
+        // 5. buffer query (guard against too small `lwork` returned by query)
+        unsafe {
+            F::run_gesvdx(&mut driver)?;
+        }
+        let lwork = F::ftoi(driver.work[0]).max(lwork_min as isize);
+        driver.lwork = lwork.try_into()?;
+        driver.work = Array1::zeros(lwork as usize);
+
+        // 6. perform comput
+        let result = unsafe { F::run_gesvdx(&mut driver) };
+        gesvdx_check_info(result, &driver)?;
+
+        // 7. finalize
+        let GESVDX_Driver { a, ns, s, u, vt, .. } = driver;
+        a.clone_to_view_mut(); // write back if `a` was cloned for layout
+        let ns = ns as usize;
+        lapack_assert_le!(ns, ns_max, RuntimeError)?;
+        let s = s.slice(s![..ns]).into_owned();
+        let u = (jobu == 'V').then(|| u.slice(s![.., ..ns]).into_owned());
+        let vt = (jobvt == 'V').then(|| vt.slice(s![..ns, ..]).into_owned());
+        return Ok(GESVDX_Output { s, u, vt });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GESVDX<'a, F> = GESVDX_Builder<'a, F>;
+pub type SGESVDX<'a> = GESVDX<'a, f32>;
+pub type DGESVDX<'a> = GESVDX<'a, f64>;
+pub type CGESVDX<'a> = GESVDX<'a, c32>;
+pub type ZGESVDX<'a> = GESVDX<'a, c64>;
+
+impl<'a, F> GESVDX_Builder<'a, F>
+where
+    F: GESVDXNum,
+{
+    pub fn run(self) -> Result<GESVDX_Output<F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let GESVDX_Output { s, u, vt } = obj.driver()?;
+            let u = u.map(|u| u.as_standard_layout().into_owned());
+            let vt = vt.map(|vt| vt.as_standard_layout().into_owned());
+            return Ok(GESVDX_Output { s, u, vt });
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,326 @@
+extern crate alloc;
+
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use alloc::string::String;
+use core::fmt::Write;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct GESVJ_Driver<'a, 'v, F>
+where
+    F: LapackFloat,
+{
+    joba: c_char,
+    jobu: c_char,
+    jobv: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    sva: Array1<F::RealFloat>,
+    mv: lapack_int,
+    v: ArrayOut2<'v, F>,
+    ldv: lapack_int,
+    work: Array1<F>,
+    lwork: lapack_int,
+    rwork: Array1<F::RealFloat>,
+    lrwork: lapack_int,
+    /// `ctol` on input (`jobu = 'C'`), and the 6 statistics of `work` (real) or `rwork` (complex) on exit.
+    stat: Array1<F::RealFloat>,
+    info: lapack_int,
+}
+
+pub trait GESVJNum: LapackFloat {
+    unsafe fn run_gesvj(driver: &mut GESVJ_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func_real {
+    ($type:ty, $func:ident) => {
+        impl GESVJNum for $type {
+            unsafe fn run_gesvj(driver: &mut GESVJ_Driver<Self>) -> Result<(), LapackError> {
+                driver.work[0] = driver.stat[0];
+                ffi::$func(
+                    &driver.joba,
+                    &driver.jobu,
+                    &driver.jobv,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.sva.as_mut_ptr(),
+                    &driver.mv,
+                    driver.v.get_data_mut_ptr(),
+                    &driver.ldv,
+                    driver.work.as_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                driver.stat.assign(&driver.work.slice(s![..6]));
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+macro_rules! impl_func_comp {
+    ($type:ty, $func:ident) => {
+        impl GESVJNum for $type {
+            unsafe fn run_gesvj(driver: &mut GESVJ_Driver<Self>) -> Result<(), LapackError> {
+                driver.rwork[0] = driver.stat[0];
+                ffi::$func(
+                    &driver.joba,
+                    &driver.jobu,
+                    &driver.jobv,
+                    &driver.m,
+                    &driver.n,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.sva.as_mut_ptr(),
+                    &driver.mv,
+                    driver.v.get_data_mut_ptr(),
+                    &driver.ldv,
+                    driver.work.as_mut_ptr(),
+                    &driver.lwork,
+                    driver.rwork.as_mut_ptr(),
+                    &driver.lrwork,
+                    &mut driver.info,
+                );
+                driver.stat.assign(&driver.rwork.slice(s![..6]));
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func_real!(f32, sgesvj_);
+impl_func_real!(f64, dgesvj_);
+impl_func_comp!(c32, cgesvj_);
+impl_func_comp!(c64, zgesvj_);
+
+/* #endregion */
+
+/* #region error */
+
+/// Translate positive `info` of `GESVJ` (no convergence in 30 sweeps) into readable error, carrying the largest
+/// `|cos|` of column pairs in the last sweep.
+fn gesvj_check_info<F>(result: Result<(), LapackError>, driver: &GESVJ_Driver<F>) -> Result<(), LapackError>
+where
+    F: LapackFloat,
+{
+    match result {
+        Err(LapackError::Info(info)) if info > 0 => {
+            let mut s = String::from(concat!(file!(), ":", line!(), ": ", "LapackError::FailedCheck", " : "));
+            write!(s, "GESVJ did not converge in 30 sweeps, max |cos| in last sweep = {:?}", driver.stat[4])
+                .unwrap();
+            Err(LapackError::FailedCheck(s))
+        },
+        _ => result,
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`GESVJ`].
+///
+/// Singular values are `scale * sva` (see [`GESVJ_Output::s`]); they are not sorted. Statistics are parsed from
+/// `work` (real) or `rwork` (complex) on exit.
+#[derive(Debug)]
+pub struct GESVJ_Output<'a, 'v, F>
+where
+    F: LapackFloat,
+{
+    /// Left singular vectors (`jobu = 'U'` or `'C'`) of the nonzero singular values, otherwise destroyed.
+    pub a: ArrayOut2<'a, F>,
+    /// Scaled singular values, of length `n`.
+    pub sva: Array1<F::RealFloat>,
+    /// Right singular vectors `V` of shape `(n, n)` (`jobv = 'V'`), or input `v` post-multiplied by the Jacobi
+    /// rotations (`jobv = 'A'`).
+    pub v: Option<ArrayOut2<'v, F>>,
+    /// Scaling factor, such that singular values are `scale * sva`.
+    pub scale: F::RealFloat,
+    /// Number of computed nonzero singular values.
+    pub n_nonzero: usize,
+    /// Number of computed singular values larger than the underflow threshold.
+    pub n_above_underflow: usize,
+    /// Number of sweeps of Jacobi rotations needed for numerical convergence.
+    pub sweeps: usize,
+    /// Largest `|cos(A[:, i], A[:, j])|` (`i != j`) in the last sweep.
+    pub max_cos: F::RealFloat,
+    /// Largest absolute value of sines of Jacobi rotation angles in the last sweep.
+    pub max_sin: F::RealFloat,
+}
+
+impl<F> GESVJ_Output<'_, '_, F>
+where
+    F: LapackFloat,
+{
+    /// Singular values `scale * sva` (not sorted).
+    pub fn s(&self) -> Array1<F::RealFloat> {
+        self.sva.mapv(|x| x * self.scale)
+    }
+}
+
+/// Singular value decomposition `A = U Σ Vᴴ` of general matrix (`m >= n`) by one-sided Jacobi method, with high
+/// relative accuracy.
+///
+/// - `joba`: structure of `a`, `'G'` (general, default), `'U'` (upper triangular) or `'L'` (lower triangular).
+/// - `jobu`: `'U'` (compute left singular vectors into `a`, default), `'C'` (same as `'U'` with user-given
+///   orthogonality tolerance `ctol >= 1`), or `'N'`.
+/// - `jobv`: `'V'` (compute `V`, default), `'A'` (apply rotations to user-given `v` of shape `(mv, n)`), or `'N'`.
+///
+/// Convergence failure in 30 sweeps is reported as error.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct GESVJ_<'a, 'v, F>
+where
+    F: GESVJNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+    #[builder(setter(strip_option), default = "None")]
+    pub v: Option<ArrayViewMut2<'v, F>>,
+
+    // option
+    #[builder(default = "'G'")]
+    pub joba: char,
+    #[builder(default = "'U'")]
+    pub jobu: char,
+    #[builder(default = "'V'")]
+    pub jobv: char,
+    #[builder(setter(strip_option), default = "None")]
+    pub ctol: Option<F::RealFloat>,
+}
+
+impl<'a, 'v, F> GESVJ_<'a, 'v, F>
+where
+    F: GESVJNum,
+{
+    pub fn driver(self) -> Result<GESVJ_Output<'a, 'v, F>, LapackError> {
+        let Self { a, v, joba, jobu, jobv, ctol } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let (m, n) = a.view().dim();
+        let joba = joba.to_ascii_uppercase();
+        let jobu = jobu.to_ascii_uppercase();
+        let jobv = jobv.to_ascii_uppercase();
+        lapack_check_flag!(joba, ['G', 'U', 'L'])?;
+        lapack_check_flag!(jobu, ['U', 'C', 'N'])?;
+        lapack_check_flag!(jobv, ['V', 'A', 'N'])?;
+        lapack_assert!((jobu == 'C') == ctol.is_some(), InvalidFlag)?;
+
+        // 2. dim check & alloc
+        lapack_assert_le!(n, m, InvalidDim)?;
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+        let (v, mv) = match jobv {
+            'V' => (ArrayOut2::optional_alloc_fpref(v, (n, n), true)?, 0),
+            'A' => {
+                lapack_assert!(v.is_some(), InvalidFlag)?;
+                let mv = v.as_ref().unwrap().nrows();
+                (ArrayOut2::optional_alloc_fpref(v, (mv, n), true)?, mv)
+            },
+            _ => {
+                lapack_assert!(v.is_none(), InvalidFlag)?;
+                (ArrayOut::Owned(Array2::zeros((1, 1).f())), 0)
+            },
+        };
+        let ldv = v.view().stride_of(Axis(1)).max(v.view().nrows().max(1) as isize);
+        let sva = Array1::zeros(n);
+
+        // 3. buffer check & alloc
+        // workspace query is not supported by older LAPACK, so documented minimum sizes are allocated
+        let lwork = match F::is_complex() {
+            false => (m + n).max(6),
+            true => (m + n).max(1),
+        };
+        let lrwork = if F::is_complex() { n.max(6) } else { 1 };
+        let mut stat = Array1::zeros(6);
+        if let Some(ctol) = ctol {
+            stat[0] = ctol;
+        }
+
+        // 4. struct build
+        let mut driver = GESVJ_Driver {
+            joba: joba as c_char,
+            jobu: jobu as c_char,
+            jobv: jobv as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            sva,
+            mv: mv.try_into()?,
+            v,
+            ldv: ldv.try_into()?,
+            work: Array1::zeros(lwork),
+            lwork: lwork.try_into()?,
+            rwork: Array1::zeros(lrwork),
+            lrwork: lrwork.try_into()?,
+            stat,
+            info: 0,
+        };
+
+        // 5. perform comput
+        let result = unsafe { F::run_gesvj(&mut driver) };
+        gesvj_check_info(result, &driver)?;
+
+        // 6. finalize
+        let GESVJ_Driver { a, sva, v, stat, .. } = driver;
+        let itos = |x: F::RealFloat| F::ftoi(F::from_real(x)).max(0) as usize;
+        return Ok(GESVJ_Output {
+            a: a.clone_to_view_mut(),
+            sva,
+            v: (jobv != 'N').then(|| v.clone_to_view_mut()),
+            scale: stat[0],
+            n_nonzero: itos(stat[1]),
+            n_above_underflow: itos(stat[2]),
+            sweeps: itos(stat[3]),
+            max_cos: stat[4],
+            max_sin: stat[5],
+        });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type GESVJ<'a, 'v, F> = GESVJ_Builder<'a, 'v, F>;
+pub type SGESVJ<'a, 'v> = GESVJ<'a, 'v, f32>;
+pub type DGESVJ<'a, 'v> = GESVJ<'a, 'v, f64>;
+pub type CGESVJ<'a, 'v> = GESVJ<'a, 'v, c32>;
+pub type ZGESVJ<'a, 'v> = GESVJ<'a, 'v, c64>;
+
+impl<'a, 'v, F> GESVJ_Builder<'a, 'v, F>
+where
+    F: GESVJNum,
+{
+    pub fn run(self) -> Result<GESVJ_Output<'a, 'v, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let mut output = obj.driver()?;
+            output.a = match output.a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            output.v = output.v.map(|v| match v {
+                ArrayOut::Owned(v) => ArrayOut::Owned(v.as_standard_layout().into_owned()),
+                _ => v,
+            });
+            return Ok(output);
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,129 @@
+use crate::ffi::{self, lapack_int};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct LARNV_Driver<F>
+where
+    F: LapackFloat,
+{
+    idist: lapack_int,
+    iseed: [lapack_int; 4],
+    n: lapack_int,
+    x: Array1<F>,
+}
+
+pub trait LARNVNum: LapackFloat {
+    unsafe fn run_larnv(driver: &mut LARNV_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl LARNVNum for $type {
+            unsafe fn run_larnv(driver: &mut LARNV_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(&driver.idist, driver.iseed.as_mut_ptr(), &driver.n, driver.x.as_mut_ptr());
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, slarnv_);
+impl_func!(f64, dlarnv_);
+impl_func!(c32, clarnv_);
+impl_func!(c64, zlarnv_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`LARNV`].
+#[derive(Debug, Clone)]
+pub struct LARNV_Output<F> {
+    /// Random vector of length `n`.
+    pub x: Array1<F>,
+    /// Seed updated on exit; passing it to the next call continues the same random stream.
+    pub iseed: [lapack_int; 4],
+}
+
+/// Vector of random numbers from uniform or normal distribution.
+///
+/// Option `idist` could be
+/// - `1`: uniform `(0, 1)`;
+/// - `2`: uniform `(-1, 1)`;
+/// - `3`: normal `(0, 1)` (default);
+/// - `4`: uniform in the unit disc `|z| < 1` (complex only);
+/// - `5`: uniform on the unit circle `|z| = 1` (complex only).
+///
+/// For complex types, real and imaginary parts are drawn independently for `idist` in `1`, `2`, `3`.
+///
+/// Seed `iseed` has elements in `0..=4095`, and `iseed[3]` must be odd. Same seed always gives the same vector.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct LARNV_<F>
+where
+    F: LARNVNum,
+{
+    // option
+    pub n: usize,
+    #[builder(default = "3")]
+    pub idist: lapack_int,
+    #[builder(default = "[0, 0, 0, 1]")]
+    pub iseed: [lapack_int; 4],
+
+    #[builder(setter(skip))]
+    _phantom: core::marker::PhantomData<F>,
+}
+
+impl<F> LARNV_<F>
+where
+    F: LARNVNum,
+{
+    pub fn driver(self) -> Result<LARNV_Output<F>, LapackError> {
+        let Self { n, idist, iseed, .. } = self;
+
+        // 1. flag check
+        let idist_max = if F::is_complex() { 5 } else { 3 };
+        lapack_assert!((1..=idist_max).contains(&idist), InvalidFlag)?;
+        lapack_assert!(iseed.iter().all(|v| (0..=4095).contains(v)), InvalidFlag)?;
+        lapack_assert!(iseed[3] % 2 == 1, InvalidFlag)?;
+
+        // 2. alloc
+        let x = Array1::zeros(n);
+
+        // 3. struct build
+        let mut driver = LARNV_Driver { idist, iseed, n: n.try_into()?, x };
+
+        // 4. perform comput
+        unsafe {
+            F::run_larnv(&mut driver)?;
+        }
+
+        // 5. finalize
+        let LARNV_Driver { iseed, x, .. } = driver;
+        return Ok(LARNV_Output { x, iseed });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type LARNV<F> = LARNV_Builder<F>;
+pub type SLARNV = LARNV<f32>;
+pub type DLARNV = LARNV<f64>;
+pub type CLARNV = LARNV<c32>;
+pub type ZLARNV = LARNV<c64>;
+
+impl<F> LARNV_Builder<F>
+where
+    F: LARNVNum,
+{
+    pub fn run(self) -> Result<LARNV_Output<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,33 @@
+pub mod bdsdc;
+pub mod bdsqr;
+pub mod bdsvdx;
+pub mod bidiagonal_factorization;
+pub mod gebrd;
+pub mod gejsv;
+pub mod gesdd;
+pub mod gesvd;
+pub mod gesvdq;
+pub mod gesvdx;
+pub mod gesvj;
+pub mod larnv;
+pub mod orgbr;
+pub mod ormbr;
+pub mod pinv;
+pub mod randomized_svd;
+
+pub use bdsdc::*;
+pub use bdsqr::*;
+pub use bdsvdx::*;
+pub use bidiagonal_factorization::*;
+pub use gebrd::*;
+pub use gejsv::*;
+pub use gesdd::*;
+pub use gesvd::*;
+pub use gesvdq::*;
+pub use gesvdx::*;
+pub use gesvj::*;
+pub use larnv::*;
+pub use orgbr::*;
+pub use ormbr::*;
+pub use pinv::*;
+pub use randomized_svd::*;
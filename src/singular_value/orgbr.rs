@@ -0,0 +1,190 @@
+use crate::ffi::{self, c_char, lapack_int};
+use crate::{lapack_info, util::*};
+use derive_builder::Builder;
+use ndarray::prelude::*;
+
+/* #region Lapack driver */
+
+pub struct ORGBR_Driver<'a, 'tau, 'work, F>
+where
+    F: LapackFloat,
+{
+    vect: c_char,
+    m: lapack_int,
+    n: lapack_int,
+    k: lapack_int,
+    a: ArrayOut2<'a, F>,
+    lda: lapack_int,
+    tau: ArrayView1<'tau, F>,
+    work: ArrayOut1<'work, F>,
+    lwork: lapack_int,
+    info: lapack_int,
+}
+
+pub trait ORGBRNum: LapackFloat {
+    unsafe fn run_orgbr(driver: &mut ORGBR_Driver<Self>) -> Result<(), LapackError>;
+}
+
+macro_rules! impl_func {
+    ($type:ty, $func:ident) => {
+        impl ORGBRNum for $type {
+            unsafe fn run_orgbr(driver: &mut ORGBR_Driver<Self>) -> Result<(), LapackError> {
+                ffi::$func(
+                    &driver.vect,
+                    &driver.m,
+                    &driver.n,
+                    &driver.k,
+                    driver.a.get_data_mut_ptr(),
+                    &driver.lda,
+                    driver.tau.as_ptr(),
+                    driver.work.get_data_mut_ptr(),
+                    &driver.lwork,
+                    &mut driver.info,
+                );
+                lapack_info!(driver.info)?;
+                return Ok(());
+            }
+        }
+    };
+}
+
+impl_func!(f32, sorgbr_);
+impl_func!(f64, dorgbr_);
+impl_func!(c32, cungbr_);
+impl_func!(c64, zungbr_);
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Generate `Q` (`vect = 'Q'`) or `Pᴴ` (`vect = 'P'`) of bidiagonal reduction from `GEBRD`.
+///
+/// Option `k` is the number of columns (`'Q'`) or rows (`'P'`) of the original matrix reduced by `GEBRD`.
+/// - `vect = 'Q'`: `a` of shape `(m, n)` with `m >= n >= min(m, k)` holds reflectors in its columns; on exit, it
+///   is overwritten by the first `n` columns of `Q`. Length of `tau` is `min(m, k)`.
+/// - `vect = 'P'`: `a` of shape `(m, n)` with `n >= m >= min(n, k)` holds reflectors in its rows; on exit, it is
+///   overwritten by the first `m` rows of `Pᴴ`. Length of `tau` is `min(n, k)`.
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct ORGBR_<'a, 'tau, 'work, F>
+where
+    F: ORGBRNum,
+{
+    // input/output
+    #[builder(setter(into))]
+    pub a: ArrayViewOrMut2<'a, F>,
+
+    // input
+    #[builder(setter(into))]
+    pub tau: ArrayView1<'tau, F>,
+
+    // option
+    pub k: usize,
+    #[builder(default = "'Q'")]
+    pub vect: char,
+
+    // buffer
+    #[builder(setter(strip_option), default = "None")]
+    pub work: Option<ArrayViewMut1<'work, F>>,
+}
+
+impl<'a, 'tau, 'work, F> ORGBR_<'a, 'tau, 'work, F>
+where
+    F: ORGBRNum,
+{
+    pub fn driver(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let Self { a, tau, k, vect, work } = self;
+
+        // 1. dim assign, flag check
+        let a = a.into_col_array_out();
+        let tau = tau.to_seq_layout()?;
+        let (m, n) = a.view().dim();
+        let vect = vect.to_ascii_uppercase();
+        lapack_check_flag!(vect, ['Q', 'P'])?;
+
+        // 2. dim check
+        match vect {
+            'Q' => {
+                lapack_assert_le!(n, m, InvalidDim)?;
+                lapack_assert_le!(m.min(k), n, InvalidDim)?;
+                lapack_assert_eq!(tau.len(), m.min(k), InvalidDim)?;
+            },
+            _ => {
+                lapack_assert_le!(m, n, InvalidDim)?;
+                lapack_assert_le!(n.min(k), m, InvalidDim)?;
+                lapack_assert_eq!(tau.len(), n.min(k), InvalidDim)?;
+            },
+        }
+        let lda = a.view().stride_of(Axis(1)).max(m.max(1) as isize);
+
+        // 3. buffer check & alloc
+        let (work, query_work) = ArrayOut1::optional_buffer(work, m.min(n).max(1), true)?;
+        let lwork = if query_work { -1 } else { work.view().len() as isize };
+
+        // 4. struct build
+        let mut driver = ORGBR_Driver {
+            vect: vect as c_char,
+            m: m.try_into()?,
+            n: n.try_into()?,
+            k: k.try_into()?,
+            a,
+            lda: lda.try_into()?,
+            tau: tau.view(),
+            work,
+            lwork: lwork.try_into()?,
+            info: 0,
+        };
+
+        // 5. buffer query
+        if query_work {
+            unsafe {
+                F::run_orgbr(&mut driver)?;
+            }
+            let lwork = F::ftoi(driver.work.view()[0]).max(1);
+            driver.lwork = lwork.try_into()?;
+            driver.work = ArrayOut1::optional_alloc(None, lwork as usize, false)?;
+        }
+
+        // 6. perform comput
+        unsafe {
+            F::run_orgbr(&mut driver)?;
+        }
+
+        // 7. finalize
+        let ORGBR_Driver { a, .. } = driver;
+        return Ok(a.clone_to_view_mut());
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type ORGBR<'a, 'tau, 'work, F> = ORGBR_Builder<'a, 'tau, 'work, F>;
+pub type UNGBR<'a, 'tau, 'work, F> = ORGBR_Builder<'a, 'tau, 'work, F>;
+pub type SORGBR<'a, 'tau, 'work> = ORGBR<'a, 'tau, 'work, f32>;
+pub type DORGBR<'a, 'tau, 'work> = ORGBR<'a, 'tau, 'work, f64>;
+pub type CUNGBR<'a, 'tau, 'work> = UNGBR<'a, 'tau, 'work, c32>;
+pub type ZUNGBR<'a, 'tau, 'work> = UNGBR<'a, 'tau, 'work, c64>;
+
+impl<'a, 'tau, 'work, F> ORGBR_Builder<'a, 'tau, 'work, F>
+where
+    F: ORGBRNum,
+{
+    pub fn run(self) -> Result<ArrayOut2<'a, F>, LapackError> {
+        let obj = self.build()?;
+        if obj.a.view().is_fpref() {
+            return obj.driver();
+        } else {
+            let a = obj.driver()?;
+            let a = match a {
+                ArrayOut::Owned(a) => ArrayOut::Owned(a.as_standard_layout().into_owned()),
+                ArrayOut::ViewMut(a) => ArrayOut::ViewMut(a),
+                _ => lapack_raise!(RuntimeError)?,
+            };
+            return Ok(a);
+        }
+    }
+}
+
+/* #endregion */
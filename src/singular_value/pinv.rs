@@ -0,0 +1,107 @@
+//! Pseudo-inverse, rank, null space and range space of general matrix, by SVD (gesdd).
+//!
+//! Singular values `σ_i <= tol` are regarded as zero. If `tol` is not given, it defaults to
+//! `max(m, n) · ε · σ_max`, where `ε` is [`LapackFloat::EPSILON`].
+//!
+//! Input matrix could be in any layout, and is not overwritten. Outputs are owned arrays in row-major layout.
+
+use crate::singular_value::gesdd::{GESDDNum, GESDD};
+use crate::util::*;
+use ndarray::prelude::*;
+use num_traits::{Float, NumCast, Zero};
+
+/* #region tolerance */
+
+/// Owned `(s, u, vt)` of SVD.
+type SvdOwned<F> = (Array1<<F as LapackFloat>::RealFloat>, Option<Array2<F>>, Option<Array2<F>>);
+
+/// SVD of a copy of `a` by `GESDD`, returning owned `(s, u, vt)`; `u` and `vt` are `None` for `jobz = 'N'`.
+fn svd_owned<F>(a: ArrayView2<F>, jobz: char) -> Result<SvdOwned<F>, LapackError>
+where
+    F: GESDDNum,
+{
+    let svd = GESDD::default().a(a).jobz(jobz).run()?;
+    return Ok((svd.s.into_owned(), svd.u.map(|u| u.into_owned()), svd.vt.map(|vt| vt.into_owned())));
+}
+
+fn svd_default_tol<F>(s: &ArrayView1<F::RealFloat>, m: usize, n: usize) -> F::RealFloat
+where
+    F: LapackFloat,
+{
+    let s_max = s.iter().fold(F::RealFloat::zero(), |acc, &v| acc.max(v));
+    let mn: F::RealFloat = NumCast::from(m.max(n)).unwrap();
+    return mn * F::EPSILON * s_max;
+}
+
+fn svd_rank<F>(s: &ArrayView1<F::RealFloat>, m: usize, n: usize, tol: Option<F::RealFloat>) -> usize
+where
+    F: LapackFloat,
+{
+    let tol = tol.unwrap_or_else(|| svd_default_tol::<F>(s, m, n));
+    return s.iter().filter(|&&v| v > tol).count();
+}
+
+/* #endregion */
+
+/* #region functions */
+
+/// Moore-Penrose pseudo-inverse `A^+ = V Σ^+ U^H` of general `(m, n)` matrix; output is `(n, m)`.
+pub fn pinv<F>(a: ArrayView2<F>, tol: Option<F::RealFloat>) -> Result<Array2<F>, LapackError>
+where
+    F: GESDDNum,
+{
+    let (m, n) = a.dim();
+    let (s, u, vt) = svd_owned(a, 'S')?;
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+    let r = svd_rank::<F>(&s.view(), m, n, tol);
+    let mut a_pinv = Array2::<F>::zeros((n, m));
+    for k in 0..r {
+        let s_inv = F::from_real(s[k].recip());
+        for i in 0..n {
+            let v_ik = F::conj(vt[[k, i]]) * s_inv;
+            for j in 0..m {
+                a_pinv[[i, j]] += v_ik * F::conj(u[[j, k]]);
+            }
+        }
+    }
+    return Ok(a_pinv);
+}
+
+/// Numerical rank of general matrix, as number of singular values larger than tolerance.
+pub fn matrix_rank<F>(a: ArrayView2<F>, tol: Option<F::RealFloat>) -> Result<usize, LapackError>
+where
+    F: GESDDNum,
+{
+    let (m, n) = a.dim();
+    let (s, _, _) = svd_owned(a, 'N')?;
+    return Ok(svd_rank::<F>(&s.view(), m, n, tol));
+}
+
+/// Orthonormal basis of null space `{x : A x = 0}` of general `(m, n)` matrix; output is `(n, n - rank)`.
+pub fn null_space<F>(a: ArrayView2<F>, tol: Option<F::RealFloat>) -> Result<Array2<F>, LapackError>
+where
+    F: GESDDNum,
+{
+    let (m, n) = a.dim();
+    // full V is required if m < n
+    let jobz = if m >= n { 'S' } else { 'A' };
+    let (s, _, vt) = svd_owned(a, jobz)?;
+    let vt = vt.unwrap();
+    let r = svd_rank::<F>(&s.view(), m, n, tol);
+    let basis = vt.slice(s![r..n, ..]).t().mapv(F::conj);
+    return Ok(basis.as_standard_layout().into_owned());
+}
+
+/// Orthonormal basis of range space (column space) of general `(m, n)` matrix; output is `(m, rank)`.
+pub fn orth<F>(a: ArrayView2<F>, tol: Option<F::RealFloat>) -> Result<Array2<F>, LapackError>
+where
+    F: GESDDNum,
+{
+    let (m, n) = a.dim();
+    let (s, u, _) = svd_owned(a, 'S')?;
+    let u = u.unwrap();
+    let r = svd_rank::<F>(&s.view(), m, n, tol);
+    return Ok(u.slice(s![.., ..r]).as_standard_layout().into_owned());
+}
+
+/* #endregion */
@@ -0,0 +1,160 @@
+//! Randomized truncated SVD of general matrix (Halko, Martinsson and Tropp, SIAM Rev. 53, 217 (2011)).
+//!
+//! The range of `A` is sampled by `Y = A Ω` with Gaussian test matrix `Ω` of shape `(n, l)`, where `l = k + p` and
+//! `p` is the oversampling. Power iterations `Y = (A Aᴴ)^q A Ω` sharpen the spectral decay, and each product is
+//! re-orthonormalized to avoid loss of accuracy in floating point. With `Q` the orthonormal basis of `Y`, the small
+//! matrix `B = Qᴴ A` of shape `(l, n)` is decomposed by `GESDD`, giving the rank-`k` approximation
+//! `A ≈ (Q U_B) Σ Vᴴ`.
+//!
+//! Test matrix is generated by `LARNV` from seed `iseed`, so results are reproducible for the same seed.
+//!
+//! Input matrix could be in any layout, and is not overwritten. Only matrix products with `A` and `Aᴴ` are taken.
+
+use crate::ffi::lapack_int;
+use crate::orthogonal_factor::{GEQRFNum, ORGQRNum, ORMQRNum, GEQRF};
+use crate::singular_value::gesdd::{GESDDNum, GESDD};
+use crate::singular_value::larnv::{LARNVNum, LARNV};
+use crate::util::*;
+use derive_builder::Builder;
+use ndarray::prelude::*;
+use ndarray::LinalgScalar;
+
+/* #region range finder */
+
+/// Routines required by randomized SVD; implemented for all of `f32`, `f64`, `c32`, `c64`.
+pub trait RSVDNum: LARNVNum + GEQRFNum + ORGQRNum + ORMQRNum + GESDDNum + LinalgScalar {}
+
+impl<F> RSVDNum for F where F: LARNVNum + GEQRFNum + ORGQRNum + ORMQRNum + GESDDNum + LinalgScalar {}
+
+/// Orthonormal basis of column space of `(m, l)` matrix `y` (`l <= m`) by `GEQRF` and `ORGQR`.
+fn orthonormalize<F>(y: ArrayView2<F>) -> Result<Array2<F>, LapackError>
+where
+    F: RSVDNum,
+{
+    GEQRF::default().a(y).run()?.q_economy()
+}
+
+/// Orthonormal basis `Q` of shape `(m, l)` approximating range space of general `(m, n)` matrix, by randomized
+/// subspace iteration with `power_iter` power iterations.
+///
+/// Gaussian test matrix is generated from `iseed` (see [`LARNV`]). Requires `1 <= l <= min(m, n)`.
+pub fn randomized_range_finder<F>(
+    a: ArrayView2<F>,
+    l: usize,
+    power_iter: usize,
+    iseed: [lapack_int; 4],
+) -> Result<Array2<F>, LapackError>
+where
+    F: RSVDNum,
+{
+    let (m, n) = a.dim();
+    lapack_assert!(1 <= l && l <= m.min(n), InvalidDim)?;
+
+    // Gaussian test matrix Ω of shape (n, l)
+    let omega = LARNV::<F>::default().n(n * l).idist(3).iseed(iseed).run()?.x;
+    let omega = Array2::from_shape_vec((n, l).f(), omega.into_raw_vec()).unwrap();
+
+    let mut q = orthonormalize(a.dot(&omega).view())?;
+    for _ in 0..power_iter {
+        // Aᴴ Q = conj(Aᵀ conj(Q))
+        let z = a.t().dot(&q.mapv(F::conj)).mapv(F::conj);
+        let w = orthonormalize(z.view())?;
+        q = orthonormalize(a.dot(&w).view())?;
+    }
+    return Ok(q);
+}
+
+/* #endregion */
+
+/* #region Lapack builder */
+
+/// Output of [`RSVD`].
+#[derive(Debug, Clone)]
+pub struct RSVD_Output<F>
+where
+    F: LapackFloat,
+{
+    /// Approximate leading left singular vectors of shape `(m, k)`.
+    pub u: Array2<F>,
+    /// Approximate leading singular values in decreasing order, of length `k`.
+    pub s: Array1<F::RealFloat>,
+    /// Approximate leading right singular vectors (conjugate transposed) of shape `(k, n)`.
+    pub vt: Array2<F>,
+}
+
+/// Randomized truncated SVD `A ≈ U Σ Vᴴ` of rank `k`, for large matrix where full SVD is infeasible.
+///
+/// Options:
+/// - `k`: target rank, `1 <= k <= min(m, n)` (required);
+/// - `oversampling`: extra samples `p` of range (default 10); sample size `k + p` is capped at `min(m, n)`;
+/// - `power_iter`: number of power iterations `q` (default 2); more iterations help for slowly decaying spectrum;
+/// - `iseed`: seed of Gaussian test matrix (default `[0, 0, 0, 1]`), see [`LARNV`].
+#[derive(Builder)]
+#[builder(pattern = "owned", build_fn(error = "LapackError"), no_std)]
+pub struct RSVD_<'a, F>
+where
+    F: RSVDNum,
+{
+    // input
+    #[builder(setter(into))]
+    pub a: ArrayView2<'a, F>,
+
+    // option
+    pub k: usize,
+    #[builder(default = "10")]
+    pub oversampling: usize,
+    #[builder(default = "2")]
+    pub power_iter: usize,
+    #[builder(default = "[0, 0, 0, 1]")]
+    pub iseed: [lapack_int; 4],
+}
+
+impl<'a, F> RSVD_<'a, F>
+where
+    F: RSVDNum,
+{
+    pub fn driver(self) -> Result<RSVD_Output<F>, LapackError> {
+        let Self { a, k, oversampling, power_iter, iseed } = self;
+
+        // 1. dim assign & check
+        let (m, n) = a.dim();
+        let mn = m.min(n);
+        lapack_assert!(1 <= k && k <= mn, InvalidDim)?;
+        let l = (k + oversampling).min(mn);
+
+        // 2. range finder
+        let q = randomized_range_finder(a, l, power_iter, iseed)?;
+
+        // 3. SVD of projected matrix B = Qᴴ A of shape (l, n)
+        let b = q.t().mapv(F::conj).dot(&a);
+        let svd = GESDD::default().a(b.view()).jobz('S').run()?;
+        let (s, ub, vt) = (svd.s.into_owned(), svd.u.unwrap().into_owned(), svd.vt.unwrap().into_owned());
+
+        // 4. finalize
+        let u = q.dot(&ub.slice(s![.., ..k]));
+        let s = s.slice(s![..k]).into_owned();
+        let vt = vt.slice(s![..k, ..]).as_standard_layout().into_owned();
+        return Ok(RSVD_Output { u, s, vt });
+    }
+}
+
+/* #endregion */
+
+/* #region Lapack wrapper */
+
+pub type RSVD<'a, F> = RSVD_Builder<'a, F>;
+pub type SRSVD<'a> = RSVD<'a, f32>;
+pub type DRSVD<'a> = RSVD<'a, f64>;
+pub type CRSVD<'a> = RSVD<'a, c32>;
+pub type ZRSVD<'a> = RSVD<'a, c64>;
+
+impl<'a, F> RSVD_Builder<'a, F>
+where
+    F: RSVDNum,
+{
+    pub fn run(self) -> Result<RSVD_Output<F>, LapackError> {
+        self.build()?.driver()
+    }
+}
+
+/* #endregion */
@@ -100,6 +100,38 @@ macro_rules! lapack_assert_eq {
     };
 }
 
+#[macro_export]
+macro_rules! lapack_assert_le {
+    ($a:expr, $b:expr, $errtype:ident) => {
+        if $a <= $b {
+            Ok(())
+        } else {
+            extern crate alloc;
+            use alloc::string::String;
+            use core::fmt::Write;
+            let mut s = String::from(concat!(
+                file!(),
+                ":",
+                line!(),
+                ": ",
+                "LapackError::",
+                stringify!($errtype),
+                " : "
+            ));
+            write!(
+                s,
+                "{:?} = {:?} not less than or equal to {:?} = {:?}",
+                stringify!($a),
+                $a,
+                stringify!($b),
+                $b
+            )
+            .unwrap();
+            Err(LapackError::$errtype(s))
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! lapack_raise {
     ($errtype:ident) => {{
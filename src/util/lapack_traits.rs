@@ -27,6 +27,7 @@ pub trait LapackFloat:
     fn conj(x: Self) -> Self;
     fn abs(x: Self) -> Self::RealFloat;
     fn ftoi(x: Self) -> isize;
+    fn from_real(x: Self::RealFloat) -> Self;
 }
 
 impl LapackFloat for f32 {
@@ -49,6 +50,10 @@ impl LapackFloat for f32 {
     fn ftoi(x: Self) -> isize {
         x as isize
     }
+    #[inline]
+    fn from_real(x: Self::RealFloat) -> Self {
+        x
+    }
 }
 
 impl LapackFloat for f64 {
@@ -71,6 +76,10 @@ impl LapackFloat for f64 {
     fn ftoi(x: Self) -> isize {
         x as isize
     }
+    #[inline]
+    fn from_real(x: Self::RealFloat) -> Self {
+        x
+    }
 }
 
 impl LapackFloat for c32 {
@@ -93,6 +102,10 @@ impl LapackFloat for c32 {
     fn ftoi(x: Self) -> isize {
         x.re as isize
     }
+    #[inline]
+    fn from_real(x: Self::RealFloat) -> Self {
+        Self::new(x, 0.0)
+    }
 }
 
 impl LapackFloat for c64 {
@@ -115,4 +128,8 @@ impl LapackFloat for c64 {
     fn ftoi(x: Self) -> isize {
         x.re as isize
     }
+    #[inline]
+    fn from_real(x: Self::RealFloat) -> Self {
+        Self::new(x, 0.0)
+    }
 }
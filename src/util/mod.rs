@@ -11,6 +11,6 @@ pub use lapack_traits::*;
 pub use util_ndarray::*;
 
 pub use crate::{
-    lapack_assert, lapack_assert_eq, lapack_check_flag, lapack_info, lapack_invalid, lapack_raise,
-    lapack_warn_layout_clone,
+    lapack_assert, lapack_assert_eq, lapack_assert_le, lapack_check_flag, lapack_info, lapack_invalid,
+    lapack_raise, lapack_warn_layout_clone,
 };
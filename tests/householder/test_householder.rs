@@ -0,0 +1,113 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Explicit `H = I - τ v vᴴ`.
+    fn reflector<F>(v: &ArrayView1<F>, tau: F) -> Array2<F>
+    where
+        F: TestFloat,
+    {
+        let n = v.len();
+        let v = v.to_owned().into_shape((n, 1)).unwrap();
+        let vvh = gemm(&v.view(), &transpose(&v.view(), 'C').view());
+        Array2::eye(n) - vvh.mapv(|x| x * tau)
+    }
+
+    fn test_generic<F>()
+    where
+        F: LARFGNum + LARFNum + LARFXNum + LARFTNum + LARFBNum + GEQRFNum + ORGQRNum + ORMQRNum + TestFloat,
+    {
+        let n = 6;
+
+        // LARFG: Hᴴ [α; x] = [β; 0]
+        let alpha = random_array::<F>(1)[0];
+        let x = random_array::<F>(n - 1);
+        let (beta, v2, tau) = LARFG::default().alpha(alpha).x(x.view()).run().unwrap();
+        let mut v = Array1::<F>::ones(n);
+        v.slice_mut(s![1..]).assign(&v2.view());
+        let h = reflector(&v.view(), tau);
+        let mut ax = Array1::<F>::zeros(n);
+        ax[0] = alpha;
+        ax.slice_mut(s![1..]).assign(&x);
+        let mut beta_e1 = Array1::<F>::zeros(n);
+        beta_e1[0] = beta;
+        allclose_epsilon(
+            &gemv(&transpose(&h.view(), 'C').view(), &ax.view()).view(),
+            &beta_e1.view(),
+            1.0e-10,
+        );
+        assert_eq!(F::conj(beta), beta);
+
+        // LARF and LARFX: H C and C H
+        for order in [n, 12] {
+            let v = random_array::<F>(order);
+            let h = reflector(&v.view(), tau);
+            let c = random_matrix::<F>(order, 3, 'R'.into());
+            let hc_ref = gemm(&h.view(), &c.view());
+            let hc = LARF::default().v(v.view()).tau(tau).c(c.view()).run().unwrap();
+            allclose_epsilon(&hc.view(), &hc_ref.view(), 1.0e-10);
+            let hc = LARFX::default().v(v.view()).tau(tau).c(c.view()).run().unwrap();
+            allclose_epsilon(&hc.view(), &hc_ref.view(), 1.0e-10);
+            let c = random_matrix::<F>(3, order, 'C'.into());
+            let ch_ref = gemm(&c.view(), &h.view());
+            let ch = LARF::default().v(v.view()).tau(tau).c(c.view()).side('R').run().unwrap();
+            allclose_epsilon(&ch.view(), &ch_ref.view(), 1.0e-10);
+            let ch = LARFX::default().v(v.view()).tau(tau).c(c.view()).side('R').run().unwrap();
+            allclose_epsilon(&ch.view(), &ch_ref.view(), 1.0e-10);
+            assert!(LARF::default().v(v.view()).tau(tau).c(c.view()).run().is_err());
+        }
+
+        // LARFT and LARFB: I - V T Vᴴ is Q from GEQRF
+        let (m, k) = (7, 3);
+        let a = random_matrix::<F>(m, k, 'C'.into());
+        let qr = GEQRF::default().a(a.view()).run().unwrap();
+        let mut v = qr.a.clone();
+        v.indexed_iter_mut().for_each(|((i, j), x)| match i.cmp(&j) {
+            core::cmp::Ordering::Less => *x = F::zero(),
+            core::cmp::Ordering::Equal => *x = F::one(),
+            core::cmp::Ordering::Greater => (),
+        });
+        let t = LARFT::default().v(v.view()).tau(qr.tau.view()).run().unwrap();
+        assert_eq!(t.dim(), (k, k));
+        let vtvh = gemm(&gemm(&v.view(), &t.view()).view(), &transpose(&v.view(), 'C').view());
+        let q_full = qr.q_complete().unwrap();
+        allclose_epsilon(&(Array2::eye(m) - vtvh).view(), &q_full.view(), 1.0e-10);
+
+        let c = random_matrix::<F>(m, 4, 'R'.into());
+        for trans in ['N', 'C'] {
+            let qc = LARFB::default().v(v.view()).t(t.view()).c(c.view()).trans(trans).run().unwrap();
+            let qc_ref = gemm(&transpose(&q_full.view(), trans).view(), &c.view());
+            allclose_epsilon(&qc.view(), &qc_ref.view(), 1.0e-10);
+        }
+        let c = random_matrix::<F>(4, m, 'C'.into());
+        for trans in ['N', 'C'] {
+            let mut c_mut = c.clone();
+            LARFB::default()
+                .v(v.view())
+                .t(t.view())
+                .c(c_mut.view_mut())
+                .side('R')
+                .trans(trans)
+                .run()
+                .unwrap();
+            let cq_ref = gemm(&c.view(), &transpose(&q_full.view(), trans).view());
+            allclose_epsilon(&c_mut.view(), &cq_ref.view(), 1.0e-10);
+        }
+
+        // dimension relationships of storev
+        assert!(LARFT::default().v(v.view()).tau(qr.tau.view()).storev('R').run().is_err());
+        assert!(LARFB::default().v(v.view()).t(t.view()).c(c.view()).storev('R').run().is_err());
+        let vr = transpose(&v.view(), 'N');
+        assert!(LARFT::default().v(vr.view()).tau(qr.tau.view()).storev('R').run().is_ok());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,5 @@
+pub mod test_accumulator;
+pub mod test_gels;
+pub mod test_getsls;
+pub mod test_gglse;
+pub mod test_lstsq;
@@ -0,0 +1,89 @@
+use crate::util::*;
+use approx::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GELSNum + TPQRTNum + TPMQRTNum + TPLQTNum + TPMLQTNum + TRTRSNum + TestFloat,
+    {
+        let (rows, n, nrhs) = (40, 5, 2);
+        let chunks = [(0, 7), (7, 20), (20, 20), (20, 40)];
+
+        // row-wise QR accumulator against one-shot GELS
+        {
+            let a = random_matrix::<F>(rows, n, 'R'.into());
+            let b = random_matrix::<F>(rows, nrhs, 'C'.into());
+            let mut acc = QrAccumulator::<F>::new(n, nrhs);
+            for (start, end) in chunks {
+                acc.absorb(a.slice(s![start..end, ..]), b.slice(s![start..end, ..])).unwrap();
+                assert_eq!(acc.rows_seen(), end);
+            }
+            let x = acc.solve().unwrap();
+            let (x_ref, _) = GELS::default().a(a.view()).b(b.view()).run().unwrap();
+            allclose_epsilon(&x.view(), &x_ref.view(), 1.0e-10);
+
+            // R^H R = A^H A
+            let r = acc.r();
+            let rhr = gemm(&transpose(&r, 'C').view(), &r);
+            let aha = gemm(&transpose(&a.view(), 'C').view(), &a.view());
+            allclose_epsilon(&rhr.view(), &aha.view(), 1.0e-10);
+
+            let res = gemm(&a.view(), &x.view()) - &b;
+            let norm = acc.residual_norm();
+            for j in 0..nrhs {
+                let rss = res.column(j).mapv(|v| F::abs(v) * F::abs(v)).sum();
+                assert_relative_eq!(
+                    norm[j].to_f64().unwrap(),
+                    rss.sqrt().to_f64().unwrap(),
+                    max_relative = 1.0e-10
+                );
+            }
+        }
+
+        // column-wise LQ accumulator: min |A^H X - B|
+        {
+            let a = random_matrix::<F>(n, rows, 'C'.into());
+            let b = random_matrix::<F>(rows, nrhs, 'R'.into());
+            let mut acc = LqAccumulator::<F>::new(n, nrhs);
+            for (start, end) in chunks {
+                acc.absorb(a.slice(s![.., start..end]), b.slice(s![start..end, ..])).unwrap();
+                assert_eq!(acc.cols_seen(), end);
+            }
+            let x = acc.solve().unwrap();
+            let ah = transpose(&a.view(), 'C');
+            let (x_ref, _) = GELS::default().a(ah.view()).b(b.view()).run().unwrap();
+            allclose_epsilon(&x.view(), &x_ref.view(), 1.0e-10);
+
+            let res = gemm(&ah.view(), &x.view()) - &b;
+            let norm = acc.residual_norm();
+            for j in 0..nrhs {
+                let rss = res.column(j).mapv(|v| F::abs(v) * F::abs(v)).sum();
+                assert_relative_eq!(
+                    norm[j].to_f64().unwrap(),
+                    rss.sqrt().to_f64().unwrap(),
+                    max_relative = 1.0e-10
+                );
+            }
+        }
+
+        // nothing absorbed: R is zero; mismatched block
+        let mut acc = QrAccumulator::<F>::new(n, nrhs);
+        assert!(acc.solve().is_err());
+        let a = random_matrix::<F>(3, n, 'C'.into());
+        let b = random_matrix::<F>(3, nrhs, 'C'.into());
+        assert!(acc.absorb(a.view(), b.slice(s![..2, ..])).is_err());
+        assert_eq!(acc.rows_seen(), 0);
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
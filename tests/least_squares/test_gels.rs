@@ -0,0 +1,66 @@
+use crate::util::*;
+use approx::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GELSNum + INVNum + TestFloat,
+    {
+        let (m, n, nrhs) = (8, 5, 3);
+        // overdetermined, normal equations A^H (A x - b) = 0
+        for layout in ['C', 'R'] {
+            let a = random_matrix::<F>(m, n, layout.into());
+            let b = random_matrix::<F>(m, nrhs, layout.into());
+            let (x, residual) = GELS::default().a(a.view()).b(b.view()).run().unwrap();
+            let x = x.into_owned();
+            assert_eq!(x.dim(), (n, nrhs));
+            let r = gemm(&a.view(), &x.view()) - &b;
+            let ahr = gemm(&transpose(&a.view(), 'C').view(), &r.view());
+            allclose_epsilon(&ahr.view(), &Array2::zeros((n, nrhs)).view(), 1.0);
+            let residual = residual.unwrap();
+            for j in 0..nrhs {
+                let rss = r.column(j).mapv(|v| F::abs(v) * F::abs(v)).sum();
+                assert_relative_eq!(
+                    residual[j].to_f64().unwrap(),
+                    rss.to_f64().unwrap(),
+                    max_relative = 1.0e-10
+                );
+            }
+        }
+        // underdetermined, minimum norm x = A^H (A A^H)^-1 b
+        {
+            let a = random_matrix::<F>(n, m, 'C'.into());
+            let b = random_matrix::<F>(n, nrhs, 'C'.into());
+            let (x, residual) = GELS::default().a(a.view()).b(b.view()).run().unwrap();
+            assert!(residual.is_none());
+            let aah_inv = inv(gemm(&a.view(), &transpose(&a.view(), 'C').view()).view()).unwrap();
+            let x_ref = gemm(&transpose(&a.view(), 'C').view(), &gemm(&aah_inv.view(), &b.view()).view());
+            allclose_epsilon(&x.view(), &x_ref.view(), 1.0e-10);
+        }
+        // transposed, b with max(m, n) rows overwritten in place
+        {
+            let a = random_matrix::<F>(m, n, 'C'.into());
+            let b = random_matrix::<F>(n, nrhs, 'C'.into());
+            let mut b_ext = Array2::<F>::zeros((m, nrhs).f());
+            b_ext.slice_mut(s![..n, ..]).assign(&b);
+            let (x, _) = GELS::default().a(a.view()).b(b_ext.view_mut()).trans('C').run().unwrap();
+            let x = x.into_owned();
+            assert_eq!(x.dim(), (m, nrhs));
+            allclose_epsilon(&b_ext.view(), &x.view(), 1.0e-10);
+            let ah = transpose(&a.view(), 'C');
+            allclose_epsilon(&gemm(&ah.view(), &x.view()).view(), &b.view(), 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,58 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GETSLSNum + GELSNum + TestFloat,
+    {
+        // tall-skinny and short-wide shapes, where TSQR/TSLQ factorization is used;
+        // solution and residual agree with GELS for both plain and transposed solve
+        let nrhs = 3;
+        for (m, n) in [(200, 4), (4, 200)] {
+            for trans in ['N', 'C'] {
+                for layout in ['C', 'R'] {
+                    let a = random_matrix::<F>(m, n, layout.into());
+                    let rows_in = if trans == 'N' { m } else { n };
+                    let b = random_matrix::<F>(rows_in, nrhs, layout.into());
+                    let (x, residual) = GETSLS::default().a(a.view()).b(b.view()).trans(trans).run().unwrap();
+                    let (x_ref, residual_ref) =
+                        GELS::default().a(a.view()).b(b.view()).trans(trans).run().unwrap();
+                    assert_eq!(x.view().dim(), x_ref.view().dim());
+                    allclose_epsilon(&x.view(), &x_ref.view(), 1.0e-10);
+                    assert_eq!(residual.is_some(), residual_ref.is_some());
+                    if let (Some(residual), Some(residual_ref)) = (residual, residual_ref) {
+                        let residual = residual.mapv(F::from_real);
+                        let residual_ref = residual_ref.mapv(F::from_real);
+                        allclose_epsilon(&residual.view(), &residual_ref.view(), 1.0e-10);
+                    }
+                }
+            }
+        }
+
+        // user-provided workspace, reused across calls
+        let mut work = Array1::<F>::zeros(4096);
+        for _ in 0..2 {
+            let a = random_matrix::<F>(200, 4, 'C'.into());
+            let b = random_matrix::<F>(200, nrhs, 'C'.into());
+            let (x, _) = GETSLS::default().a(a.view()).b(b.view()).work(work.view_mut()).run().unwrap();
+            let (x_ref, _) = GELS::default().a(a.view()).b(b.view()).run().unwrap();
+            allclose_epsilon(&x.view(), &x_ref.view(), 1.0e-10);
+        }
+
+        // invalid trans flag
+        let a = random_matrix::<F>(8, 5, 'C'.into());
+        let b = random_matrix::<F>(8, nrhs, 'C'.into());
+        assert!(GETSLS::default().a(a.view()).b(b.view()).trans('X').run().is_err());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,78 @@
+use crate::util::*;
+use approx::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GGLSENum + GGGLMNum + GELSNum + INVNum + TestFloat,
+    {
+        // GGLSE: min |c - A x| s.t. B x = d
+        {
+            let (m, n, p) = (7, 5, 2);
+            let a = random_matrix::<F>(m, n, 'C'.into());
+            let b = random_matrix::<F>(p, n, 'R'.into());
+            let c = random_array::<F>(m);
+            let d = random_array::<F>(p);
+            let (x, rss) = GGLSE::default().a(a.view()).b(b.view()).c(c.view()).d(d.view()).run().unwrap();
+            assert_eq!(x.len(), n);
+            // constraint
+            allclose_epsilon(&gemv(&b.view(), &x.view()).view(), &d.view(), 1.0e-10);
+            // residual
+            let r = gemv(&a.view(), &x.view()) - &c;
+            let rss_ref = r.mapv(|v| F::abs(v) * F::abs(v)).sum();
+            assert_relative_eq!(rss.to_f64().unwrap(), rss_ref.to_f64().unwrap(), max_relative = 1.0e-10);
+            // optimality: A^H (A x - c) = B^H λ for some λ
+            let g = gemv(&transpose(&a.view(), 'C').view(), &r.view());
+            let g = g.into_shape((n, 1)).unwrap();
+            let bh = transpose(&b.view(), 'C');
+            let (_, res) = GELS::default().a(bh.view()).b(g.view()).run().unwrap();
+            assert!(res.unwrap()[0].to_f64().unwrap() < 1.0e-20);
+        }
+        // GGGLM: min |y| s.t. d = A x + B y
+        {
+            let (n, m, p) = (6, 3, 4);
+            let a = random_matrix::<F>(n, m, 'C'.into());
+            let b = random_matrix::<F>(n, p, 'R'.into());
+            let d = random_array::<F>(n);
+            let (x, y) = GGGLM::default().a(a.view()).b(b.view()).d(d.view()).run().unwrap();
+            assert_eq!((x.len(), y.len()), (m, p));
+            // constraint
+            let d_calc = gemv(&a.view(), &x.view()) + gemv(&b.view(), &y.view());
+            allclose_epsilon(&d_calc.view(), &d.view(), 1.0e-10);
+            // optimality (KKT): y = B^H λ, A^H λ = 0; reference from [[A, B B^H], [0, A^H]] [x; λ] = [d; 0]
+            let bh = transpose(&b.view(), 'C');
+            let mut kkt = Array2::<F>::zeros((n + m, m + n));
+            kkt.slice_mut(s![..n, ..m]).assign(&a);
+            kkt.slice_mut(s![..n, m..]).assign(&gemm(&b.view(), &bh.view()));
+            kkt.slice_mut(s![n.., m..]).assign(&transpose(&a.view(), 'C'));
+            let mut rhs = Array1::<F>::zeros(n + m);
+            rhs.slice_mut(s![..n]).assign(&d);
+            let sol = gemv(&inv(kkt.view()).unwrap().view(), &rhs.view());
+            let x_ref = sol.slice(s![..m]).to_owned();
+            let y_ref = gemv(&bh.view(), &sol.slice(s![m..]));
+            allclose_epsilon(&x.view(), &x_ref.view(), 1.0e-10);
+            allclose_epsilon(&y.view(), &y_ref.view(), 1.0e-10);
+        }
+        // dimension check
+        {
+            let a = random_matrix::<F>(4, 3, 'C'.into());
+            let b = random_matrix::<F>(5, 3, 'C'.into());
+            let c = random_array::<F>(4);
+            let d = random_array::<F>(5);
+            let err = GGLSE::default().a(a.view()).b(b.view()).c(c.view()).d(d.view()).run().unwrap_err();
+            assert!(matches!(err, LapackError::InvalidDim(_)));
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
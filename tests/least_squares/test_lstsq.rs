@@ -0,0 +1,63 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: LSTSQNum + TestFloat,
+    {
+        let (m, n, r, nrhs) = (8, 5, 3, 2);
+        // rank-deficient A = X Y, with rank r
+        let x = random_matrix::<F>(m, r, 'C'.into());
+        let y = random_matrix::<F>(r, n, 'C'.into());
+        let a = gemm(&x.view(), &y.view());
+        let b = random_matrix::<F>(m, nrhs, 'R'.into());
+        let rcond = <F::RealFloat as NumCast>::from(1.0e-10).unwrap();
+
+        let mut sol_ref: Option<Array2<F>> = None;
+        for method in ['D', 'S', 'Y'] {
+            let output = LSTSQ::default().a(a.view()).b(b.view()).rcond(rcond).method(method).run().unwrap();
+            assert_eq!(output.rank, r);
+            let sol = output.x.into_owned();
+            assert_eq!(sol.dim(), (n, nrhs));
+            // normal equations
+            let res = gemm(&a.view(), &sol.view()) - &b;
+            let ahr = gemm(&transpose(&a.view(), 'C').view(), &res.view());
+            allclose_epsilon(&ahr.view(), &Array2::zeros((n, nrhs)).view(), 1.0);
+            // minimum norm solution is unique
+            match &sol_ref {
+                Some(sol_ref) => allclose_epsilon(&sol.view(), &sol_ref.view(), 1.0e-10),
+                None => sol_ref = Some(sol),
+            }
+            match method {
+                'Y' => {
+                    let mut jpvt = output.jpvt.unwrap().to_vec();
+                    assert!(output.s.is_none());
+                    jpvt.sort();
+                    assert_eq!(jpvt, (0..n).collect::<Vec<_>>());
+                },
+                _ => {
+                    let s = output.s.unwrap();
+                    assert!(output.jpvt.is_none());
+                    assert_eq!(s.len(), n);
+                    assert!(s[r] < s[0] * rcond);
+                },
+            }
+
+            // default rcond also reveals the rank
+            let output = LSTSQ::default().a(a.view()).b(b.view()).method(method).run().unwrap();
+            assert_eq!(output.rank, r);
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
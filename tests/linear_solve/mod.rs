@@ -0,0 +1,5 @@
+pub mod test_det;
+pub mod test_inv;
+pub mod test_packed;
+pub mod test_rfp;
+pub mod test_triangular;
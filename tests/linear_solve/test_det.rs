@@ -0,0 +1,93 @@
+use crate::util::*;
+use approx::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Reference determinant by Laplace expansion along first row.
+    fn det_laplace<F>(a: &ArrayView2<F>) -> F
+    where
+        F: TestFloat,
+    {
+        let n = a.nrows();
+        if n == 1 {
+            return a[[0, 0]];
+        }
+        let mut result = F::zero();
+        let mut sign = F::one();
+        for j in 0..n {
+            let cols: Vec<usize> = (0..n).filter(|&c| c != j).collect();
+            let minor = a.slice(s![1.., ..]).select(Axis(1), &cols);
+            result += sign * a[[0, j]] * det_laplace(&minor.view());
+            sign = F::zero() - sign;
+        }
+        return result;
+    }
+
+    fn test_generic<F>()
+    where
+        F: GETRFNum + POTRFNum + SYTRFNum + TestFloat,
+    {
+        let n = 5;
+        // general
+        {
+            let a = random_matrix::<F>(n, n, 'R'.into());
+            let d_ref = det_laplace(&a.view());
+            let d = det(a.view()).unwrap();
+            allclose_epsilon(&arr0(d).view(), &arr0(d_ref).view(), 1.0e-10);
+            let (sign, logabs) = slogdet(a.view()).unwrap();
+            assert_abs_diff_eq!(
+                logabs.to_f64().unwrap(),
+                F::abs(d_ref).ln().to_f64().unwrap(),
+                epsilon = 1.0e-8
+            );
+            assert_abs_diff_eq!(F::abs(sign).to_f64().unwrap(), 1.0, epsilon = 1.0e-10);
+            assert_abs_diff_eq!(logdet(a.view()).unwrap().to_f64().unwrap(), logabs.to_f64().unwrap());
+        }
+        // singular
+        {
+            let mut a = random_matrix::<F>(n, n, 'C'.into());
+            a.row_mut(2).fill(F::zero());
+            let (sign, logabs) = slogdet(a.view()).unwrap();
+            assert_eq!(sign, F::zero());
+            assert!(logabs.is_infinite() && logabs < F::RealFloat::zero());
+            assert_eq!(det(a.view()).unwrap(), F::zero());
+        }
+        // positive definite
+        {
+            let x = random_matrix::<F>(n, n, 'C'.into());
+            let a = gemm(&x.view(), &transpose(&x.view(), 'C').view());
+            let d_ref = det_laplace(&a.view());
+            for uplo in ['U', 'L'] {
+                let d = det_po(a.view(), uplo).unwrap();
+                allclose_epsilon(&arr0(d).view(), &arr0(d_ref).view(), 1.0e-10);
+                let l = logdet_po(a.view(), uplo).unwrap();
+                assert_abs_diff_eq!(
+                    l.to_f64().unwrap(),
+                    F::abs(d_ref).ln().to_f64().unwrap(),
+                    epsilon = 1.0e-8
+                );
+            }
+        }
+        // symmetric / Hermitian indefinite
+        for uplo in ['U', 'L'] {
+            let a = random_matrix::<F>(n, n, 'C'.into());
+            let a_sy = symmetrize(&a.view(), uplo);
+            let d = det_sy(a_sy.view(), uplo).unwrap();
+            allclose_epsilon(&arr0(d).view(), &arr0(det_laplace(&a_sy.view())).view(), 1.0e-10);
+            let a_he = hermitianize(&a.view(), uplo);
+            let d = det_he(a_he.view(), uplo).unwrap();
+            allclose_epsilon(&arr0(d).view(), &arr0(det_laplace(&a_he.view())).view(), 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
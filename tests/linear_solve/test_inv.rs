@@ -0,0 +1,107 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Zero strict triangle opposite to `uplo`.
+    fn zero_opposite<F>(a: &mut Array2<F>, uplo: char)
+    where
+        F: TestFloat,
+    {
+        a.indexed_iter_mut().for_each(|((i, j), x)| {
+            if (uplo == 'U' && i > j) || (uplo == 'L' && i < j) {
+                *x = F::zero();
+            }
+        });
+    }
+
+    fn test_generic<F>()
+    where
+        F: INVNum + TestFloat,
+    {
+        let n = 6;
+        let eye = Array2::<F>::eye(n);
+        // general
+        for layout in ['C', 'R'] {
+            let a = random_matrix::<F>(n, n, layout.into());
+            let a_inv = inv(a.view()).unwrap();
+            allclose_epsilon(&gemm(&a.view(), &a_inv.view()).view(), &eye.view(), 1.0e-10);
+
+            let mut a_mut = a.clone();
+            INV::default().a(a_mut.view_mut()).run().unwrap();
+            allclose_epsilon(&a_mut.view(), &a_inv.view(), 1.0e-10);
+        }
+        // Hermitian positive definite
+        for uplo in ['U', 'L'] {
+            let x = random_matrix::<F>(n, n, 'C'.into());
+            let a = gemm(&x.view(), &transpose(&x.view(), 'C').view());
+            let mut a_tri = a.clone();
+            zero_opposite(&mut a_tri, uplo);
+            let a_inv = INV::default()
+                .a(a_tri.view())
+                .structure(MatrixStructure::HermitianPositiveDefinite)
+                .uplo(uplo)
+                .run()
+                .unwrap()
+                .into_owned();
+            allclose_epsilon(&gemm(&a.view(), &a_inv.view()).view(), &eye.view(), 1.0e-10);
+        }
+        // symmetric / Hermitian indefinite
+        for uplo in ['U', 'L'] {
+            let x = random_matrix::<F>(n, n, 'C'.into());
+            let a = symmetrize(&x.view(), uplo);
+            let a_inv = INV::default()
+                .a(a.view())
+                .structure(MatrixStructure::SymmetricIndefinite)
+                .uplo(uplo)
+                .run()
+                .unwrap()
+                .into_owned();
+            allclose_epsilon(&gemm(&a.view(), &a_inv.view()).view(), &eye.view(), 1.0e-10);
+
+            let a = hermitianize(&x.view(), uplo);
+            let a_inv = INV::default()
+                .a(a.view())
+                .structure(MatrixStructure::HermitianIndefinite)
+                .uplo(uplo)
+                .run()
+                .unwrap()
+                .into_owned();
+            allclose_epsilon(&gemm(&a.view(), &a_inv.view()).view(), &eye.view(), 1.0e-10);
+        }
+        // triangular
+        for uplo in ['U', 'L'] {
+            let mut x = random_matrix::<F>(n, n, 'C'.into());
+            // diagonally dominant, to be well-conditioned
+            let shift = F::from_real(<F::RealFloat as NumCast>::from(n).unwrap());
+            x.diag_mut().mapv_inplace(|v| v + shift);
+            let mut a = x.clone();
+            zero_opposite(&mut a, uplo);
+            let a_inv = INV::default()
+                .a(x.view())
+                .structure(MatrixStructure::Triangular)
+                .uplo(uplo)
+                .run()
+                .unwrap()
+                .into_owned();
+            allclose_epsilon(&gemm(&a.view(), &a_inv.view()).view(), &eye.view(), 1.0e-10);
+        }
+        // singular
+        {
+            let mut a = random_matrix::<F>(n, n, 'C'.into());
+            a.column_mut(3).fill(F::zero());
+            let err = inv(a.view()).unwrap_err();
+            assert!(matches!(err, LapackError::FailedCheck(_)));
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,113 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: PackedMatrixNum + PPSVNum + PPTRFNum + PPTRSNum + PPTRINum + PPCONNum + TestFloat,
+    {
+        let n = 5;
+        let a = hpd_matrix::<F>(n);
+        let b = random_matrix::<F>(n, 3, 'R'.into());
+        // pack and unpack
+        {
+            let ap = PackedMatrix::from_dense(&a.view(), 'L').unwrap();
+            assert_eq!(ap.ap.len(), n * (n + 1) / 2);
+            let a_unpack = ap.to_dense().unwrap();
+            let mut a_tril = Array2::<F>::zeros((n, n));
+            tril_assign(&mut a_tril.view_mut(), &a.view(), 'L');
+            allclose_epsilon(&a_unpack.view(), &a_tril.view(), 1.0e-10);
+        }
+        // ppsv
+        {
+            let ap = PackedMatrix::from_dense(&a.view(), 'U').unwrap();
+            let (_, x) = PPSV::<F>::default().ap(ap).b(b.view()).run().unwrap();
+            let x = x.into_owned();
+            allclose_epsilon(&gemm(&a.view(), &x.view()).view(), &b.view(), 1.0e-10);
+        }
+        // pptrf, pptrs, ppcon
+        {
+            let ap = PackedMatrix::from_dense(&a.view(), 'L').unwrap();
+            let ap = PPTRF::<F>::default().ap(ap).run().unwrap();
+            let mut x = b.clone();
+            PPTRS::<F>::default().ap(&ap).b(x.view_mut()).run().unwrap();
+            allclose_epsilon(&gemm(&a.view(), &x.view()).view(), &b.view(), 1.0e-10);
+            let anorm = a.mapv(F::abs).sum_axis(Axis(0)).fold(F::RealFloat::zero(), |acc, &v| acc.max(v));
+            let rcond = PPCON::<F>::default().ap(&ap).anorm(anorm).run().unwrap();
+            assert!(rcond > F::RealFloat::zero() && rcond <= F::RealFloat::one());
+        }
+        // pptri
+        {
+            let ap = PackedMatrix::from_dense(&a.view(), 'L').unwrap();
+            let ap = PPTRF::<F>::default().ap(ap).run().unwrap();
+            let ap_inv = PPTRI::<F>::default().ap(ap).run().unwrap();
+            let a_inv = hermitianize(&ap_inv.to_dense().unwrap().view(), 'L');
+            let eye = Array2::<F>::eye(n);
+            allclose_epsilon(&gemm(&a.view(), &a_inv.view()).view(), &eye.view(), 1.0e-10);
+        }
+    }
+
+    fn test_generic_indefinite<F>(hermi: bool)
+    where
+        F: PackedMatrixNum + SPSVNum + SPTRFNum + SPTRSNum + SPTRINum + SPCONNum + TestFloat,
+    {
+        let n = 5;
+        let a = random_matrix::<F>(n, n, 'C'.into());
+        let a = if hermi { hermitianize(&a.view(), 'U') } else { symmetrize(&a.view(), 'U') };
+        let b = random_matrix::<F>(n, 2, 'C'.into());
+        // spsv / hpsv
+        {
+            let ap = PackedMatrix::from_dense(&a.view(), 'U').unwrap();
+            let (_, _, x) = match hermi {
+                true => HPSV::<F>::default().ap(ap).b(b.view()).run().unwrap(),
+                false => SPSV::<F>::default().ap(ap).b(b.view()).run().unwrap(),
+            };
+            let x = x.into_owned();
+            allclose_epsilon(&gemm(&a.view(), &x.view()).view(), &b.view(), 1.0e-10);
+        }
+        // sptrf, sptrs, spcon, sptri
+        {
+            let ap = PackedMatrix::from_dense(&a.view(), 'L').unwrap();
+            let (ap, ipiv) = match hermi {
+                true => HPTRF::<F>::default().ap(ap).run().unwrap(),
+                false => SPTRF::<F>::default().ap(ap).run().unwrap(),
+            };
+            let x = match hermi {
+                true => HPTRS::<F>::default().ap(&ap).ipiv(&ipiv).b(b.view()).run().unwrap(),
+                false => SPTRS::<F>::default().ap(&ap).ipiv(&ipiv).b(b.view()).run().unwrap(),
+            };
+            let x = x.into_owned();
+            allclose_epsilon(&gemm(&a.view(), &x.view()).view(), &b.view(), 1.0e-10);
+
+            let anorm = a.mapv(F::abs).sum_axis(Axis(0)).fold(F::RealFloat::zero(), |acc, &v| acc.max(v));
+            let rcond = match hermi {
+                true => HPCON::<F>::default().ap(&ap).ipiv(&ipiv).anorm(anorm).run().unwrap(),
+                false => SPCON::<F>::default().ap(&ap).ipiv(&ipiv).anorm(anorm).run().unwrap(),
+            };
+            assert!(rcond > F::RealFloat::zero() && rcond <= F::RealFloat::one());
+
+            let ap_inv = match hermi {
+                true => HPTRI::<F>::default().ap(ap).ipiv(&ipiv).run().unwrap(),
+                false => SPTRI::<F>::default().ap(ap).ipiv(&ipiv).run().unwrap(),
+            };
+            let a_inv = ap_inv.to_dense().unwrap();
+            let a_inv = if hermi { hermitianize(&a_inv.view(), 'L') } else { symmetrize(&a_inv.view(), 'L') };
+            let eye = Array2::<F>::eye(n);
+            allclose_epsilon(&gemm(&a.view(), &a_inv.view()).view(), &eye.view(), 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+        test_generic_indefinite::<f64>(false);
+        test_generic_indefinite::<c64>(false);
+        test_generic_indefinite::<c64>(true);
+    }
+}
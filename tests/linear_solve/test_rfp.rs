@@ -0,0 +1,71 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>(n: usize, transr: char, uplo: char)
+    where
+        F: RfpMatrixNum + PFTRFNum + PFTRSNum + PFTRINum + TFSMNum + SFRKNum + PackedMatrixNum + TestFloat,
+    {
+        let a = hpd_matrix::<F>(n);
+        let b = random_matrix::<F>(n, 3, 'R'.into());
+        let mut a_tri = Array2::<F>::zeros((n, n));
+        tril_assign(&mut a_tri.view_mut(), &a.view(), uplo);
+        // dense and packed conversion
+        {
+            let arf = RfpMatrix::from_dense(&a.view(), transr, uplo).unwrap();
+            allclose_epsilon(&arf.to_dense().unwrap().view(), &a_tri.view(), 1.0e-10);
+            let ap = arf.to_packed().unwrap();
+            allclose_epsilon(&ap.to_dense().unwrap().view(), &a_tri.view(), 1.0e-10);
+            let arf2 = RfpMatrix::from_packed(&ap, transr).unwrap();
+            allclose_epsilon(&arf2.view(), &arf.view(), 1.0e-10);
+        }
+        // pftrf, pftrs
+        {
+            let arf = RfpMatrix::from_dense(&a.view(), transr, uplo).unwrap();
+            let arf = PFTRF::<F>::default().a(arf).run().unwrap();
+            let x = PFTRS::<F>::default().a(&arf).b(b.view()).run().unwrap().into_owned();
+            allclose_epsilon(&gemm(&a.view(), &x.view()).view(), &b.view(), 1.0e-10);
+            // tfsm with cholesky factor, two triangular solves
+            let (t1, t2) = if uplo == 'U' { ('C', 'N') } else { ('N', 'C') };
+            let y = TFSM::<F>::default().a(&arf).b(b.view()).trans(t1).run().unwrap().into_owned();
+            let y = TFSM::<F>::default().a(&arf).b(y.view()).trans(t2).run().unwrap().into_owned();
+            allclose_epsilon(&y.view(), &x.view(), 1.0e-10);
+        }
+        // pftri
+        {
+            let arf = RfpMatrix::from_dense(&a.view(), transr, uplo).unwrap();
+            let arf = PFTRF::<F>::default().a(arf).run().unwrap();
+            let arf = PFTRI::<F>::default().a(arf).run().unwrap();
+            let a_inv = hermitianize(&arf.to_dense().unwrap().view(), uplo);
+            let eye = Array2::<F>::eye(n);
+            allclose_epsilon(&gemm(&a.view(), &a_inv.view()).view(), &eye.view(), 1.0e-10);
+        }
+        // sfrk/hfrk
+        {
+            let x = random_matrix::<F>(n, 4, 'C'.into());
+            let c = RfpMatrix::from_dense(&Array2::<F>::zeros((n, n)).view(), transr, uplo).unwrap();
+            let c = SFRK::<F>::default().a(x.view()).c(c).run().unwrap();
+            let c_ref = gemm(&x.view(), &transpose(&x.view(), 'C').view());
+            let mut c_ref_tri = Array2::<F>::zeros((n, n));
+            tril_assign(&mut c_ref_tri.view_mut(), &c_ref.view(), uplo);
+            allclose_epsilon(&c.to_dense().unwrap().view(), &c_ref_tri.view(), 1.0e-10);
+            assert!(SFRK::<F>::default().a(x.view()).c(c).trans('X').run().is_err());
+        }
+    }
+
+    #[test]
+    fn test() {
+        for n in [5, 6] {
+            for transr in ['N', 'T'] {
+                for uplo in ['U', 'L'] {
+                    test_generic::<f64>(n, transr, uplo);
+                    test_generic::<c64>(n, transr, uplo);
+                }
+            }
+        }
+    }
+}
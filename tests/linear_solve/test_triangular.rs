@@ -0,0 +1,105 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>(uplo: char, layout: char)
+    where
+        F: TRTRSNum + TRTRINum + TRCONNum + LAUUMNum + TPTRSNum + TBTRSNum + PackedMatrixNum + TestFloat,
+    {
+        let n = 5;
+        // full matrix holding a triangular factor, with garbage in the other triangle
+        let mut a_full = random_matrix::<F>(n, n, layout.into());
+        for i in 0..n {
+            a_full[[i, i]] += F::from_real(NumCast::from(n).unwrap());
+        }
+        let mut a_tri = Array2::<F>::zeros((n, n));
+        tril_assign(&mut a_tri.view_mut(), &a_full.view(), uplo);
+        let b = random_matrix::<F>(n, 3, layout.into());
+        let eye = Array2::<F>::eye(n);
+
+        // trtrs
+        for trans in ['N', 'T', 'C'] {
+            let x = TRTRS::<F>::default().a(a_full.view()).b(b.view()).uplo(uplo).trans(trans).run().unwrap();
+            let x = x.into_owned();
+            let op_a = transpose(&a_tri.view(), trans);
+            allclose_epsilon(&gemm(&op_a.view(), &x.view()).view(), &b.view(), 1.0e-10);
+        }
+        // trtri, other triangle untouched
+        {
+            let mut a = a_full.clone();
+            TRTRI::<F>::default().a(a.view_mut()).uplo(uplo).run().unwrap();
+            let mut a_inv = Array2::<F>::zeros((n, n));
+            tril_assign(&mut a_inv.view_mut(), &a.view(), uplo);
+            allclose_epsilon(&gemm(&a_inv.view(), &a_tri.view()).view(), &eye.view(), 1.0e-10);
+            for i in 0..n {
+                for j in 0..n {
+                    if (uplo == 'U' && i > j) || (uplo == 'L' && i < j) {
+                        assert_eq!(a[[i, j]], a_full[[i, j]]);
+                    }
+                }
+            }
+        }
+        // trcon
+        {
+            let rcond = TRCON::<F>::default().a(a_full.view()).uplo(uplo).run().unwrap();
+            assert!(rcond > F::RealFloat::zero() && rcond <= F::RealFloat::one());
+        }
+        // lauum
+        {
+            let a = LAUUM::<F>::default().a(a_full.view()).uplo(uplo).run().unwrap().into_owned();
+            let mut a_prod = Array2::<F>::zeros((n, n));
+            tril_assign(&mut a_prod.view_mut(), &a.view(), uplo);
+            let a_ref = match uplo {
+                'U' => gemm(&a_tri.view(), &transpose(&a_tri.view(), 'C').view()),
+                _ => gemm(&transpose(&a_tri.view(), 'C').view(), &a_tri.view()),
+            };
+            let mut a_ref_tri = Array2::<F>::zeros((n, n));
+            tril_assign(&mut a_ref_tri.view_mut(), &a_ref.view(), uplo);
+            allclose_epsilon(&a_prod.view(), &a_ref_tri.view(), 1.0e-10);
+        }
+        // tptrs
+        {
+            let ap = PackedMatrix::from_dense(&a_full.view(), uplo).unwrap();
+            let x = TPTRS::<F>::default().ap(&ap).b(b.view()).run().unwrap().into_owned();
+            allclose_epsilon(&gemm(&a_tri.view(), &x.view()).view(), &b.view(), 1.0e-10);
+        }
+        // tbtrs, bidiagonal
+        {
+            let kd = 1;
+            let mut a_band = Array2::<F>::zeros((n, n));
+            let mut ab = Array2::<F>::zeros((kd + 1, n).f());
+            for j in 0..n {
+                for i in 0..n {
+                    let in_band = match uplo {
+                        'U' => i <= j && j <= i + kd,
+                        _ => j <= i && i <= j + kd,
+                    };
+                    if in_band {
+                        a_band[[i, j]] = a_full[[i, j]];
+                        match uplo {
+                            'U' => ab[[kd + i - j, j]] = a_full[[i, j]],
+                            _ => ab[[i - j, j]] = a_full[[i, j]],
+                        };
+                    }
+                }
+            }
+            let x = TBTRS::<F>::default().ab(ab.view()).b(b.view()).uplo(uplo).run().unwrap().into_owned();
+            allclose_epsilon(&gemm(&a_band.view(), &x.view()).view(), &b.view(), 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn test() {
+        for uplo in ['U', 'L'] {
+            for layout in ['R', 'C'] {
+                test_generic::<f64>(uplo, layout);
+                test_generic::<c64>(uplo, layout);
+            }
+        }
+    }
+}
@@ -1,2 +1,7 @@
+pub mod householder;
+pub mod least_squares;
+pub mod linear_solve;
+pub mod orthogonal_factor;
+pub mod singular_value;
 pub mod symmetric_eigenvalues;
 pub mod util;
@@ -0,0 +1,6 @@
+pub mod test_geqp3;
+pub mod test_geqrt;
+pub mod test_lq_ql_rq;
+pub mod test_qr;
+pub mod test_rz;
+pub mod test_tsqr;
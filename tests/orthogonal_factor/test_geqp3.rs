@@ -0,0 +1,44 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GEQP3Num + ORGQRNum + ORMQRNum + TestFloat,
+    {
+        let (m, n, r) = (8, 6, 3);
+        let x = random_matrix::<F>(m, r, 'C'.into());
+        let y = random_matrix::<F>(r, n, 'C'.into());
+        let a = gemm(&x.view(), &y.view());
+
+        // A P = Q R, and rank estimation
+        let qrp = GEQP3::default().a(a.view()).run().unwrap();
+        let mut jpvt = qrp.jpvt.to_vec();
+        let ap = a.select(Axis(1), &jpvt);
+        let qr = gemm(&qrp.qr.q_economy().unwrap().view(), &qrp.qr.r().view());
+        allclose_epsilon(&qr.view(), &ap.view(), 1.0e-10);
+        assert_eq!(qrp.rank(None), r);
+        jpvt.sort();
+        assert_eq!(jpvt, (0..n).collect::<Vec<_>>());
+
+        // pinned leading columns
+        let a = random_matrix::<F>(m, n, 'R'.into());
+        let pin = array![0, 0, 0, 0, 1, 1];
+        let qrp = GEQP3::default().a(a.view()).jpvt(pin.view()).run().unwrap();
+        assert_eq!(qrp.jpvt.slice(s![..2]).to_vec(), vec![4, 5]);
+        assert_eq!(qrp.rank(None), n);
+        let ap = a.select(Axis(1), &qrp.jpvt.to_vec());
+        let qr = gemm(&qrp.qr.q_economy().unwrap().view(), &qrp.qr.r().view());
+        allclose_epsilon(&qr.view(), &ap.view(), 1.0e-10);
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,72 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GEQRTNum + GEMQRTNum + GEQRFNum + ORGQRNum + ORMQRNum + TestFloat,
+    {
+        for (m, n, nb, layout) in [(9, 5, 2, 'C'), (5, 9, 3, 'R'), (6, 6, 6, 'R'), (6, 6, 1, 'C')] {
+            let k = m.min(n);
+            let a = random_matrix::<F>(m, n, layout.into());
+            let (v, t) = GEQRT::default().a(a.view()).nb(nb).run().unwrap();
+            let v = v.into_owned();
+            assert_eq!(t.dim(), (nb, k));
+
+            // R agrees with unblocked factorization
+            let qr = GEQRF::default().a(a.view()).run().unwrap();
+            let mut r = v.slice(s![..k, ..]).to_owned();
+            for i in 0..k {
+                for j in 0..i {
+                    r[[i, j]] = F::zero();
+                }
+            }
+            allclose_epsilon(&r.view(), &qr.r().view(), 1.0e-10);
+
+            // Q applied from the left reconstructs A
+            let q_full = qr.q_complete().unwrap();
+            let mut r_full = Array2::<F>::zeros((m, n));
+            r_full.slice_mut(s![..k, ..]).assign(&r);
+            let qr_ = GEMQRT::default().v(v.view()).t(t.view()).c(r_full.view()).run().unwrap();
+            allclose_epsilon(&qr_.view(), &a.view(), 1.0e-10);
+
+            // left and right application against explicit Q
+            let c = random_matrix::<F>(m, 3, 'R'.into());
+            for trans in ['N', 'C'] {
+                let qc = GEMQRT::default().v(v.view()).t(t.view()).c(c.view()).trans(trans).run().unwrap();
+                let qc_ref = gemm(&transpose(&q_full.view(), trans).view(), &c.view());
+                allclose_epsilon(&qc.view(), &qc_ref.view(), 1.0e-10);
+            }
+            let c = random_matrix::<F>(3, m, 'C'.into());
+            for trans in ['N', 'C'] {
+                let mut c_mut = c.clone();
+                GEMQRT::default()
+                    .v(v.view())
+                    .t(t.view())
+                    .c(c_mut.view_mut())
+                    .side('R')
+                    .trans(trans)
+                    .run()
+                    .unwrap();
+                let cq_ref = gemm(&c.view(), &transpose(&q_full.view(), trans).view());
+                allclose_epsilon(&c_mut.view(), &cq_ref.view(), 1.0e-10);
+            }
+        }
+
+        // block size out of range
+        let a = random_matrix::<F>(5, 4, 'C'.into());
+        assert!(GEQRT::default().a(a.view()).nb(5).run().is_err());
+        assert!(GEQRT::default().a(a.view()).nb(0).run().is_err());
+        assert!(GEQRT::<F>::default().a(a.view()).run().is_err());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
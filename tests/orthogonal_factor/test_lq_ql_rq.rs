@@ -0,0 +1,111 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Check `apply_q` against explicit complete `Q` of shape `(nq, nq)`.
+    fn check_apply_q<F, Func>(q_full: &Array2<F>, apply_q: Func)
+    where
+        F: TestFloat,
+        Func: Fn(char, char, ArrayViewMut2<F>),
+    {
+        let nq = q_full.nrows();
+        for trans in ['N', 'C'] {
+            let c = random_matrix::<F>(nq, 3, 'R'.into());
+            let mut qc = c.clone();
+            apply_q('L', trans, qc.view_mut());
+            let qc_ref = gemm(&transpose(&q_full.view(), trans).view(), &c.view());
+            allclose_epsilon(&qc.view(), &qc_ref.view(), 1.0e-10);
+
+            let c = random_matrix::<F>(3, nq, 'C'.into());
+            let mut cq = c.clone();
+            apply_q('R', trans, cq.view_mut());
+            let cq_ref = gemm(&c.view(), &transpose(&q_full.view(), trans).view());
+            allclose_epsilon(&cq.view(), &cq_ref.view(), 1.0e-10);
+        }
+    }
+
+    fn check_unitary<F>(q: &Array2<F>)
+    where
+        F: TestFloat,
+    {
+        let n = q.nrows();
+        let qqh = gemm(&q.view(), &transpose(&q.view(), 'C').view());
+        allclose_epsilon(&qqh.view(), &Array2::eye(n).view(), 1.0e-10);
+    }
+
+    fn test_generic<F>()
+    where
+        F: GELQFNum
+            + ORGLQNum
+            + ORMLQNum
+            + GEQLFNum
+            + ORGQLNum
+            + ORMQLNum
+            + GERQFNum
+            + ORGRQNum
+            + ORMRQNum
+            + TestFloat,
+    {
+        for (m, n, layout) in [(7, 4, 'C'), (4, 7, 'R'), (5, 5, 'C')] {
+            let k = m.min(n);
+            let a = random_matrix::<F>(m, n, layout.into());
+
+            // LQ
+            let lq = GELQF::default().a(a.view()).run().unwrap();
+            let (l, q) = (lq.l(), lq.q().unwrap());
+            assert_eq!((l.dim(), q.dim()), ((m, k), (k, n)));
+            allclose_epsilon(&gemm(&l.view(), &q.view()).view(), &a.view(), 1.0e-10);
+            check_unitary(&q);
+            let q_full = lq.q_complete().unwrap();
+            check_unitary(&q_full);
+            allclose_epsilon(&q_full.slice(s![..k, ..]), &q.view(), 1.0e-10);
+            check_apply_q(&q_full, |side, trans, c| {
+                lq.apply_q(side, trans, c).unwrap();
+            });
+
+            // QL
+            let ql = GEQLF::default().a(a.view()).run().unwrap();
+            let (q, l) = (ql.q().unwrap(), ql.l());
+            assert_eq!((q.dim(), l.dim()), ((m, k), (k, n)));
+            allclose_epsilon(&gemm(&q.view(), &l.view()).view(), &a.view(), 1.0e-10);
+            check_unitary(&transpose(&q.view(), 'C'));
+            let q_full = ql.q_complete().unwrap();
+            check_unitary(&q_full);
+            allclose_epsilon(&q_full.slice(s![.., m - k..]), &q.view(), 1.0e-10);
+            check_apply_q(&q_full, |side, trans, c| {
+                ql.apply_q(side, trans, c).unwrap();
+            });
+            // raw `GEQLF` output could be passed to builder; reflectors are in the last `k` columns
+            let c = random_matrix::<F>(m, 3, layout.into());
+            let qc = ORMQL::default().a(ql.a.view()).tau(ql.tau.view()).c(c.view()).run().unwrap();
+            allclose_epsilon(&qc.view(), &gemm(&q_full.view(), &c.view()).view(), 1.0e-10);
+
+            // RQ
+            let rq = GERQF::default().a(a.view()).run().unwrap();
+            let (r, q) = (rq.r(), rq.q().unwrap());
+            assert_eq!((r.dim(), q.dim()), ((m, k), (k, n)));
+            allclose_epsilon(&gemm(&r.view(), &q.view()).view(), &a.view(), 1.0e-10);
+            check_unitary(&q);
+            let q_full = rq.q_complete().unwrap();
+            check_unitary(&q_full);
+            allclose_epsilon(&q_full.slice(s![n - k.., ..]), &q.view(), 1.0e-10);
+            check_apply_q(&q_full, |side, trans, c| {
+                rq.apply_q(side, trans, c).unwrap();
+            });
+            // raw `GERQF` output could be passed to builder; reflectors are in the last `k` rows
+            let c = random_matrix::<F>(n, 3, layout.into());
+            let qc = ORMRQ::default().a(rq.a.view()).tau(rq.tau.view()).c(c.view()).run().unwrap();
+            allclose_epsilon(&qc.view(), &gemm(&q_full.view(), &c.view()).view(), 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,61 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GEQRFNum + ORGQRNum + ORMQRNum + TestFloat,
+    {
+        for (m, n, layout) in [(7, 4, 'C'), (4, 7, 'R'), (5, 5, 'R')] {
+            let k = m.min(n);
+            let a = random_matrix::<F>(m, n, layout.into());
+            let qr = GEQRF::default().a(a.view()).run().unwrap();
+
+            // economy Q R = A
+            let q = qr.q_economy().unwrap();
+            let r = qr.r();
+            assert_eq!(q.dim(), (m, k));
+            assert_eq!(r.dim(), (k, n));
+            allclose_epsilon(&gemm(&q.view(), &r.view()).view(), &a.view(), 1.0e-10);
+            let qhq = gemm(&transpose(&q.view(), 'C').view(), &q.view());
+            allclose_epsilon(&qhq.view(), &Array2::eye(k).view(), 1.0e-10);
+            for i in 0..k {
+                for j in 0..i {
+                    assert_eq!(r[[i, j]], F::zero());
+                }
+            }
+
+            // complete Q
+            let q_full = qr.q_complete().unwrap();
+            assert_eq!(q_full.dim(), (m, m));
+            let qhq = gemm(&transpose(&q_full.view(), 'C').view(), &q_full.view());
+            allclose_epsilon(&qhq.view(), &Array2::eye(m).view(), 1.0e-10);
+            allclose_epsilon(&q_full.slice(s![.., ..k]), &q.view(), 1.0e-10);
+
+            // implicit Q application
+            let c = random_matrix::<F>(m, 3, 'R'.into());
+            for trans in ['N', 'C'] {
+                let qc = qr.apply_q('L', trans, c.view()).unwrap();
+                let qc_ref = gemm(&transpose(&q_full.view(), trans).view(), &c.view());
+                allclose_epsilon(&qc.view(), &qc_ref.view(), 1.0e-10);
+            }
+            let c = random_matrix::<F>(3, m, 'C'.into());
+            for trans in ['N', 'C'] {
+                let mut c_mut = c.clone();
+                qr.apply_q('R', trans, c_mut.view_mut()).unwrap();
+                let cq_ref = gemm(&c.view(), &transpose(&q_full.view(), trans).view());
+                allclose_epsilon(&c_mut.view(), &cq_ref.view(), 1.0e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
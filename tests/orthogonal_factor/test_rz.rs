@@ -0,0 +1,67 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: TZRZFNum + ORMRZNum + GEQP3Num + ORGQRNum + ORMQRNum + TRTRSNum + GESDDNum + TestFloat,
+    {
+        // A = R Z for upper trapezoidal A
+        for (m, n, layout) in [(4, 9, 'C'), (4, 9, 'R'), (5, 5, 'C')] {
+            let mut a = random_matrix::<F>(m, n, layout.into());
+            a.indexed_iter_mut().filter(|((i, j), _)| i > j).for_each(|(_, x)| *x = F::zero());
+            let rz = TZRZF::default().a(a.view()).run().unwrap();
+
+            let r = rz.r();
+            let z = rz.z_economy().unwrap();
+            assert_eq!(r.dim(), (m, m));
+            assert_eq!(z.dim(), (m, n));
+            allclose_epsilon(&gemm(&r.view(), &z.view()).view(), &a.view(), 1.0e-10);
+
+            let z_full = rz.z_complete().unwrap();
+            let zzh = gemm(&z_full.view(), &transpose(&z_full.view(), 'C').view());
+            allclose_epsilon(&zzh.view(), &Array2::eye(n).view(), 1.0e-10);
+            allclose_epsilon(&z_full.slice(s![..m, ..]), &z.view(), 1.0e-10);
+
+            let c = random_matrix::<F>(n, 3, 'R'.into());
+            for trans in ['N', 'C'] {
+                let zc = rz.apply_z('L', trans, c.view()).unwrap();
+                let zc_ref = gemm(&transpose(&z_full.view(), trans).view(), &c.view());
+                allclose_epsilon(&zc.view(), &zc_ref.view(), 1.0e-10);
+            }
+        }
+
+        // complete orthogonal decomposition A P = Q [R11, 0; 0, 0] Z of rank-deficient matrix,
+        // minimum norm least squares solution against pseudo-inverse
+        let (m, n, rank, nrhs) = (8, 6, 3, 2);
+        let a = gemm(
+            &random_matrix::<F>(m, rank, 'C'.into()).view(),
+            &random_matrix::<F>(rank, n, 'C'.into()).view(),
+        );
+        let b = random_matrix::<F>(m, nrhs, 'C'.into());
+        let qrp = GEQP3::default().a(a.view()).run().unwrap();
+        assert_eq!(qrp.rank(None), rank);
+        let rz = TZRZF::default().a(qrp.qr.r().slice(s![..rank, ..])).run().unwrap();
+        let qhb = qrp.qr.apply_q('L', 'C', b.view()).unwrap().into_owned();
+        let mut w = Array2::<F>::zeros((n, nrhs));
+        w.slice_mut(s![..rank, ..]).assign(&qhb.slice(s![..rank, ..]));
+        TRTRS::default().a(rz.r().view()).b(w.slice_mut(s![..rank, ..])).run().unwrap();
+        let xp = rz.apply_z('L', 'C', w.view()).unwrap().into_owned();
+        let mut x = Array2::<F>::zeros((n, nrhs));
+        for (j, &p) in qrp.jpvt.iter().enumerate() {
+            x.row_mut(p).assign(&xp.row(j));
+        }
+        let x_ref = gemm(&pinv(a.view(), None).unwrap().view(), &b.view());
+        allclose_epsilon(&x.view(), &x_ref.view(), 1.0e-10);
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,85 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// `Q [R; 0]` by `GEMQRT`, for Householder form `(v, t)` of `(m, n)` matrix.
+    fn reconstruct_qrt<F>(v: &Array2<F>, t: &Array2<F>) -> Array2<F>
+    where
+        F: GEMQRTNum + TestFloat,
+    {
+        let (m, n) = v.dim();
+        let mut r = Array2::<F>::zeros((m, n));
+        for i in 0..m.min(n) {
+            for j in i..n {
+                r[[i, j]] = v[[i, j]];
+            }
+        }
+        GEMQRT::default().v(v.view()).t(t.view()).c(r.view()).run().unwrap().into_owned()
+    }
+
+    fn test_generic<F>()
+    where
+        F: GEQRNum + GEMQRNum + GETSQRHRTNum + ORHR_COLNum + GEMQRTNum + TestFloat,
+    {
+        for (m, n, layout) in [(200, 8, 'C'), (200, 8, 'R'), (6, 6, 'C'), (5, 9, 'R')] {
+            let k = m.min(n);
+            let a = random_matrix::<F>(m, n, layout.into());
+            let qr = GEQR::default().a(a.view()).run().unwrap();
+
+            // economy Q R = A
+            let q = qr.q_economy().unwrap();
+            let r = qr.r();
+            assert_eq!(q.dim(), (m, k));
+            assert_eq!(r.dim(), (k, n));
+            allclose_epsilon(&gemm(&q.view(), &r.view()).view(), &a.view(), 1.0e-10);
+            let qhq = gemm(&transpose(&q.view(), 'C').view(), &q.view());
+            allclose_epsilon(&qhq.view(), &Array2::eye(k).view(), 1.0e-10);
+
+            // implicit Q application against complete Q
+            let q_full = qr.q_complete().unwrap();
+            let c = random_matrix::<F>(m, 3, 'R'.into());
+            for trans in ['N', 'C'] {
+                let qc = qr.apply_q('L', trans, c.view()).unwrap();
+                let qc_ref = gemm(&transpose(&q_full.view(), trans).view(), &c.view());
+                allclose_epsilon(&qc.view(), &qc_ref.view(), 1.0e-10);
+            }
+            let c = random_matrix::<F>(3, m, 'C'.into());
+            for trans in ['N', 'C'] {
+                let cq = qr.apply_q('R', trans, c.view()).unwrap();
+                let cq_ref = gemm(&c.view(), &transpose(&q_full.view(), trans).view());
+                allclose_epsilon(&cq.view(), &cq_ref.view(), 1.0e-10);
+            }
+
+            if m < n {
+                continue;
+            }
+
+            // Householder reconstruction of TSQR result
+            let (v, t) = qr.to_householder(3).unwrap();
+            assert_eq!(t.dim(), (3, n));
+            allclose_epsilon(&reconstruct_qrt(&v, &t).view(), &a.view(), 1.0e-10);
+
+            // TSQR with Householder reconstruction in one call
+            let (v, t) = GETSQRHRT::default().a(a.view()).mb1(n + 4).nb1(2).nb2(4).run().unwrap();
+            let v = v.into_owned();
+            assert_eq!(t.dim(), (4.min(n), n));
+            allclose_epsilon(&reconstruct_qrt(&v, &t).view(), &a.view(), 1.0e-10);
+        }
+
+        // invalid block sizes and shapes
+        let a = random_matrix::<F>(20, 4, 'C'.into());
+        assert!(GETSQRHRT::default().a(a.view()).mb1(4).nb1(2).nb2(2).run().is_err());
+        let a = random_matrix::<F>(4, 20, 'C'.into());
+        assert!(ORHR_COL::default().a(a.view()).nb(2).run().is_err());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,8 @@
+pub mod test_bidiagonal;
+pub mod test_gesdd;
+pub mod test_gesvd;
+pub mod test_gesvdq;
+pub mod test_gesvdx;
+pub mod test_jacobi;
+pub mod test_pinv;
+pub mod test_randomized_svd;
@@ -0,0 +1,139 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GEBRDNum + ORGBRNum + ORMBRNum + BDSQRNum + GESDDNum + TestFloat,
+    {
+        for (m, n, layout) in [(7, 4, 'C'), (4, 7, 'R'), (5, 5, 'C')] {
+            let k = m.min(n);
+            let a = random_matrix::<F>(m, n, layout.into());
+
+            // bidiagonal reduction A = Q B Pᴴ
+            let fac = GEBRD::default().a(a.view()).run().unwrap();
+            assert_eq!(fac.uplo(), if m >= n { 'U' } else { 'L' });
+            assert_eq!(fac.e.len(), k - 1);
+            let q = fac.q().unwrap();
+            let pt = fac.pt().unwrap();
+            assert_eq!(q.dim(), (m, k));
+            assert_eq!(pt.dim(), (k, n));
+            let qbpt = gemm(&gemm(&q.view(), &fac.b().view()).view(), &pt.view());
+            allclose_epsilon(&qbpt.view(), &a.view(), 1.0e-10);
+
+            let q_complete = fac.q_complete().unwrap();
+            let pt_complete = fac.pt_complete().unwrap();
+            let qhq = gemm(&transpose(&q_complete.view(), 'C').view(), &q_complete.view());
+            allclose_epsilon(&qhq.view(), &Array2::eye(m).view(), 1.0e-10);
+            let ppt = gemm(&pt_complete.view(), &transpose(&pt_complete.view(), 'C').view());
+            allclose_epsilon(&ppt.view(), &Array2::eye(n).view(), 1.0e-10);
+
+            // applying Q and P without forming them
+            let eye_m = Array2::<F>::eye(m);
+            let eye_n = Array2::<F>::eye(n);
+            let q_applied = fac.apply_q('L', 'N', eye_m.view()).unwrap().into_owned();
+            allclose_epsilon(&q_applied.view(), &q_complete.view(), 1.0e-10);
+            let p_applied = fac.apply_p('R', 'C', eye_n.view()).unwrap().into_owned();
+            allclose_epsilon(&p_applied.view(), &pt_complete.view(), 1.0e-10);
+
+            // bidiagonal SVD, accumulated into Q and Pᴴ, gives SVD of A
+            let s_ref = GESDD::default().a(a.view()).jobz('N').run().unwrap().s.into_owned();
+            let svd = BDSQR::default()
+                .d(fac.d.view())
+                .e(fac.e.view())
+                .uplo(fac.uplo())
+                .u(q.view())
+                .vt(pt.view())
+                .run()
+                .unwrap();
+            allclose_epsilon(&svd.s.mapv(F::from_real).view(), &s_ref.mapv(F::from_real).view(), 1.0e-10);
+            let u = svd.u.unwrap().into_owned();
+            let vt = svd.vt.unwrap().into_owned();
+            allclose_epsilon(
+                &svd_reconstruct(&u.view(), &svd.s.view(), &vt.view()).view(),
+                &a.view(),
+                1.0e-10,
+            );
+
+            // singular values only; `c` is multiplied by Qᴴ of `B`
+            let mut c = Array2::<F>::eye(k);
+            let svd = BDSQR::default()
+                .d(fac.d.view())
+                .e(fac.e.view())
+                .uplo(fac.uplo())
+                .c(c.view_mut())
+                .run()
+                .unwrap();
+            assert!(svd.u.is_none() && svd.vt.is_none());
+            drop(svd);
+            let cch = gemm(&c.view(), &transpose(&c.view(), 'C').view());
+            allclose_epsilon(&cch.view(), &Array2::eye(k).view(), 1.0e-10);
+        }
+
+        // invalid flags and sizes
+        let d = Array1::<F::RealFloat>::ones(4);
+        let e = Array1::<F::RealFloat>::ones(4);
+        assert!(BDSQR::<F>::default().d(d.view()).e(e.view()).run().is_err());
+        let e = Array1::<F::RealFloat>::ones(3);
+        assert!(BDSQR::<F>::default().d(d.view()).e(e.view()).uplo('N').run().is_err());
+        let u = Array2::<F>::zeros((3, 3));
+        assert!(BDSQR::<F>::default().d(d.view()).e(e.view()).u(u.view()).run().is_err());
+    }
+
+    fn test_real<F>()
+    where
+        F: GEBRDNum + BDSDCNum + BDSVDXNum + BDSQRNum + TestFloat<RealFloat = F>,
+    {
+        let a = random_matrix::<F>(8, 5, 'C'.into());
+        let fac = GEBRD::default().a(a.view()).run().unwrap();
+        let b = fac.b();
+        let s_ref = BDSQR::<F>::default().d(fac.d.view()).e(fac.e.view()).run().unwrap().s;
+
+        // divide-and-conquer
+        let svd = BDSDC::default().d(fac.d.view()).e(fac.e.view()).run().unwrap();
+        allclose_epsilon(&svd.s.view(), &s_ref.view(), 1.0e-10);
+        let (u, vt) = (svd.u.unwrap(), svd.vt.unwrap());
+        check_svd(&b.view(), &svd.s.view(), &u.view(), &vt.view());
+        let svd = BDSDC::default().d(fac.d.view()).e(fac.e.view()).compq('N').run().unwrap();
+        assert!(svd.u.is_none() && svd.vt.is_none());
+        allclose_epsilon(&svd.s.view(), &s_ref.view(), 1.0e-10);
+
+        // lower bidiagonal is transpose of upper bidiagonal
+        let svd = BDSDC::default().d(fac.d.view()).e(fac.e.view()).uplo('L').run().unwrap();
+        let (u, vt) = (svd.u.unwrap(), svd.vt.unwrap());
+        let bt = transpose(&b.view(), 'T');
+        check_svd(&bt.view(), &svd.s.view(), &u.view(), &vt.view());
+
+        // selected singular triplets: the 2nd and 3rd largest
+        let svd = BDSVDX::default().d(fac.d.view()).e(fac.e.view()).range('I').il(2).iu(3).run().unwrap();
+        allclose_epsilon(&svd.s.view(), &s_ref.slice(s![1..3]), 1.0e-10);
+        let (u, vt) = (svd.u.unwrap(), svd.vt.unwrap());
+        assert_eq!(u.dim(), (5, 2));
+        assert_eq!(vt.dim(), (2, 5));
+        let bv = gemm(&b.view(), &transpose(&vt.view(), 'T').view());
+        let us = svd_reconstruct(&u.view(), &svd.s.view(), &Array2::eye(2).view());
+        allclose_epsilon(&bv.view(), &us.view(), 1.0e-10);
+
+        // selected singular values by interval, containing the largest one only
+        let vl = (s_ref[0] + s_ref[1]) / (F::one() + F::one());
+        let svd = BDSVDX::default().d(fac.d.view()).e(fac.e.view()).range('V').vl(vl).vu(s_ref[0] + s_ref[0]);
+        let svd = svd.jobz('N').run().unwrap();
+        assert!(svd.u.is_none());
+        allclose_epsilon(&svd.s.view(), &s_ref.slice(s![..1]), 1.0e-10);
+
+        // invalid ranges
+        assert!(BDSVDX::default().d(fac.d.view()).e(fac.e.view()).range('I').il(3).iu(6).run().is_err());
+        assert!(BDSVDX::default().d(fac.d.view()).e(fac.e.view()).range('V').run().is_err());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+        test_real::<f64>();
+    }
+}
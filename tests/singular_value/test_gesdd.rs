@@ -0,0 +1,68 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GESDDNum + GESVDNum + TestFloat,
+    {
+        for (m, n, layout) in [(7, 4, 'C'), (4, 7, 'R'), (5, 5, 'C')] {
+            let mn = m.min(n);
+            let a = random_matrix::<F>(m, n, layout.into());
+            let s_ref = GESVD::default().a(a.view()).jobu('N').jobvt('N').run().unwrap().s.into_owned();
+            let s_ref = s_ref.mapv(F::from_real);
+
+            // jobz = 'S' (default) and 'A' agree with QR iteration driver
+            for (jobz, (nu, nvt)) in [('S', (mn, mn)), ('A', (m, n))] {
+                let svd = GESDD::default().a(a.view()).jobz(jobz).run().unwrap();
+                let (u, vt) = (svd.u().unwrap(), svd.vt().unwrap());
+                assert_eq!((u.dim(), vt.dim()), ((m, nu), (nvt, n)));
+                check_svd(&a.view(), &svd.s.view(), &u, &vt);
+                allclose_epsilon(&svd.s.view().mapv(F::from_real).view(), &s_ref.view(), 1.0e-10);
+            }
+
+            // jobz = 'O': U (m >= n) or Vᴴ (m < n) overwrites `a`; the other is returned
+            let mut a_mut = a.clone();
+            let svd = GESDD::default().a(a_mut.view_mut()).jobz('O').run().unwrap();
+            assert!(if m >= n { svd.u.is_none() } else { svd.vt.is_none() });
+            let (u, vt) = (svd.u().unwrap(), svd.vt().unwrap());
+            check_svd(&a.view(), &svd.s.view(), &u, &vt);
+            let (u, vt) = (u.to_owned(), vt.to_owned());
+            drop(svd);
+            if m >= n {
+                allclose_epsilon(&a_mut.slice(s![.., ..n]), &u.view(), 1.0e-10);
+            } else {
+                allclose_epsilon(&a_mut.slice(s![..m, ..]), &vt.view(), 1.0e-10);
+            }
+
+            // jobz = 'N': singular values only, into user-provided array
+            let mut s_out = Array1::<F::RealFloat>::zeros(mn);
+            let svd = GESDD::default().a(a.view()).s(s_out.view_mut()).jobz('N').run().unwrap();
+            assert!(svd.u().is_none() && svd.vt().is_none());
+            drop(svd);
+            allclose_epsilon(&s_out.mapv(F::from_real).view(), &s_ref.view(), 1.0e-10);
+
+            // too small workspace falls back to query
+            let mut work = Array1::<F>::zeros(1);
+            let svd = GESDD::default().a(a.view()).work(work.view_mut()).run().unwrap();
+            allclose_epsilon(&svd.s.view().mapv(F::from_real).view(), &s_ref.view(), 1.0e-10);
+        }
+
+        // invalid flags and sizes; `u` is not accepted for jobz = 'O'
+        let a = random_matrix::<F>(6, 4, 'C'.into());
+        assert!(GESDD::default().a(a.view()).jobz('V').run().is_err());
+        let mut u_out = Array2::<F>::zeros((6, 6));
+        assert!(GESDD::default().a(a.view()).u(u_out.view_mut()).jobz('O').run().is_err());
+        assert!(GESDD::default().a(a.view()).u(u_out.view_mut()).jobz('A').run().is_ok());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
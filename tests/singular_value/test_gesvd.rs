@@ -0,0 +1,69 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GESVDNum + TestFloat,
+    {
+        for (m, n, layout) in [(7, 4, 'C'), (4, 7, 'R'), (5, 5, 'C')] {
+            let mn = m.min(n);
+            let a = random_matrix::<F>(m, n, layout.into());
+
+            // economy
+            let svd = GESVD::default().a(a.view()).run().unwrap();
+            let s = svd.s.view().to_owned();
+            let (u, vt) = (svd.u().unwrap(), svd.vt().unwrap());
+            assert_eq!((u.dim(), vt.dim()), ((m, mn), (mn, n)));
+            check_svd(&a.view(), &s.view(), &u, &vt);
+
+            // complete
+            let svd = GESVD::default().a(a.view()).jobu('A').jobvt('A').run().unwrap();
+            let (u, vt) = (svd.u().unwrap(), svd.vt().unwrap());
+            assert_eq!((u.dim(), vt.dim()), ((m, m), (n, n)));
+            check_svd(&a.view(), &s.view(), &u, &vt);
+
+            // overwrite U or Vᴴ in `a`
+            let mut a_mut = a.clone();
+            let svd = GESVD::default().a(a_mut.view_mut()).jobu('O').run().unwrap();
+            assert!(svd.u.is_none());
+            let u = svd.u().unwrap();
+            assert_eq!(u.dim(), (m, mn));
+            check_svd(&a.view(), &svd.s.view(), &u, &svd.vt().unwrap());
+            let u = u.to_owned();
+            drop(svd);
+            allclose_epsilon(&a_mut.slice(s![.., ..mn]), &u.view(), 1.0e-10);
+
+            let svd = GESVD::default().a(a.view()).jobu('S').jobvt('O').run().unwrap();
+            assert!(svd.vt.is_none());
+            assert_eq!(svd.vt().unwrap().dim(), (mn, n));
+            check_svd(&a.view(), &svd.s.view(), &svd.u().unwrap(), &svd.vt().unwrap());
+
+            // singular values only, into user-provided array
+            let mut s_out = Array1::<F::RealFloat>::zeros(mn);
+            let svd = GESVD::default().a(a.view()).s(s_out.view_mut()).jobu('N').jobvt('N').run().unwrap();
+            assert!(svd.u().is_none() && svd.vt().is_none());
+            drop(svd);
+            allclose_epsilon(&s_out.mapv(F::from_real).view(), &s.mapv(F::from_real).view(), 1.0e-10);
+        }
+
+        // invalid flags and sizes
+        let a = random_matrix::<F>(6, 4, 'C'.into());
+        assert!(GESVD::default().a(a.view()).jobu('O').jobvt('O').run().is_err());
+        let mut s_out = Array1::<F::RealFloat>::zeros(6);
+        assert!(GESVD::default().a(a.view()).s(s_out.view_mut()).run().is_err());
+        let mut u_out = Array2::<F>::zeros((6, 6));
+        assert!(GESVD::default().a(a.view()).u(u_out.view_mut()).run().is_err());
+        assert!(GESVD::default().a(a.view()).u(u_out.view_mut()).jobu('A').run().is_ok());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
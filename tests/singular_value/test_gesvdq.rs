@@ -0,0 +1,79 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GESVDQNum + GESDDNum + TestFloat,
+    {
+        for (m, n, layout) in [(9, 6, 'C'), (8, 5, 'R'), (6, 6, 'C')] {
+            let a = random_matrix::<F>(m, n, layout.into());
+            let s_ref = GESDD::default().a(a.view()).jobz('N').run().unwrap().s.into_owned();
+
+            // default: high accuracy, n left vectors
+            let svd = GESVDQ::default().a(a.view()).run().unwrap();
+            assert_eq!(svd.numrank, n);
+            assert!(svd.sconda.is_none());
+            let s = svd.s.view();
+            allclose_epsilon(&s.mapv(F::from_real).view(), &s_ref.mapv(F::from_real).view(), 1.0e-10);
+            let u = svd.u.as_ref().unwrap().view();
+            let vt = svd.vt.as_ref().unwrap().view();
+            assert_eq!(u.dim(), (m, n));
+            assert_eq!(vt.dim(), (n, n));
+            check_svd(&a.view(), &s, &u, &vt);
+
+            // all left vectors, row pivoting and condition estimate
+            let svd = GESVDQ::default().a(a.view()).joba('E').jobp('P').jobu('A').run().unwrap();
+            assert!(svd.sconda.is_some());
+            let u = svd.u.as_ref().unwrap().view();
+            assert_eq!(u.dim(), (m, m));
+            check_svd(&a.view(), &svd.s.view(), &u, &svd.vt.as_ref().unwrap().view());
+
+            // singular values only, into user-provided array
+            let mut s_out = Array1::<F::RealFloat>::zeros(n);
+            let svd = GESVDQ::default().a(a.view()).s(s_out.view_mut()).jobu('N').jobv('N').run().unwrap();
+            assert!(svd.u.is_none() && svd.vt.is_none());
+            drop(svd);
+            allclose_epsilon(&s_out.mapv(F::from_real).view(), &s_ref.mapv(F::from_real).view(), 1.0e-10);
+
+            // workspace below the documented minimum falls back to query
+            let mut work = Array1::<F>::zeros(3);
+            let svd = GESVDQ::default().a(a.view()).work(work.view_mut()).run().unwrap();
+            allclose_epsilon(
+                &svd.s.view().mapv(F::from_real).view(),
+                &s_ref.mapv(F::from_real).view(),
+                1.0e-10,
+            );
+        }
+
+        // numerically rank deficient: rank 3 matrix of shape (9, 6)
+        let b = random_matrix::<F>(9, 3, 'C'.into());
+        let c = random_matrix::<F>(3, 6, 'C'.into());
+        let a = gemm(&b.view(), &c.view());
+        let svd = GESVDQ::default().a(a.view()).joba('A').jobu('R').jobv('R').run().unwrap();
+        assert_eq!(svd.numrank, 3);
+        let k = svd.numrank;
+        let u = svd.u.as_ref().unwrap().view();
+        let vt = svd.vt.as_ref().unwrap().view();
+        allclose_epsilon(&svd_reconstruct(&u, &svd.s.view().slice(s![..k]), &vt).view(), &a.view(), 1.0e-10);
+
+        // invalid flags and sizes
+        let a = random_matrix::<F>(4, 6, 'C'.into());
+        assert!(GESVDQ::default().a(a.view()).run().is_err());
+        let a = random_matrix::<F>(6, 4, 'C'.into());
+        assert!(GESVDQ::default().a(a.view()).jobu('F').run().is_err());
+        let mut u_out = Array2::<F>::zeros((6, 6));
+        assert!(GESVDQ::default().a(a.view()).u(u_out.view_mut()).run().is_err());
+        assert!(GESVDQ::default().a(a.view()).u(u_out.view_mut()).jobu('A').run().is_ok());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,77 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GESVDXNum + GESDDNum + TestFloat,
+    {
+        for (m, n, layout) in [(9, 6, 'C'), (6, 9, 'R'), (7, 7, 'C')] {
+            let mn = m.min(n);
+            let a = random_matrix::<F>(m, n, layout.into());
+            let s_ref = GESDD::default().a(a.view()).jobz('N').run().unwrap().s.into_owned();
+
+            // all singular triplets
+            let svd = GESVDX::default().a(a.view()).run().unwrap();
+            assert_eq!(svd.s.len(), mn);
+            allclose_epsilon(&svd.s.mapv(F::from_real).view(), &s_ref.mapv(F::from_real).view(), 1.0e-10);
+
+            // index range: the 2nd to 4th largest
+            let svd = GESVDX::default().a(a.view()).range('I').il(2).iu(4).run().unwrap();
+            let (u, vt) = (svd.u.unwrap(), svd.vt.unwrap());
+            assert_eq!(svd.s.len(), 3);
+            assert_eq!(u.dim(), (m, 3));
+            assert_eq!(vt.dim(), (3, n));
+            allclose_epsilon(
+                &svd.s.mapv(F::from_real).view(),
+                &s_ref.slice(s![1..4]).mapv(F::from_real).view(),
+                1.0e-10,
+            );
+            let uhu = gemm(&transpose(&u.view(), 'C').view(), &u.view());
+            allclose_epsilon(&uhu.view(), &Array2::eye(3).view(), 1.0e-10);
+            // A vᵢ = σᵢ uᵢ
+            let av = gemm(&a.view(), &transpose(&vt.view(), 'C').view());
+            let mut us = u.clone();
+            for (mut col, &sv) in us.columns_mut().into_iter().zip(svd.s.iter()) {
+                col.mapv_inplace(|x| x * F::from_real(sv));
+            }
+            allclose_epsilon(&av.view(), &us.view(), 1.0e-10);
+
+            // value range: values in (s[3], s[0]] are the 3 largest ones
+            let svd = GESVDX::default()
+                .a(a.view())
+                .range('V')
+                .vl((s_ref[3] + s_ref[2]) / (F::RealFloat::one() + F::RealFloat::one()))
+                .vu(s_ref[0] + F::RealFloat::one())
+                .jobu('N')
+                .run()
+                .unwrap();
+            assert!(svd.u.is_none());
+            assert_eq!(svd.s.len(), 3);
+            assert_eq!(svd.vt.unwrap().dim(), (3, n));
+            allclose_epsilon(
+                &svd.s.mapv(F::from_real).view(),
+                &s_ref.slice(s![..3]).mapv(F::from_real).view(),
+                1.0e-10,
+            );
+        }
+
+        // invalid flags and ranges
+        let a = random_matrix::<F>(6, 4, 'C'.into());
+        assert!(GESVDX::default().a(a.view()).jobu('S').run().is_err());
+        assert!(GESVDX::default().a(a.view()).range('I').il(0).iu(2).run().is_err());
+        assert!(GESVDX::default().a(a.view()).range('I').il(2).iu(5).run().is_err());
+        assert!(GESVDX::default().a(a.view()).range('V').run().is_err());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,99 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+use num_traits::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Singular values sorted decreasingly, as scalar type for comparison.
+    fn sorted<F>(s: &Array1<F::RealFloat>) -> Array1<F>
+    where
+        F: TestFloat,
+    {
+        let mut s = s.to_vec();
+        s.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        Array1::from_vec(s).mapv(F::from_real)
+    }
+
+    fn test_generic<F>()
+    where
+        F: GESVJNum + GEJSVNum + GESDDNum + TestFloat,
+    {
+        for (m, n, layout) in [(9, 6, 'C'), (8, 5, 'R'), (6, 6, 'C')] {
+            let a = random_matrix::<F>(m, n, layout.into());
+            let s_ref = GESDD::default().a(a.view()).jobz('N').run().unwrap().s.into_owned();
+
+            // GESVJ
+            let svd = GESVJ::default().a(a.view()).run().unwrap();
+            let s = svd.s();
+            allclose_epsilon(&sorted::<F>(&s).view(), &s_ref.mapv(F::from_real).view(), 1.0e-10);
+            assert_eq!(svd.n_nonzero, n);
+            assert_eq!(svd.n_above_underflow, n);
+            assert!(svd.sweeps > 0);
+            let v = svd.v.as_ref().unwrap().view();
+            assert_eq!(v.dim(), (n, n));
+            let vt = transpose(&v, 'C');
+            allclose_epsilon(
+                &svd_reconstruct(&svd.a.view(), &s.view(), &vt.view()).view(),
+                &a.view(),
+                1.0e-10,
+            );
+
+            // GESVJ, apply rotations to identity is equivalent to computing V
+            let mut v_acc = Array2::<F>::eye(n);
+            let svd_acc = GESVJ::default().a(a.view()).jobv('A').v(v_acc.view_mut()).run().unwrap();
+            allclose_epsilon(&svd_acc.v.unwrap().view(), &v, 1.0e-10);
+
+            // GESVJ, user-given orthogonality tolerance and no vectors
+            let ctol = <F::RealFloat as NumCast>::from(m).unwrap();
+            let svd = GESVJ::default().a(a.view()).jobu('C').ctol(ctol).jobv('N').run().unwrap();
+            assert!(svd.v.is_none());
+            allclose_epsilon(&sorted::<F>(&svd.s()).view(), &s_ref.mapv(F::from_real).view(), 1.0e-10);
+
+            // GEJSV
+            let svd = GEJSV::default().a(a.view()).run().unwrap();
+            let s = svd.s();
+            allclose_epsilon(&s.mapv(F::from_real).view(), &s_ref.mapv(F::from_real).view(), 1.0e-10);
+            assert_eq!(svd.rank, n);
+            assert_eq!(svd.n_nonzero, n);
+            assert!(!svd.denormalized);
+            assert!(svd.entropy.is_none());
+            let u = svd.u.as_ref().unwrap().view();
+            let v = svd.v.as_ref().unwrap().view();
+            assert_eq!(u.dim(), (m, n));
+            check_svd(&a.view(), &s.view(), &u, &transpose(&v, 'C').view());
+
+            // GEJSV, full U with condition estimate and entropy
+            let svd = GEJSV::default().a(a.view()).joba('G').jobu('F').jobt('T').run().unwrap();
+            let u = svd.u.as_ref().unwrap().view();
+            assert_eq!(u.dim(), (m, m));
+            assert!(svd.sconda > F::RealFloat::zero());
+            assert!(svd.entropy.is_some());
+            let vt = transpose(&svd.v.as_ref().unwrap().view(), 'C');
+            check_svd(&a.view(), &svd.s().view(), &u, &vt.view());
+
+            // GEJSV, singular values only
+            let svd = GEJSV::default().a(a.view()).jobu('N').jobv('N').run().unwrap();
+            assert!(svd.u.is_none() && svd.v.is_none());
+            allclose_epsilon(&svd.s().mapv(F::from_real).view(), &s_ref.mapv(F::from_real).view(), 1.0e-10);
+        }
+
+        // invalid flags and sizes
+        let a = random_matrix::<F>(4, 6, 'C'.into());
+        assert!(GESVJ::default().a(a.view()).run().is_err());
+        assert!(GEJSV::default().a(a.view()).run().is_err());
+        let a = random_matrix::<F>(6, 4, 'C'.into());
+        assert!(GESVJ::default().a(a.view()).jobu('C').run().is_err());
+        assert!(GESVJ::default().a(a.view()).jobv('A').run().is_err());
+        assert!(GEJSV::default().a(a.view()).jobu('W').jobv('N').run().is_err());
+        assert!(GEJSV::default().a(a.view()).jobu('N').jobv('J').run().is_err());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
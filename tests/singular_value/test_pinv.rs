@@ -0,0 +1,52 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: GESDDNum + TestFloat,
+    {
+        for (m, n, r, layout) in [(7, 5, 3, 'C'), (5, 7, 3, 'R'), (6, 4, 4, 'R')] {
+            // rank-r matrix
+            let x = random_matrix::<F>(m, r, 'C'.into());
+            let y = random_matrix::<F>(r, n, 'C'.into());
+            let a = ndarray_to_layout(gemm(&x.view(), &y.view()), layout);
+
+            assert_eq!(matrix_rank(a.view(), None).unwrap(), r);
+
+            // Penrose conditions
+            let a_pinv = pinv(a.view(), None).unwrap();
+            assert_eq!(a_pinv.dim(), (n, m));
+            let apa = gemm(&gemm(&a.view(), &a_pinv.view()).view(), &a.view());
+            allclose_epsilon(&apa.view(), &a.view(), 1.0e-10);
+            let pap = gemm(&gemm(&a_pinv.view(), &a.view()).view(), &a_pinv.view());
+            allclose_epsilon(&pap.view(), &a_pinv.view(), 1.0e-10);
+
+            // null space
+            let ns = null_space(a.view(), None).unwrap();
+            assert_eq!(ns.dim(), (n, n - r));
+            let a_ns = gemm(&a.view(), &ns.view());
+            allclose_epsilon(&a_ns.view(), &Array2::zeros((m, n - r)).view(), 1.0);
+            let nhn = gemm(&transpose(&ns.view(), 'C').view(), &ns.view());
+            allclose_epsilon(&nhn.view(), &Array2::eye(n - r).view(), 1.0e-10);
+
+            // range space
+            let q = orth(a.view(), None).unwrap();
+            assert_eq!(q.dim(), (m, r));
+            let qhq = gemm(&transpose(&q.view(), 'C').view(), &q.view());
+            allclose_epsilon(&qhq.view(), &Array2::eye(r).view(), 1.0e-10);
+            let qqha = gemm(&q.view(), &gemm(&transpose(&q.view(), 'C').view(), &a.view()).view());
+            allclose_epsilon(&qqha.view(), &a.view(), 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+}
@@ -0,0 +1,97 @@
+use crate::util::*;
+use lapack_array2::prelude::*;
+use ndarray::prelude::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_generic<F>()
+    where
+        F: RSVDNum + TestFloat,
+    {
+        let (m, n, r) = (60, 40, 5);
+        for layout in ['C', 'R'] {
+            // exactly low-rank matrix, recovered exactly up to rounding
+            let x = random_matrix::<F>(m, r, layout.into());
+            let y = random_matrix::<F>(r, n, layout.into());
+            let a = gemm(&x.view(), &y.view());
+            let s_ref = GESDD::default().a(a.view()).jobz('N').run().unwrap().s.into_owned();
+
+            let svd = RSVD::default().a(a.view()).k(r).oversampling(3).power_iter(1).run().unwrap();
+            assert_eq!(svd.u.dim(), (m, r));
+            assert_eq!(svd.s.len(), r);
+            assert_eq!(svd.vt.dim(), (r, n));
+            allclose_epsilon(
+                &svd.s.mapv(F::from_real).view(),
+                &s_ref.slice(s![..r]).mapv(F::from_real).view(),
+                1.0e-10,
+            );
+            let uhu = gemm(&transpose(&svd.u.view(), 'C').view(), &svd.u.view());
+            allclose_epsilon(&uhu.view(), &Array2::eye(r).view(), 1.0e-10);
+            allclose_epsilon(
+                &svd_reconstruct(&svd.u.view(), &svd.s.view(), &svd.vt.view()).view(),
+                &a.view(),
+                1.0e-10,
+            );
+
+            // truncation below rank gives leading singular values
+            let svd = RSVD::default().a(a.view()).k(2).run().unwrap();
+            allclose_epsilon(
+                &svd.s.mapv(F::from_real).view(),
+                &s_ref.slice(s![..2]).mapv(F::from_real).view(),
+                1.0e-10,
+            );
+
+            // same seed reproduces the same result
+            let svd1 = RSVD::default().a(a.view()).k(3).iseed([1, 2, 3, 5]).run().unwrap();
+            let svd2 = RSVD::default().a(a.view()).k(3).iseed([1, 2, 3, 5]).run().unwrap();
+            assert_eq!(svd1.u, svd2.u);
+            assert_eq!(svd1.vt, svd2.vt);
+
+            // range finder
+            let q = randomized_range_finder(a.view(), r, 0, [0, 0, 0, 1]).unwrap();
+            assert_eq!(q.dim(), (m, r));
+            let qqha = gemm(&q.view(), &gemm(&transpose(&q.view(), 'C').view(), &a.view()).view());
+            allclose_epsilon(&qqha.view(), &a.view(), 1.0e-10);
+        }
+
+        // invalid rank and seed
+        let a = random_matrix::<F>(10, 6, 'C'.into());
+        assert!(RSVD::default().a(a.view()).k(0).run().is_err());
+        assert!(RSVD::default().a(a.view()).k(7).run().is_err());
+        assert!(RSVD::default().a(a.view()).k(2).iseed([0, 0, 0, 2]).run().is_err());
+        assert!(randomized_range_finder(a.view(), 7, 0, [0, 0, 0, 1]).is_err());
+    }
+
+    fn test_larnv_generic<F>()
+    where
+        F: LARNVNum + TestFloat,
+    {
+        let out1 = LARNV::<F>::default().n(100).iseed([1, 2, 3, 4095]).run().unwrap();
+        let out2 = LARNV::<F>::default().n(100).iseed([1, 2, 3, 4095]).run().unwrap();
+        assert_eq!(out1.x, out2.x);
+        assert_ne!(out1.iseed, [1, 2, 3, 4095]);
+        // continuing from updated seed gives a different vector
+        let out3 = LARNV::<F>::default().n(100).iseed(out1.iseed).run().unwrap();
+        assert_ne!(out1.x, out3.x);
+
+        assert!(LARNV::<F>::default().n(10).idist(0).run().is_err());
+        assert!(LARNV::<F>::default().n(10).idist(6).run().is_err());
+        assert!(LARNV::<F>::default().n(10).iseed([4096, 0, 0, 1]).run().is_err());
+        assert!(LARNV::<F>::default().n(10).iseed([0, 0, 0, 4]).run().is_err());
+        assert!(LARNV::<f64>::default().n(10).idist(4).run().is_err());
+    }
+
+    #[test]
+    fn test() {
+        test_generic::<f64>();
+        test_generic::<c64>();
+    }
+
+    #[test]
+    fn test_larnv() {
+        test_larnv_generic::<f64>();
+        test_larnv_generic::<c64>();
+    }
+}
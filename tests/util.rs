@@ -7,16 +7,12 @@ use rand::{thread_rng, Rng};
 /* #region TestFloat and Random matrix */
 
 pub trait TestFloat: LapackFloat {
-    fn from_real(x: Self::RealFloat) -> Self;
     fn re(x: Self) -> Self::RealFloat;
     fn im(x: Self) -> Self::RealFloat;
     fn rand() -> Self;
 }
 
 impl TestFloat for f32 {
-    fn from_real(x: Self::RealFloat) -> Self {
-        x
-    }
     fn re(x: Self) -> Self::RealFloat {
         x
     }
@@ -29,9 +25,6 @@ impl TestFloat for f32 {
 }
 
 impl TestFloat for f64 {
-    fn from_real(x: Self::RealFloat) -> Self {
-        x
-    }
     fn re(x: Self) -> Self::RealFloat {
         x
     }
@@ -44,9 +37,6 @@ impl TestFloat for f64 {
 }
 
 impl TestFloat for c32 {
-    fn from_real(x: Self::RealFloat) -> Self {
-        Self::new(x, 0.0)
-    }
     fn re(x: Self) -> Self::RealFloat {
         x.re
     }
@@ -61,9 +51,6 @@ impl TestFloat for c32 {
 }
 
 impl TestFloat for c64 {
-    fn from_real(x: Self::RealFloat) -> Self {
-        Self::new(x, 0.0)
-    }
     fn re(x: Self) -> Self::RealFloat {
         x.re
     }
@@ -103,6 +90,19 @@ where
     return array;
 }
 
+/// Random Hermitian (symmetric for real) positive definite matrix `X Xᴴ + n I`.
+pub fn hpd_matrix<F>(n: usize) -> Array2<F>
+where
+    F: TestFloat + LapackFloat,
+{
+    let x = random_matrix::<F>(n, n, 'C'.into());
+    let mut a = gemm(&x.view(), &transpose(&x.view(), 'C').view());
+    for i in 0..n {
+        a[[i, i]] += F::from_real(NumCast::from(n).unwrap());
+    }
+    return a;
+}
+
 /* #endregion */
 
 /* #region Sized subatrix */
@@ -353,3 +353,37 @@ where
 }
 
 /* #endregion */
+
+/* #region SVD */
+
+/// `U Σ Vᴴ` with the leading `len(s)` columns of `u` and rows of `vt`.
+pub fn svd_reconstruct<F>(u: &ArrayView2<F>, s: &ArrayView1<F::RealFloat>, vt: &ArrayView2<F>) -> Array2<F>
+where
+    F: LapackFloat,
+{
+    let k = s.len();
+    let mut us = u.slice(s![.., ..k]).to_owned();
+    for (mut col, &sv) in us.columns_mut().into_iter().zip(s.iter()) {
+        col.mapv_inplace(|x| x * F::from_real(sv));
+    }
+    return gemm(&us.view(), &vt.slice(s![..k, ..]));
+}
+
+/// Check SVD `A = U Σ Vᴴ`, economy or complete: `s` is decreasing, `u` has orthonormal columns, `vt` has orthonormal
+/// rows, and `a` is reconstructed.
+pub fn check_svd<F>(a: &ArrayView2<F>, s: &ArrayView1<F::RealFloat>, u: &ArrayView2<F>, vt: &ArrayView2<F>)
+where
+    F: TestFloat,
+{
+    let (m, n) = a.dim();
+    assert_eq!(s.len(), m.min(n));
+    assert!(s.windows(2).into_iter().all(|w| w[0] >= w[1]));
+    assert_eq!((u.nrows(), vt.ncols()), (m, n));
+    let uhu = gemm(&transpose(u, 'C').view(), u);
+    allclose_epsilon(&uhu.view(), &Array2::eye(u.ncols()).view(), 1.0e-10);
+    let vvh = gemm(vt, &transpose(vt, 'C').view());
+    allclose_epsilon(&vvh.view(), &Array2::eye(vt.nrows()).view(), 1.0e-10);
+    allclose_epsilon(&svd_reconstruct(u, s, vt).view(), a, 1.0e-10);
+}
+
+/* #endregion */